@@ -127,6 +127,12 @@ pub fn clear_dir(dir: &Path, watch: &mut StopWatch) {
     std::fs::create_dir_all(dir).unwrap_or_else(|e| panic!("failed to create dir: {e}"));
 }
 
+/// Highest minor version for which `since_api`/`before_api` keys are emitted.
+///
+/// Must be at least the newest version checked anywhere in the code. Otherwise, `before_api` fallbacks are compiled
+/// out on older engines: class docs need `before_api = "4.3"`, and typed dictionaries need `before_api = "4.4"`.
+const MAX_API_MINOR: u8 = 4;
+
 pub fn emit_godot_version_cfg() {
     let GodotVersion {
         major,
@@ -135,22 +141,64 @@ pub fn emit_godot_version_cfg() {
         ..
     } = get_godot_version();
 
+    for cfg in godot_version_cfgs(major, minor, patch) {
+        println!("cargo:rustc-cfg={cfg}");
+    }
+}
+
+fn godot_version_cfgs(major: u8, minor: u8, patch: u8) -> Vec<String> {
+    let mut cfgs = vec![];
+
     // Start at 1; checking for "since/before 4.0" makes no sense
-    let max = 2;
     for m in 1..=minor {
-        println!(r#"cargo:rustc-cfg=since_api="{major}.{m}""#);
+        cfgs.push(format!(r#"since_api="{major}.{m}""#));
     }
-    for m in minor + 1..=max {
-        println!(r#"cargo:rustc-cfg=before_api="{major}.{m}""#);
+    for m in minor + 1..=MAX_API_MINOR {
+        cfgs.push(format!(r#"before_api="{major}.{m}""#));
     }
 
     // The below configuration keys are very rarely needed and should generally not be used.
-    println!(r#"cargo:rustc-cfg=gdextension_minor_api="{major}.{minor}""#);
+    cfgs.push(format!(r#"gdextension_minor_api="{major}.{minor}""#));
 
     // Godot drops the patch version if it is 0.
     if patch != 0 {
-        println!(r#"cargo:rustc-cfg=gdextension_exact_api="{major}.{minor}.{patch}""#);
+        cfgs.push(format!(
+            r#"gdextension_exact_api="{major}.{minor}.{patch}""#
+        ));
     } else {
-        println!(r#"cargo:rustc-cfg=gdextension_exact_api="{major}.{minor}""#);
+        cfgs.push(format!(r#"gdextension_exact_api="{major}.{minor}""#));
+    }
+
+    cfgs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn version_cfgs_before_typed_dictionaries() {
+        let cfgs = godot_version_cfgs(4, 1, 3);
+
+        assert_eq!(
+            cfgs,
+            [
+                r#"since_api="4.1""#,
+                r#"before_api="4.2""#,
+                r#"before_api="4.3""#,
+                r#"before_api="4.4""#,
+                r#"gdextension_minor_api="4.1""#,
+                r#"gdextension_exact_api="4.1.3""#,
+            ]
+        );
+    }
+
+    #[test]
+    fn version_cfgs_since_typed_dictionaries() {
+        let cfgs = godot_version_cfgs(4, 4, 0);
+
+        assert!(cfgs.contains(&r#"since_api="4.4""#.to_string()));
+        assert!(!cfgs.iter().any(|cfg| cfg.starts_with("before_api")));
+        assert!(cfgs.contains(&r#"gdextension_exact_api="4.4""#.to_string()));
     }
 }
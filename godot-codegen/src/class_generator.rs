@@ -184,6 +184,8 @@ struct FnCode {
     receiver: FnReceiver,
     varcall_invocation: TokenStream,
     ptrcall_invocation: TokenStream,
+    /// Varcall returning `Result<R, CallError>`, used for `try_*` variants. Only available for class methods.
+    try_varcall_invocation: Option<TokenStream>,
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -516,17 +518,12 @@ fn make_class(class: &Class, class_name: &TyName, ctx: &mut Context) -> Generate
     let constants = make_constants(option_as_slice(&class.constants), class_name, ctx);
     let inherits_macro = format_ident!("inherits_transitive_{}", class_name.rust_ty);
 
-    let (exportable_impl, exportable_macro_impl) = if ctx.is_exportable(class_name) {
-        (
-            quote! {
-                impl crate::obj::ExportableObject for #class_name {}
-            },
-            quote! {
-                impl ::godot::obj::ExportableObject for $Class {}
-            },
-        )
+    let exportable_impl = if ctx.is_exportable(class_name) {
+        quote! {
+            impl crate::obj::ExportableObject for #class_name {}
+        }
     } else {
-        (TokenStream::new(), TokenStream::new())
+        TokenStream::new()
     };
 
     // The base_ty of `Object` is `()`, and we dont want every engine class to deref to `()`.
@@ -647,7 +644,6 @@ fn make_class(class: &Class, class_name: &TyName, ctx: &mut Context) -> Generate
                     #(
                         impl ::godot::obj::Inherits<::godot::engine::#all_bases> for $Class {}
                     )*
-                    #exportable_macro_impl
                 }
             }
         }
@@ -1068,6 +1064,18 @@ fn make_special_builtin_methods(class_name: &TyName, _ctx: &Context) -> TokenStr
                 }
             }
         }
+    } else if class_name.godot_ty == "Dictionary" {
+        quote! {
+            pub fn from_outer_typed<K, V>(outer: &Dictionary<K, V>) -> Self
+                where K: crate::builtin::meta::VariantMetadata,
+                      V: crate::builtin::meta::VariantMetadata
+            {
+                Self {
+                    _outer_lifetime: std::marker::PhantomData,
+                    sys_ptr: outer.sys(),
+                }
+            }
+        }
     } else {
         TokenStream::new()
     }
@@ -1139,6 +1147,19 @@ fn make_method_definition(
         )
     };
 
+    let try_varcall_invocation = quote! {
+        let method_bind = sys::#get_method_table().fptr_by_index(#table_index);
+
+        <CallSig as VarcallSignatureTuple>::try_out_class_varcall(
+            method_bind,
+            #method_name_str,
+            #object_ptr,
+            #maybe_instance_id,
+            args,
+            varargs
+        )
+    };
+
     make_function_definition(
         &FnSignature {
             function_name: method_name_str,
@@ -1154,6 +1175,7 @@ fn make_method_definition(
             receiver,
             varcall_invocation,
             ptrcall_invocation,
+            try_varcall_invocation: Some(try_varcall_invocation),
         },
     )
 }
@@ -1222,6 +1244,7 @@ fn make_builtin_method_definition(
             receiver,
             varcall_invocation,
             ptrcall_invocation,
+            try_varcall_invocation: None,
         },
     )
 }
@@ -1276,6 +1299,7 @@ pub(crate) fn make_utility_function_definition(
             receiver: FnReceiver::global_function(),
             varcall_invocation,
             ptrcall_invocation,
+            try_varcall_invocation: None,
         },
     );
 
@@ -1355,7 +1379,35 @@ fn make_function_definition(sig: &FnSignature, code: &FnCode) -> FnDefinition {
         // If the return type is not Variant, then convert to concrete target type
         let varcall_invocation = &code.varcall_invocation;
 
-        // TODO use Result instead of panic on error
+        // Fallible variant, for callers that need to handle errors (e.g. calling user scripts) instead of panicking.
+        let try_function = if let Some(try_varcall_invocation) = &code.try_varcall_invocation {
+            let try_fn_name = format_ident!("try_{}", primary_fn_name);
+            let try_doc = format!(
+                "Like [`Self::{primary_fn_name}()`], but returns a [`CallError`][crate::builtin::meta::CallError] \
+                instead of panicking if the call fails."
+            );
+
+            quote! {
+                #[doc = #try_doc]
+                #safety_doc
+                #vis #maybe_unsafe fn #try_fn_name(
+                    #receiver_param
+                    #( #params, )*
+                    varargs: &[Variant]
+                ) -> Result<#return_ty, crate::builtin::meta::CallError> {
+                    type CallSig = #call_sig;
+
+                    let args = (#( #arg_names, )*);
+
+                    unsafe {
+                        #try_varcall_invocation
+                    }
+                }
+            }
+        } else {
+            TokenStream::new()
+        };
+
         quote! {
             #safety_doc
             #vis #maybe_unsafe fn #primary_fn_name(
@@ -1371,6 +1423,8 @@ fn make_function_definition(sig: &FnSignature, code: &FnCode) -> FnDefinition {
                     #varcall_invocation
                 }
             }
+
+            #try_function
         }
     } else {
         // Always ptrcall, no varargs
@@ -1785,6 +1839,57 @@ fn special_virtual_methods(notification_enum_name: &Ident) -> TokenStream {
         fn on_notification(&mut self, what: #notification_enum_name) {
             unimplemented!()
         }
+
+        /// Called whenever [`get()`][crate::engine::Object::get] is called or Godot gets the value of a property.
+        ///
+        /// Should return the given `property`'s value as `Some(value)`, or `None` if the property should be handled normally.
+        ///
+        /// See also in Godot docs:
+        /// * [`Object::_get`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-get).
+        fn get_property(&self, property: crate::builtin::StringName) -> Option<crate::builtin::Variant> {
+            unimplemented!()
+        }
+
+        /// Called whenever [`set()`][crate::engine::Object::set] is called or Godot sets the value of a property.
+        ///
+        /// Should set `property` to the given `value` and return `true`, or return `false` to indicate the `property`
+        /// should be handled normally.
+        ///
+        /// See also in Godot docs:
+        /// * [`Object::_set`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-set).
+        fn set_property(&mut self, property: crate::builtin::StringName, value: crate::builtin::Variant) -> bool {
+            unimplemented!()
+        }
+
+        /// Called whenever [`get_property_list()`][crate::engine::Object::get_property_list] is called or Godot queries the
+        /// properties of the object, e.g. to display them in the inspector.
+        ///
+        /// The returned properties are added to the ones registered with `#[var]` and `#[export]`. Their values are read and
+        /// written through [`get_property()`][Self::get_property] and [`set_property()`][Self::set_property].
+        ///
+        /// See also in Godot docs:
+        /// * [`Object::_get_property_list`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-get-property-list).
+        fn get_property_list(&self) -> Vec<crate::builtin::meta::PropertyInfo> {
+            unimplemented!()
+        }
+
+        /// Called by the editor to check whether `property` can be reverted to a default value.
+        ///
+        /// See also in Godot docs:
+        /// * [`Object::_property_can_revert`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-property-can-revert).
+        fn property_can_revert(&self, property: crate::builtin::StringName) -> bool {
+            unimplemented!()
+        }
+
+        /// Called by the editor to get the default value of `property`, if it [can be reverted][Self::property_can_revert].
+        ///
+        /// Should return `Some(value)` with the default value, or `None` if the property has no default.
+        ///
+        /// See also in Godot docs:
+        /// * [`Object::_property_get_revert`](https://docs.godotengine.org/en/stable/classes/class_object.html#class-object-private-method-property-get-revert).
+        fn property_get_revert(&self, property: crate::builtin::StringName) -> Option<crate::builtin::Variant> {
+            unimplemented!()
+        }
     }
 }
 
@@ -1810,6 +1915,7 @@ fn make_virtual_method(method: &ClassMethod, ctx: &mut Context) -> TokenStream {
             // make_return() requests following args, but they are not used for virtual methods. We can provide empty streams.
             varcall_invocation: TokenStream::new(),
             ptrcall_invocation: TokenStream::new(),
+            try_varcall_invocation: None,
         },
     );
 
@@ -127,6 +127,7 @@ const SELECTED_CLASSES: &[&str] = &[
     "CollisionObject2D",
     "CollisionShape2D",
     "Control",
+    "EditorPlugin",
     "Engine",
     "FileAccess",
     "HTTPRequest",
@@ -156,6 +157,10 @@ const SELECTED_CLASSES: &[&str] = &[
     "ResourceLoader",
     "RigidBody2D",
     "SceneTree",
+    "Script",
+    "ScriptExtension",
+    "ScriptLanguage",
+    "ScriptLanguageExtension",
     "Sprite2D",
     "SpriteFrames",
     "TextServer",
@@ -167,4 +172,5 @@ const SELECTED_CLASSES: &[&str] = &[
     "Timer",
     "Window",
     "Viewport",
+    "WorkerThreadPool",
 ];
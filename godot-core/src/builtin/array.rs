@@ -795,6 +795,35 @@ impl<T: VariantMetadata + ToVariant> Extend<T> for Array<T> {
     }
 }
 
+/// Serialized as a sequence of elements.
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for Array<T>
+where
+    T: VariantMetadata + FromVariant + serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq as _;
+
+        // The iterator has no exact size hint, but formats without delimiters need the length upfront.
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for element in self.iter_shared() {
+            seq.serialize_element(&element)?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> serde::Deserialize<'de> for Array<T>
+where
+    T: VariantMetadata + ToVariant + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let elements = <Vec<T> as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(elements.into_iter().collect())
+    }
+}
+
 /// Converts this array to a strongly typed Rust vector.
 impl<T: VariantMetadata + FromVariant> From<&Array<T>> for Vec<T> {
     fn from(array: &Array<T>) -> Vec<T> {
@@ -930,27 +959,27 @@ macro_rules! varray {
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
-/// Represents the type information of a Godot array. See
+/// Represents the type information of a Godot array (or of dictionary keys and values). See
 /// [`set_typed`](https://docs.godotengine.org/en/latest/classes/class_array.html#class-array-method-set-typed).
 ///
 /// We ignore the `script` parameter because it has no impact on typing in Godot.
 #[derive(PartialEq, Eq)]
-struct TypeInfo {
-    variant_type: VariantType,
+pub(super) struct TypeInfo {
+    pub variant_type: VariantType,
 
     /// Not a `ClassName` because some values come from Godot engine API.
-    class_name: StringName,
+    pub class_name: StringName,
 }
 
 impl TypeInfo {
-    fn of<T: VariantMetadata>() -> Self {
+    pub fn of<T: VariantMetadata>() -> Self {
         Self {
             variant_type: T::variant_type(),
             class_name: T::class_name().to_string_name(),
         }
     }
 
-    fn is_typed(&self) -> bool {
+    pub fn is_typed(&self) -> bool {
         self.variant_type != VariantType::Nil
     }
 }
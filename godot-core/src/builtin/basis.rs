@@ -141,24 +141,52 @@ impl Basis {
     ///
     /// The up axis (+Y) points as close to the `up` vector as possible while
     /// staying perpendicular to the forward axis. The resulting Basis is
-    /// orthonormalized. The `target` and `up` vectors cannot be zero, and
-    /// cannot be parallel to each other.
+    /// orthonormalized.
+    ///
+    /// # Panics
+    ///
+    /// If `target` or `up` is zero, or if they are parallel to each other.
     ///
     #[cfg(before_api = "4.1")]
     /// _Godot equivalent: `Basis.looking_at()`_
     #[doc(alias = "looking_at")]
     pub fn new_looking_at(target: Vector3, up: Vector3) -> Self {
-        super::inner::InnerBasis::looking_at(target, up)
+        Self::looking_at_impl(target, up, false)
     }
 
     /// If `use_model_front` is true, the +Z axis (asset front) is treated as forward (implies +X is left)
     /// and points toward the target position. By default, the -Z axis (camera forward) is treated as forward
     /// (implies +X is right).
     ///
+    /// # Panics
+    ///
+    /// If `target` or `up` is zero, or if they are parallel to each other.
+    ///
     /// _Godot equivalent: `Basis.looking_at()`_
     #[cfg(since_api = "4.1")]
     pub fn new_looking_at(target: Vector3, up: Vector3, use_model_front: bool) -> Self {
-        super::inner::InnerBasis::looking_at(target, up, use_model_front)
+        Self::looking_at_impl(target, up, use_model_front)
+    }
+
+    fn looking_at_impl(target: Vector3, up: Vector3, use_model_front: bool) -> Self {
+        assert!(!target.is_zero_approx(), "The target vector can't be zero.");
+        assert!(!up.is_zero_approx(), "The up vector can't be zero.");
+
+        let mut z = target.normalized();
+        if !use_model_front {
+            z = -z;
+        }
+
+        let x = up.cross(z);
+        assert!(
+            !x.is_zero_approx(),
+            "The target vector and up vector can't be parallel to each other."
+        );
+
+        let x = x.normalized();
+        let y = z.cross(x);
+
+        Self::from_cols(x, y, z)
     }
 
     /// Creates a `[Vector3; 3]` with the columns of the `Basis`.
@@ -841,6 +869,26 @@ mod test {
         );
     }
 
+    #[test]
+    fn looking_at() {
+        let basis = Basis::looking_at_impl(Vector3::new(0.0, 0.0, -2.0), Vector3::UP, false);
+        assert_eq_approx!(basis, Basis::IDENTITY);
+
+        let basis = Basis::looking_at_impl(Vector3::new(3.0, 0.0, 0.0), Vector3::UP, false);
+        assert_eq_approx!(basis.col_c(), Vector3::new(-1.0, 0.0, 0.0));
+        assert_eq_approx!(basis.col_b(), Vector3::UP);
+
+        let basis = Basis::looking_at_impl(Vector3::new(3.0, 0.0, 0.0), Vector3::UP, true);
+        assert_eq_approx!(basis.col_c(), Vector3::new(1.0, 0.0, 0.0));
+        assert_eq_approx!(basis.col_b(), Vector3::UP);
+    }
+
+    #[test]
+    #[should_panic]
+    fn looking_at_parallel() {
+        Basis::looking_at_impl(Vector3::UP, Vector3::UP * 2.0, false);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
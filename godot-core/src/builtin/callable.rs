@@ -11,6 +11,8 @@ use crate::engine::Object;
 use crate::obj::mem::Memory;
 use crate::obj::{Gd, GodotClass, InstanceId};
 use std::fmt;
+#[cfg(since_api = "4.2")]
+use std::sync::Mutex;
 use sys::{ffi_methods, GodotFfi};
 
 use super::{StringName, VariantArray};
@@ -53,6 +55,61 @@ impl Callable {
         }
     }
 
+    /// Create a custom callable, which invokes the Rust function `rust_function` when called.
+    ///
+    /// The function receives all arguments passed to the callable and returns the call's result. `name` is used for
+    /// string conversion and panic messages; it doesn't need to be unique.
+    ///
+    /// Two callables created by separate `from_fn()` invocations are never equal, even if they have the same name. The function
+    /// is dropped once Godot no longer holds any reference to the callable.
+    ///
+    /// This is useful to connect signals to closures, or to pass callbacks to engine APIs such as `Tween` or `call_deferred`,
+    /// without declaring a dedicated class with a `#[func]` method:
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// let callable = Callable::from_fn("sum", |args: &[Variant]| {
+    ///     let sum: i64 = args.iter().map(|arg| arg.to::<i64>()).sum();
+    ///     sum.to_variant()
+    /// });
+    /// ```
+    ///
+    /// Panics inside `rust_function` are caught at the FFI boundary; the call then fails in Godot and returns `NIL`.
+    ///
+    /// Godot may invoke the callable from any thread, so the function must be `Send + Sync`. Concurrent calls are serialized,
+    /// which also means that `rust_function` must not invoke its own callable (this would deadlock).
+    ///
+    /// _Godot equivalent: custom callable, similar to a GDScript lambda_
+    #[cfg(since_api = "4.2")]
+    pub fn from_fn<F, S>(name: S, rust_function: F) -> Self
+    where
+        F: 'static + Send + Sync + FnMut(&[Variant]) -> Variant,
+        S: Into<String>,
+    {
+        let userdata = CallableUserdata {
+            name: name.into(),
+            rust_function: Mutex::new(rust_function),
+        };
+
+        let mut info = sys::GDExtensionCallableCustomInfo {
+            callable_userdata: Box::into_raw(Box::new(userdata)) as *mut std::ffi::c_void,
+            token: sys::get_library() as *mut std::ffi::c_void,
+            object_id: 0,
+            call_func: Some(custom_callable::call::<F>),
+            is_valid_func: None, // always valid
+            free_func: Some(custom_callable::free::<F>),
+            hash_func: Some(custom_callable::hash::<F>),
+            equal_func: Some(custom_callable::equal::<F>),
+            less_than_func: Some(custom_callable::less_than::<F>),
+            to_string_func: Some(custom_callable::to_string::<F>),
+        };
+
+        unsafe {
+            Self::from_sys_init(|self_ptr| {
+                sys::interface_fn!(callable_custom_create)(self_ptr, std::ptr::addr_of_mut!(info));
+            })
+        }
+    }
+
     /// Creates an invalid/empty object that is not able to be called.
     ///
     /// _Godot equivalent: `Callable()`_
@@ -135,7 +192,7 @@ impl Callable {
     /// Returns true if this callable is a custom callable.
     ///
     /// Custom callables are mainly created from bind or unbind. In GDScript, lambda functions are also
-    /// custom callables.
+    /// custom callables. Callables created with `Callable::from_fn()` are custom callables, too.
     ///
     /// If a callable is not a custom callable, then it is considered a standard callable, this function is
     /// the opposite of [`Callable.is_standard`].
@@ -219,3 +276,101 @@ impl std::fmt::Display for Callable {
         write!(f, "{}", self.to_variant())
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Custom callables
+
+/// State owned by a custom callable created with [`Callable::from_fn`].
+#[cfg(since_api = "4.2")]
+struct CallableUserdata<F> {
+    name: String,
+    /// Locked during calls, as `FnMut` requires exclusive access, but Godot may call from several threads at once.
+    rust_function: Mutex<F>,
+}
+
+/// Callbacks passed to Godot upon creation of a custom callable. `callable_userdata` always points to a `CallableUserdata<F>`.
+#[cfg(since_api = "4.2")]
+mod custom_callable {
+    use super::*;
+    use crate::builtin::GodotString;
+    use std::hash::{Hash, Hasher};
+    use std::sync::PoisonError;
+
+    pub unsafe extern "C" fn call<F: FnMut(&[Variant]) -> Variant>(
+        callable_userdata: *mut std::ffi::c_void,
+        args_ptr: *const sys::GDExtensionConstVariantPtr,
+        arg_count: sys::GDExtensionInt,
+        ret: sys::GDExtensionVariantPtr,
+        err: *mut sys::GDExtensionCallError,
+    ) {
+        let args: Vec<Variant> = (0..arg_count as isize)
+            .map(|i| (*(*args_ptr.offset(i) as *const Variant)).clone())
+            .collect();
+
+        let CallableUserdata {
+            name,
+            rust_function,
+        } = &*(callable_userdata as *const CallableUserdata<F>);
+
+        let result = crate::private::handle_panic(
+            || format!("custom callable `{name}`"),
+            std::panic::AssertUnwindSafe(|| {
+                // A panic in a previous call poisons the mutex, but leaves the function itself intact.
+                let mut rust_function =
+                    rust_function.lock().unwrap_or_else(PoisonError::into_inner);
+                (*rust_function)(&args)
+            }),
+        );
+
+        match result {
+            Some(ret_variant) => {
+                *(ret as *mut Variant) = ret_variant;
+                (*err).error = sys::GDEXTENSION_CALL_OK;
+            }
+            None => {
+                // Signal error; return value stays Nil
+                (*err).error = sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD; // no better fitting enum?
+            }
+        }
+    }
+
+    pub unsafe extern "C" fn free<F>(callable_userdata: *mut std::ffi::c_void) {
+        drop(Box::from_raw(callable_userdata as *mut CallableUserdata<F>));
+    }
+
+    pub unsafe extern "C" fn hash<F>(callable_userdata: *mut std::ffi::c_void) -> u32 {
+        let userdata = &*(callable_userdata as *const CallableUserdata<F>);
+
+        // Equal callables share the same userdata, so hashing the name is consistent with equality.
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        userdata.name.hash(&mut hasher);
+        hasher.finish() as u32
+    }
+
+    pub unsafe extern "C" fn equal<F>(
+        callable_userdata_a: *mut std::ffi::c_void,
+        callable_userdata_b: *mut std::ffi::c_void,
+    ) -> sys::GDExtensionBool {
+        // Custom callables are only equal if they stem from the same from_fn() call.
+        (callable_userdata_a == callable_userdata_b) as sys::GDExtensionBool
+    }
+
+    pub unsafe extern "C" fn less_than<F>(
+        callable_userdata_a: *mut std::ffi::c_void,
+        callable_userdata_b: *mut std::ffi::c_void,
+    ) -> sys::GDExtensionBool {
+        (callable_userdata_a < callable_userdata_b) as sys::GDExtensionBool
+    }
+
+    pub unsafe extern "C" fn to_string<F>(
+        callable_userdata: *mut std::ffi::c_void,
+        is_valid: *mut sys::GDExtensionBool,
+        out_string: sys::GDExtensionStringPtr,
+    ) {
+        let userdata = &*(callable_userdata as *const CallableUserdata<F>);
+
+        // Transfer ownership to Godot
+        GodotString::from(userdata.name.as_str()).move_string_ptr(out_string);
+        *is_valid = true as sys::GDExtensionBool;
+    }
+}
@@ -4,9 +4,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::builtin::inner::InnerColor;
-use crate::builtin::math::ApproxEq;
-use crate::builtin::GodotString;
+use crate::builtin::math::{ApproxEq, FloatExt};
 
 use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
@@ -102,9 +100,8 @@ impl Color {
     /// - `#RGB` and `RGB`. Equivalent to `#RRGGBBff`.
     ///
     /// Returns `None` if the format is invalid.
-    pub fn from_html<S: Into<GodotString>>(html: S) -> Option<Self> {
-        let html = html.into();
-        InnerColor::html_is_valid(html.clone()).then(|| InnerColor::html(html))
+    pub fn from_html(html: impl AsRef<str>) -> Option<Self> {
+        Self::parse_html(html.as_ref())
     }
 
     /// Constructs a `Color` from a string, which can be either:
@@ -121,25 +118,35 @@ impl Color {
     ///
     /// [color_constants]: https://docs.godotengine.org/en/latest/classes/class_color.html#constants
     /// [cheat_sheet]: https://raw.githubusercontent.com/godotengine/godot-docs/master/img/color_constants.png
-    pub fn from_string<S: Into<GodotString>>(string: S) -> Option<Self> {
-        let color = InnerColor::from_string(
-            string.into(),
-            Self::from_rgba(f32::NAN, f32::NAN, f32::NAN, f32::NAN),
-        );
-        // Assumption: the implementation of `from_string` in the engine will never return any NaN
-        // upon success.
-        if color.r.is_nan() {
-            None
-        } else {
-            Some(color)
-        }
+    pub fn from_string(string: impl AsRef<str>) -> Option<Self> {
+        Self::parse_string(string.as_ref())
     }
 
     /// Constructs a `Color` from an [HSV profile](https://en.wikipedia.org/wiki/HSL_and_HSV). The
     /// hue (`h`), saturation (`s`), and value (`v`) are typically between 0.0 and 1.0. Alpha is
     /// set to 1; use [`Color::with_alpha`] to change it.
     pub fn from_hsv(h: f64, s: f64, v: f64) -> Self {
-        InnerColor::from_hsv(h, s, v, 1.0)
+        // core/math/color.cpp: Color::set_hsv(), computed in single precision like the engine.
+        let (h, s, v) = (h as f32, s as f32, v as f32);
+        if s == 0.0 {
+            return Self::from_rgb(v, v, v);
+        }
+
+        let h = (h * 6.0) % 6.0;
+        let i = h.floor();
+        let f = h - i;
+        let p = v * (1.0 - s);
+        let q = v * (1.0 - s * f);
+        let t = v * (1.0 - s * (1.0 - f));
+
+        match i as i32 {
+            0 => Self::from_rgb(v, t, p),
+            1 => Self::from_rgb(q, v, p),
+            2 => Self::from_rgb(p, v, t),
+            3 => Self::from_rgb(p, q, v),
+            4 => Self::from_rgb(t, p, v),
+            _ => Self::from_rgb(v, p, q),
+        }
     }
 
     /// Constructs a `Color` from an [OK HSL
@@ -147,14 +154,23 @@ impl Color {
     /// and lightness (`l`) are typically between 0.0 and 1.0. Alpha is set to 1; use
     /// [`Color::with_alpha`] to change it.
     pub fn from_ok_hsl(h: f64, s: f64, l: f64) -> Self {
-        InnerColor::from_ok_hsl(h, s, l, 1.0)
+        // core/math/color.cpp: Color::set_ok_hsl(), computed in single precision like the engine.
+        let [r, g, b] = ok_color::okhsl_to_srgb(h as f32, s as f32, l as f32);
+        Self::from_rgb(r, g, b).clamp(Self::TRANSPARENT_BLACK, Self::WHITE)
     }
 
     /// Constructs a `Color` from an RGBE9995 format integer. This is a special OpenGL texture
     /// format where the three color components have 9 bits of precision and all three share a
     /// single 5-bit exponent.
     pub fn from_rgbe9995(rgbe: u32) -> Self {
-        InnerColor::from_rgbe9995(rgbe as i64)
+        let r = (rgbe & 0x1ff) as f32;
+        let g = ((rgbe >> 9) & 0x1ff) as f32;
+        let b = ((rgbe >> 18) & 0x1ff) as f32;
+        let e = (rgbe >> 27) as f32;
+
+        // Exponent bias is 15, mantissa has 9 bits.
+        let m = 2.0f32.powf(e - 15.0 - 9.0);
+        Self::from_rgb(r * m, g * m, b * m)
     }
 
     /// Returns a copy of this color with the given alpha value. Useful for chaining with
@@ -219,47 +235,89 @@ impl Color {
     /// accurate relative luminance value. If the color is in the sRGB color space, use
     /// [`Color::srgb_to_linear`] to convert it to the linear color space first.
     pub fn luminance(self) -> f64 {
-        self.as_inner().get_luminance()
+        (0.2126 * self.r + 0.7152 * self.g + 0.0722 * self.b) as f64
     }
 
     /// Blends the given color on top of this color, taking its alpha into account.
     #[must_use]
     pub fn blend(self, over: Color) -> Self {
-        self.as_inner().blend(over)
+        let sa = 1.0 - over.a;
+        let a = self.a * sa + over.a;
+        if a == 0.0 {
+            return Self::TRANSPARENT_BLACK;
+        }
+
+        let blend_channel = |dst: f32, src: f32| (dst * self.a * sa + src * over.a) / a;
+        Self::from_rgba(
+            blend_channel(self.r, over.r),
+            blend_channel(self.g, over.g),
+            blend_channel(self.b, over.b),
+            a,
+        )
     }
 
     /// Returns the linear interpolation between `self`'s components and `to`'s components. The
     /// interpolation factor `weight` should be between 0.0 and 1.0 (inclusive).
     #[must_use]
     pub fn lerp(self, to: Color, weight: f64) -> Self {
-        self.as_inner().lerp(to, weight)
+        let weight = weight as f32;
+        Self::from_rgba(
+            self.r.lerp(to.r, weight),
+            self.g.lerp(to.g, weight),
+            self.b.lerp(to.b, weight),
+            self.a.lerp(to.a, weight),
+        )
     }
 
     /// Returns a new color with all components clamped between the components of `min` and `max`.
     #[must_use]
     pub fn clamp(self, min: Color, max: Color) -> Self {
-        self.as_inner().clamp(min, max)
+        // Not f32::clamp(), which panics if min > max; the engine's CLAMP macro doesn't.
+        let clamp_channel = |v: f32, min: f32, max: f32| {
+            if v < min {
+                min
+            } else if v > max {
+                max
+            } else {
+                v
+            }
+        };
+
+        Self::from_rgba(
+            clamp_channel(self.r, min.r, max.r),
+            clamp_channel(self.g, min.g, max.g),
+            clamp_channel(self.b, min.b, max.b),
+            clamp_channel(self.a, min.a, max.a),
+        )
     }
 
     /// Creates a new color resulting by making this color darker by the specified amount (ratio
     /// from 0.0 to 1.0). See also [`lightened`][Self::lightened].
     #[must_use]
     pub fn darkened(self, amount: f64) -> Self {
-        self.as_inner().darkened(amount)
+        let factor = 1.0 - amount as f32;
+        Self::from_rgba(self.r * factor, self.g * factor, self.b * factor, self.a)
     }
 
     /// Creates a new color resulting by making this color lighter by the specified amount, which
     /// should be a ratio from 0.0 to 1.0. See also [`darkened`][Self::darkened].
     #[must_use]
     pub fn lightened(self, amount: f64) -> Self {
-        self.as_inner().lightened(amount)
+        let amount = amount as f32;
+        let lighten_channel = |v: f32| v + (1.0 - v) * amount;
+        Self::from_rgba(
+            lighten_channel(self.r),
+            lighten_channel(self.g),
+            lighten_channel(self.b),
+            self.a,
+        )
     }
 
     /// Returns the color with its `r`, `g`, and `b` components inverted:
     /// `Color::from_rgba(1 - r, 1 - g, 1 - b, a)`.
     #[must_use]
     pub fn inverted(self) -> Self {
-        self.as_inner().inverted()
+        Self::from_rgba(1.0 - self.r, 1.0 - self.g, 1.0 - self.b, self.a)
     }
 
     /// Returns the color converted to the [sRGB](https://en.wikipedia.org/wiki/SRGB) color space.
@@ -267,7 +325,15 @@ impl Color {
     /// [`Color::srgb_to_linear`] which performs the opposite operation.
     #[must_use]
     pub fn linear_to_srgb(self) -> Self {
-        self.as_inner().linear_to_srgb()
+        let to_srgb = |v: f32| {
+            if v < 0.0031308 {
+                12.92 * v
+            } else {
+                (1.0 + 0.055) * v.powf(1.0 / 2.4) - 0.055
+            }
+        };
+
+        Self::from_rgba(to_srgb(self.r), to_srgb(self.g), to_srgb(self.b), self.a)
     }
 
     /// Returns the color converted to the linear color space. This method assumes the original
@@ -275,19 +341,32 @@ impl Color {
     /// opposite operation.
     #[must_use]
     pub fn srgb_to_linear(self) -> Self {
-        self.as_inner().srgb_to_linear()
+        let to_linear = |v: f32| {
+            if v < 0.04045 {
+                v * (1.0 / 12.92)
+            } else {
+                ((v + 0.055) * (1.0 / (1.0 + 0.055))).powf(2.4)
+            }
+        };
+
+        Self::from_rgba(
+            to_linear(self.r),
+            to_linear(self.g),
+            to_linear(self.b),
+            self.a,
+        )
     }
 
     /// Returns the HTML color code representation of this color, as 8 lowercase hex digits in the
     /// order `RRGGBBAA`, without the `#` prefix.
-    pub fn to_html(self) -> GodotString {
-        self.as_inner().to_html(true)
+    pub fn to_html(self) -> String {
+        self.html_string(true)
     }
 
     /// Returns the HTML color code representation of this color, as 6 lowercase hex digits in the
     /// order `RRGGBB`, without the `#` prefix. The alpha channel is ignored.
-    pub fn to_html_without_alpha(self) -> GodotString {
-        self.as_inner().to_html(false)
+    pub fn to_html_without_alpha(self) -> String {
+        self.html_string(false)
     }
 
     /// Returns the color converted to a 32-bit integer (each component is 8 bits) with the given
@@ -307,8 +386,67 @@ impl Color {
         ]))
     }
 
-    fn as_inner(&self) -> InnerColor {
-        InnerColor::from_outer(self)
+    /// Parses an HTML color code, see [`Color::from_html`].
+    fn parse_html(html: &str) -> Option<Self> {
+        let html = html.strip_prefix('#').unwrap_or(html);
+
+        let digits = html
+            .chars()
+            .map(|c| c.to_digit(16))
+            .collect::<Option<Vec<u32>>>()?;
+
+        // Shorthand digits are scaled by 15, full bytes by 255 -- same as the engine.
+        let color = match digits.as_slice() {
+            &[r, g, b] => Self::from_rgb(r as f32 / 15.0, g as f32 / 15.0, b as f32 / 15.0),
+            &[r, g, b, a] => Self::from_rgba(
+                r as f32 / 15.0,
+                g as f32 / 15.0,
+                b as f32 / 15.0,
+                a as f32 / 15.0,
+            ),
+            &[r1, r0, g1, g0, b1, b0] => {
+                Self::from_rgba8(hex_byte(r1, r0), hex_byte(g1, g0), hex_byte(b1, b0), 255)
+            }
+            &[r1, r0, g1, g0, b1, b0, a1, a0] => Self::from_rgba8(
+                hex_byte(r1, r0),
+                hex_byte(g1, g0),
+                hex_byte(b1, b0),
+                hex_byte(a1, a0),
+            ),
+            _ => return None,
+        };
+
+        Some(color)
+    }
+
+    /// Parses an HTML color code or color name, see [`Color::from_string`].
+    fn parse_string(string: &str) -> Option<Self> {
+        Self::parse_html(string).or_else(|| Self::from_name(string))
+    }
+
+    /// Formats the color as HTML color code, see [`Color::to_html`].
+    fn html_string(self, with_alpha: bool) -> String {
+        let rgb = format!("{:02x}{:02x}{:02x}", self.r8(), self.g8(), self.b8());
+        if with_alpha {
+            format!("{rgb}{:02x}", self.a8())
+        } else {
+            rgb
+        }
+    }
+
+    /// Looks up a named color constant, normalizing the name the same way the engine does.
+    fn from_name(name: &str) -> Option<Self> {
+        // core/math/color.cpp: Color::find_named_color().
+        let name: String = name
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '_' | '\'' | '.'))
+            .flat_map(char::to_uppercase)
+            .collect();
+
+        NAMED_COLORS
+            .iter()
+            .find(|(const_name, _)| const_name.replace('_', "") == name)
+            .map(|&(_, rgba)| Self::from_u32_rgba(rgba, ColorChannelOrder::Rgba))
     }
 }
 
@@ -320,8 +458,10 @@ unsafe impl GodotFfi for Color {
 
 impl ApproxEq for Color {
     fn approx_eq(&self, other: &Self) -> bool {
-        // TODO(bromeon): re-implement in Rust
-        self.as_inner().is_equal_approx(*other)
+        self.r.approx_eq(&other.r)
+            && self.g.approx_eq(&other.g)
+            && self.b.approx_eq(&other.b)
+            && self.a.approx_eq(&other.a)
     }
 }
 
@@ -464,6 +604,163 @@ impl ops::Neg for Color {
     }
 }
 
+/// Named color constants recognized by [`Color::from_string`], as RGBA hex values.
+///
+/// Mirrors `core/math/color_names.inc` in the engine.
+const NAMED_COLORS: &[(&str, u32)] = &[
+    ("ALICE_BLUE", 0xF0F8FFFF),
+    ("ANTIQUE_WHITE", 0xFAEBD7FF),
+    ("AQUA", 0x00FFFFFF),
+    ("AQUAMARINE", 0x7FFFD4FF),
+    ("AZURE", 0xF0FFFFFF),
+    ("BEIGE", 0xF5F5DCFF),
+    ("BISQUE", 0xFFE4C4FF),
+    ("BLACK", 0x000000FF),
+    ("BLANCHED_ALMOND", 0xFFEBCDFF),
+    ("BLUE", 0x0000FFFF),
+    ("BLUE_VIOLET", 0x8A2BE2FF),
+    ("BROWN", 0xA52A2AFF),
+    ("BURLYWOOD", 0xDEB887FF),
+    ("CADET_BLUE", 0x5F9EA0FF),
+    ("CHARTREUSE", 0x7FFF00FF),
+    ("CHOCOLATE", 0xD2691EFF),
+    ("CORAL", 0xFF7F50FF),
+    ("CORNFLOWER_BLUE", 0x6495EDFF),
+    ("CORNSILK", 0xFFF8DCFF),
+    ("CRIMSON", 0xDC143CFF),
+    ("CYAN", 0x00FFFFFF),
+    ("DARK_BLUE", 0x00008BFF),
+    ("DARK_CYAN", 0x008B8BFF),
+    ("DARK_GOLDENROD", 0xB8860BFF),
+    ("DARK_GRAY", 0xA9A9A9FF),
+    ("DARK_GREEN", 0x006400FF),
+    ("DARK_KHAKI", 0xBDB76BFF),
+    ("DARK_MAGENTA", 0x8B008BFF),
+    ("DARK_OLIVE_GREEN", 0x556B2FFF),
+    ("DARK_ORANGE", 0xFF8C00FF),
+    ("DARK_ORCHID", 0x9932CCFF),
+    ("DARK_RED", 0x8B0000FF),
+    ("DARK_SALMON", 0xE9967AFF),
+    ("DARK_SEA_GREEN", 0x8FBC8FFF),
+    ("DARK_SLATE_BLUE", 0x483D8BFF),
+    ("DARK_SLATE_GRAY", 0x2F4F4FFF),
+    ("DARK_TURQUOISE", 0x00CED1FF),
+    ("DARK_VIOLET", 0x9400D3FF),
+    ("DEEP_PINK", 0xFF1493FF),
+    ("DEEP_SKY_BLUE", 0x00BFFFFF),
+    ("DIM_GRAY", 0x696969FF),
+    ("DODGER_BLUE", 0x1E90FFFF),
+    ("FIREBRICK", 0xB22222FF),
+    ("FLORAL_WHITE", 0xFFFAF0FF),
+    ("FOREST_GREEN", 0x228B22FF),
+    ("FUCHSIA", 0xFF00FFFF),
+    ("GAINSBORO", 0xDCDCDCFF),
+    ("GHOST_WHITE", 0xF8F8FFFF),
+    ("GOLD", 0xFFD700FF),
+    ("GOLDENROD", 0xDAA520FF),
+    ("GRAY", 0xBEBEBEFF),
+    ("GREEN", 0x00FF00FF),
+    ("GREEN_YELLOW", 0xADFF2FFF),
+    ("HONEYDEW", 0xF0FFF0FF),
+    ("HOT_PINK", 0xFF69B4FF),
+    ("INDIAN_RED", 0xCD5C5CFF),
+    ("INDIGO", 0x4B0082FF),
+    ("IVORY", 0xFFFFF0FF),
+    ("KHAKI", 0xF0E68CFF),
+    ("LAVENDER", 0xE6E6FAFF),
+    ("LAVENDER_BLUSH", 0xFFF0F5FF),
+    ("LAWN_GREEN", 0x7CFC00FF),
+    ("LEMON_CHIFFON", 0xFFFACDFF),
+    ("LIGHT_BLUE", 0xADD8E6FF),
+    ("LIGHT_CORAL", 0xF08080FF),
+    ("LIGHT_CYAN", 0xE0FFFFFF),
+    ("LIGHT_GOLDENROD", 0xFAFAD2FF),
+    ("LIGHT_GRAY", 0xD3D3D3FF),
+    ("LIGHT_GREEN", 0x90EE90FF),
+    ("LIGHT_PINK", 0xFFB6C1FF),
+    ("LIGHT_SALMON", 0xFFA07AFF),
+    ("LIGHT_SEA_GREEN", 0x20B2AAFF),
+    ("LIGHT_SKY_BLUE", 0x87CEFAFF),
+    ("LIGHT_SLATE_GRAY", 0x778899FF),
+    ("LIGHT_STEEL_BLUE", 0xB0C4DEFF),
+    ("LIGHT_YELLOW", 0xFFFFE0FF),
+    ("LIME", 0x00FF00FF),
+    ("LIME_GREEN", 0x32CD32FF),
+    ("LINEN", 0xFAF0E6FF),
+    ("MAGENTA", 0xFF00FFFF),
+    ("MAROON", 0xB03060FF),
+    ("MEDIUM_AQUAMARINE", 0x66CDAAFF),
+    ("MEDIUM_BLUE", 0x0000CDFF),
+    ("MEDIUM_ORCHID", 0xBA55D3FF),
+    ("MEDIUM_PURPLE", 0x9370DBFF),
+    ("MEDIUM_SEA_GREEN", 0x3CB371FF),
+    ("MEDIUM_SLATE_BLUE", 0x7B68EEFF),
+    ("MEDIUM_SPRING_GREEN", 0x00FA9AFF),
+    ("MEDIUM_TURQUOISE", 0x48D1CCFF),
+    ("MEDIUM_VIOLET_RED", 0xC71585FF),
+    ("MIDNIGHT_BLUE", 0x191970FF),
+    ("MINT_CREAM", 0xF5FFFAFF),
+    ("MISTY_ROSE", 0xFFE4E1FF),
+    ("MOCCASIN", 0xFFE4B5FF),
+    ("NAVAJO_WHITE", 0xFFDEADFF),
+    ("NAVY_BLUE", 0x000080FF),
+    ("OLD_LACE", 0xFDF5E6FF),
+    ("OLIVE", 0x808000FF),
+    ("OLIVE_DRAB", 0x6B8E23FF),
+    ("ORANGE", 0xFFA500FF),
+    ("ORANGE_RED", 0xFF4500FF),
+    ("ORCHID", 0xDA70D6FF),
+    ("PALE_GOLDENROD", 0xEEE8AAFF),
+    ("PALE_GREEN", 0x98FB98FF),
+    ("PALE_TURQUOISE", 0xAFEEEEFF),
+    ("PALE_VIOLET_RED", 0xDB7093FF),
+    ("PAPAYA_WHIP", 0xFFEFD5FF),
+    ("PEACH_PUFF", 0xFFDAB9FF),
+    ("PERU", 0xCD853FFF),
+    ("PINK", 0xFFC0CBFF),
+    ("PLUM", 0xDDA0DDFF),
+    ("POWDER_BLUE", 0xB0E0E6FF),
+    ("PURPLE", 0xA020F0FF),
+    ("REBECCA_PURPLE", 0x663399FF),
+    ("RED", 0xFF0000FF),
+    ("ROSY_BROWN", 0xBC8F8FFF),
+    ("ROYAL_BLUE", 0x4169E1FF),
+    ("SADDLE_BROWN", 0x8B4513FF),
+    ("SALMON", 0xFA8072FF),
+    ("SANDY_BROWN", 0xF4A460FF),
+    ("SEA_GREEN", 0x2E8B57FF),
+    ("SEASHELL", 0xFFF5EEFF),
+    ("SIENNA", 0xA0522DFF),
+    ("SILVER", 0xC0C0C0FF),
+    ("SKY_BLUE", 0x87CEEBFF),
+    ("SLATE_BLUE", 0x6A5ACDFF),
+    ("SLATE_GRAY", 0x708090FF),
+    ("SNOW", 0xFFFAFAFF),
+    ("SPRING_GREEN", 0x00FF7FFF),
+    ("STEEL_BLUE", 0x4682B4FF),
+    ("TAN", 0xD2B48CFF),
+    ("TEAL", 0x008080FF),
+    ("THISTLE", 0xD8BFD8FF),
+    ("TOMATO", 0xFF6347FF),
+    ("TRANSPARENT", 0xFFFFFF00),
+    ("TURQUOISE", 0x40E0D0FF),
+    ("VIOLET", 0xEE82EEFF),
+    ("WEB_GRAY", 0x808080FF),
+    ("WEB_GREEN", 0x008000FF),
+    ("WEB_MAROON", 0x800000FF),
+    ("WEB_PURPLE", 0x800080FF),
+    ("WHEAT", 0xF5DEB3FF),
+    ("WHITE", 0xFFFFFFFF),
+    ("WHITE_SMOKE", 0xF5F5F5FF),
+    ("YELLOW", 0xFFFF00FF),
+    ("YELLOW_GREEN", 0x9ACD32FF),
+];
+
+/// Combines two hex digits into a byte.
+fn hex_byte(high: u32, low: u32) -> u8 {
+    (high << 4 | low) as u8
+}
+
 /// Converts a single channel byte to a float in the range 0 to 1.
 fn from_u8(byte: u8) -> f32 {
     byte as f32 / 255.0
@@ -508,6 +805,299 @@ fn to_be_words(mut u: u64) -> [u16; 4] {
     [x, y, z, w]
 }
 
+/// Conversion from the OK HSL color space, ported from `thirdparty/misc/ok_color.h` in the engine (by Björn Ottosson, MIT).
+mod ok_color {
+    use std::f32::consts::PI;
+
+    /// Returns the gamma-encoded sRGB color for the OK HSL hue `h`, saturation `s` and lightness `l`.
+    pub(super) fn okhsl_to_srgb(h: f32, s: f32, l: f32) -> [f32; 3] {
+        if l == 1.0 {
+            return [1.0, 1.0, 1.0];
+        } else if l == 0.0 {
+            return [0.0, 0.0, 0.0];
+        }
+
+        let a_ = (2.0 * PI * h).cos();
+        let b_ = (2.0 * PI * h).sin();
+        let lightness = toe_inv(l);
+
+        let Cs { c_0, c_mid, c_max } = get_cs(lightness, a_, b_);
+
+        // Saturation 0.8 maps to `c_mid`; below and above, chroma is interpolated towards `c_0` and `c_max`.
+        const MID: f32 = 0.8;
+        const MID_INV: f32 = 1.25;
+
+        let chroma = if s < MID {
+            let t = MID_INV * s;
+            let k_1 = MID * c_0;
+            let k_2 = 1.0 - k_1 / c_mid;
+
+            t * k_1 / (1.0 - k_2 * t)
+        } else {
+            let t = (s - MID) / (1.0 - MID);
+            let k_0 = c_mid;
+            let k_1 = (1.0 - MID) * c_mid * c_mid * MID_INV * MID_INV / c_0;
+            let k_2 = 1.0 - k_1 / (c_max - c_mid);
+
+            k_0 + t * k_1 / (1.0 - k_2 * t)
+        };
+
+        oklab_to_linear_srgb(lightness, chroma * a_, chroma * b_).map(srgb_transfer_function)
+    }
+
+    fn srgb_transfer_function(a: f32) -> f32 {
+        if 0.0031308 >= a {
+            12.92 * a
+        } else {
+            1.055 * a.powf(0.416_666_66) - 0.055
+        }
+    }
+
+    /// Inverse of the lightness estimate used by OK HSL.
+    fn toe_inv(x: f32) -> f32 {
+        const K_1: f32 = 0.206;
+        const K_2: f32 = 0.03;
+        const K_3: f32 = (1.0 + K_1) / (1.0 + K_2);
+
+        (x * x + K_1 * x) / (K_3 * (x + K_2))
+    }
+
+    fn oklab_to_linear_srgb(lightness: f32, a: f32, b: f32) -> [f32; 3] {
+        let l_ = lightness + 0.396_337_78 * a + 0.215_803_76 * b;
+        let m_ = lightness - 0.105_561_346 * a - 0.063_854_17 * b;
+        let s_ = lightness - 0.089_484_18 * a - 1.291_485_5 * b;
+
+        let l = l_ * l_ * l_;
+        let m = m_ * m_ * m_;
+        let s = s_ * s_ * s_;
+
+        [
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            -0.004_196_086_4 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        ]
+    }
+
+    /// Finds the maximum saturation possible for a given hue that fits in sRGB.
+    ///
+    /// `a` and `b` must be normalized so `a^2 + b^2 == 1`.
+    fn compute_max_saturation(a: f32, b: f32) -> f32 {
+        // Max saturation will be when one of r, g or b goes below zero. Select different coefficients depending on which
+        // component goes below zero first.
+        let ([k0, k1, k2, k3, k4], [wl, wm, ws]) = if -1.881_703_3 * a - 0.809_364_9 * b > 1.0 {
+            // Red component
+            (
+                [
+                    1.190_862_8,
+                    1.765_767_3,
+                    0.596_626_4,
+                    0.755_152,
+                    0.567_712_4,
+                ],
+                [4.076_741_7, -3.307_711_6, 0.230_969_94],
+            )
+        } else if 1.814_441_1 * a - 1.194_452_8 * b > 1.0 {
+            // Green component
+            (
+                [
+                    0.739_565_13,
+                    -0.459_544_03,
+                    0.082_854_27,
+                    0.125_410_7,
+                    0.145_032_03,
+                ],
+                [-1.268_438, 2.609_757_4, -0.341_319_38],
+            )
+        } else {
+            // Blue component
+            (
+                [
+                    1.357_336_5,
+                    -0.009_157_99,
+                    -1.151_302_1,
+                    -0.505_596_04,
+                    0.006_921_67,
+                ],
+                [-0.004_196_086_4, -0.703_418_6, 1.707_614_7],
+            )
+        };
+
+        // Approximate max saturation using a polynomial.
+        let sat = k0 + k1 * a + k2 * b + k3 * a * a + k4 * a * b;
+
+        // Do one step Halley's method to get closer.
+        let k_l = 0.396_337_78 * a + 0.215_803_76 * b;
+        let k_m = -0.105_561_346 * a - 0.063_854_17 * b;
+        let k_s = -0.089_484_18 * a - 1.291_485_5 * b;
+
+        let l_ = 1.0 + sat * k_l;
+        let m_ = 1.0 + sat * k_m;
+        let s_ = 1.0 + sat * k_s;
+
+        let l = l_ * l_ * l_;
+        let m = m_ * m_ * m_;
+        let s = s_ * s_ * s_;
+
+        let l_ds = 3.0 * k_l * l_ * l_;
+        let m_ds = 3.0 * k_m * m_ * m_;
+        let s_ds = 3.0 * k_s * s_ * s_;
+
+        let l_ds2 = 6.0 * k_l * k_l * l_;
+        let m_ds2 = 6.0 * k_m * k_m * m_;
+        let s_ds2 = 6.0 * k_s * k_s * s_;
+
+        let f = wl * l + wm * m + ws * s;
+        let f1 = wl * l_ds + wm * m_ds + ws * s_ds;
+        let f2 = wl * l_ds2 + wm * m_ds2 + ws * s_ds2;
+
+        sat - f * f1 / (f1 * f1 - 0.5 * f * f2)
+    }
+
+    /// Finds the lightness and chroma of the cusp of the sRGB gamut triangle for a hue. `a` and `b` must be normalized.
+    fn find_cusp(a: f32, b: f32) -> (f32, f32) {
+        // First, find the maximum saturation (saturation S = C/L).
+        let s_cusp = compute_max_saturation(a, b);
+
+        // Convert to linear sRGB to find the first point where at least one of r, g or b >= 1.
+        let [r, g, b] = oklab_to_linear_srgb(1.0, s_cusp * a, s_cusp * b);
+        let l_cusp = (1.0 / r.max(g).max(b)).cbrt();
+
+        (l_cusp, l_cusp * s_cusp)
+    }
+
+    /// Finds the intersection of a line with the sRGB gamut. The line goes from `(l0, 0)` to `(l1, c1)` in lightness/chroma,
+    /// and `a`, `b` must be normalized.
+    fn find_gamut_intersection(a: f32, b: f32, l1: f32, c1: f32, l0: f32) -> f32 {
+        let (cusp_l, cusp_c) = find_cusp(a, b);
+
+        // Find the intersection for upper and lower half separately.
+        if ((l1 - l0) * cusp_c - (cusp_l - l0) * c1) <= 0.0 {
+            // Lower half
+            return cusp_c * l0 / (c1 * cusp_l + cusp_c * (l0 - l1));
+        }
+
+        // Upper half: first intersect with triangle, then refine with one step of Halley's method.
+        let t = cusp_c * (l0 - 1.0) / (c1 * (cusp_l - 1.0) + cusp_c * (l0 - l1));
+
+        let d_l = l1 - l0;
+        let d_c = c1;
+
+        let k_l = 0.396_337_78 * a + 0.215_803_76 * b;
+        let k_m = -0.105_561_346 * a - 0.063_854_17 * b;
+        let k_s = -0.089_484_18 * a - 1.291_485_5 * b;
+
+        let l_dt = d_l + d_c * k_l;
+        let m_dt = d_l + d_c * k_m;
+        let s_dt = d_l + d_c * k_s;
+
+        let lightness = l0 * (1.0 - t) + t * l1;
+        let chroma = t * c1;
+
+        let l_ = lightness + chroma * k_l;
+        let m_ = lightness + chroma * k_m;
+        let s_ = lightness + chroma * k_s;
+
+        let l = l_ * l_ * l_;
+        let m = m_ * m_ * m_;
+        let s = s_ * s_ * s_;
+
+        let ldt = 3.0 * l_dt * l_ * l_;
+        let mdt = 3.0 * m_dt * m_ * m_;
+        let sdt = 3.0 * s_dt * s_ * s_;
+
+        let ldt2 = 6.0 * l_dt * l_dt * l_;
+        let mdt2 = 6.0 * m_dt * m_dt * m_;
+        let sdt2 = 6.0 * s_dt * s_dt * s_;
+
+        // Step for the channel with weights `w`, or infinity if it moves away from the gamut boundary.
+        let halley_step = |w: [f32; 3]| {
+            let f = w[0] * l + w[1] * m + w[2] * s - 1.0;
+            let f1 = w[0] * ldt + w[1] * mdt + w[2] * sdt;
+            let f2 = w[0] * ldt2 + w[1] * mdt2 + w[2] * sdt2;
+
+            let u = f1 / (f1 * f1 - 0.5 * f * f2);
+            if u >= 0.0 {
+                -f * u
+            } else {
+                f32::MAX
+            }
+        };
+
+        let t_r = halley_step([4.076_741_7, -3.307_711_6, 0.230_969_94]);
+        let t_g = halley_step([-1.268_438, 2.609_757_4, -0.341_319_38]);
+        let t_b = halley_step([-0.004_196_086_4, -0.703_418_6, 1.707_614_7]);
+
+        t + t_r.min(t_g.min(t_b))
+    }
+
+    /// Returns `(S, T)` for the cusp, i.e. the slopes of the gamut triangle's lower and upper edge.
+    fn to_st(cusp_l: f32, cusp_c: f32) -> (f32, f32) {
+        (cusp_c / cusp_l, cusp_c / (1.0 - cusp_l))
+    }
+
+    /// Returns a smooth approximation of `(S, T)` at the location of the cusp. This polynomial was created by fitting
+    /// to the `to_st()` of the cusp.
+    fn get_st_mid(a_: f32, b_: f32) -> (f32, f32) {
+        let s = 0.115_169_93
+            + 1.0
+                / (7.447_789_7
+                    + 4.159_012_3 * b_
+                    + a_ * (-2.195_573_6
+                        + 1.751_984 * b_
+                        + a_ * (-2.137_049_4 - 10.023_01 * b_
+                            + a_ * (-4.248_945_7 + 5.387_708 * b_ + 4.698_91 * a_))));
+
+        let t = 0.112_396_42
+            + 1.0
+                / (1.613_203_2 - 0.681_243_8 * b_
+                    + a_ * (0.403_706_13
+                        + 0.901_481_2 * b_
+                        + a_ * (-0.270_879_42
+                            + 0.612_239_9 * b_
+                            + a_ * (0.002_992_15 - 0.453_995_67 * b_ - 0.146_618_72 * a_))));
+
+        (s, t)
+    }
+
+    struct Cs {
+        c_0: f32,
+        c_mid: f32,
+        c_max: f32,
+    }
+
+    fn get_cs(lightness: f32, a_: f32, b_: f32) -> Cs {
+        let (cusp_l, cusp_c) = find_cusp(a_, b_);
+
+        let c_max = find_gamut_intersection(a_, b_, lightness, 1.0, lightness);
+        let (st_max_s, st_max_t) = to_st(cusp_l, cusp_c);
+
+        // Scale factor to compensate for the curved part of gamut shape.
+        let k = c_max / (lightness * st_max_s).min((1.0 - lightness) * st_max_t);
+
+        // Use a soft minimum function, instead of a sharp triangle shape to get a smooth value for chroma.
+        let c_mid = {
+            let (st_mid_s, st_mid_t) = get_st_mid(a_, b_);
+            let c_a = lightness * st_mid_s;
+            let c_b = (1.0 - lightness) * st_mid_t;
+
+            let c_a4 = c_a * c_a * c_a * c_a;
+            let c_b4 = c_b * c_b * c_b * c_b;
+
+            0.9 * k * (1.0 / (1.0 / c_a4 + 1.0 / c_b4)).sqrt().sqrt()
+        };
+
+        // For `c_0`, the shape is independent of hue, so `(S, T)` are constant; roughly the average values.
+        let c_0 = {
+            let c_a = lightness * 0.4;
+            let c_b = (1.0 - lightness) * 0.8;
+
+            (1.0 / (1.0 / (c_a * c_a) + 1.0 / (c_b * c_b))).sqrt()
+        };
+
+        Cs { c_0, c_mid, c_max }
+    }
+}
+
 impl std::fmt::Display for Color {
     /// Formats `Color` to match Godot's string representation.
     ///
@@ -524,10 +1114,143 @@ impl std::fmt::Display for Color {
 
 #[cfg(test)]
 mod test {
+    use super::*;
+    use crate::assert_eq_approx;
+
+    #[test]
+    fn from_html() {
+        let expected = Color::from_rgba8(0xaa, 0xbb, 0xcc, 0xdd);
+        assert_eq!(Color::from_html("#abcd"), Some(expected));
+        assert_eq!(Color::from_html("AABBCCDD"), Some(expected));
+        assert_eq!(Color::from_html("#aabbcc"), Some(expected.with_alpha(1.0)));
+        assert_eq!(Color::from_html("abc"), Some(expected.with_alpha(1.0)));
+
+        assert_eq!(Color::from_html(""), None);
+        assert_eq!(Color::from_html("#"), None);
+        assert_eq!(Color::from_html("#abcde"), None);
+        assert_eq!(Color::from_html("#abcg"), None);
+        assert_eq!(Color::from_html("##abc"), None);
+    }
+
+    #[test]
+    fn from_string() {
+        assert_eq!(Color::from_string("white"), Some(Color::WHITE));
+        assert_eq!(
+            Color::from_string("Lawn-Green"),
+            Some(Color::from_rgba8(0x7c, 0xfc, 0x00, 0xff))
+        );
+        assert_eq!(
+            Color::from_string("web gray"),
+            Some(Color::from_rgba8(0x80, 0x80, 0x80, 0xff))
+        );
+        assert_eq!(
+            Color::from_string("TRANSPARENT"),
+            Some(Color::TRANSPARENT_WHITE)
+        );
+        assert_eq!(Color::from_string(""), None);
+        assert_eq!(Color::from_string("octarine"), None);
+    }
+
+    #[test]
+    fn to_html() {
+        let c = Color::from_rgba8(0x01, 0x23, 0xab, 0xff);
+        assert_eq!(c.to_html(), "0123abff");
+        assert_eq!(c.to_html_without_alpha(), "0123ab");
+        assert_eq!(Color::from_html(c.to_html()), Some(c));
+    }
+
+    #[test]
+    fn from_ok_hsl() {
+        assert_eq!(Color::from_ok_hsl(0.3, 0.7, 1.0), Color::WHITE);
+        assert_eq!(Color::from_ok_hsl(0.3, 0.7, 0.0), Color::BLACK);
+
+        // Without saturation, the hue does not matter.
+        let gray = Color::from_ok_hsl(0.0, 0.0, 0.5);
+        assert_eq_approx!(gray, Color::from_ok_hsl(0.6, 0.0, 0.5));
+        assert_eq_approx!(gray, Color::from_rgb(gray.r, gray.r, gray.r));
+
+        // Full saturation is on the edge of the sRGB gamut.
+        for h in [0.0, 0.25, 0.5, 0.75] {
+            let c = Color::from_ok_hsl(h, 1.0, 0.6);
+            let min = c.r.min(c.g).min(c.b);
+            let max = c.r.max(c.g).max(c.b);
+            assert!(min.approx_eq(&0.0) || max.approx_eq(&1.0), "{c}");
+        }
+    }
+
+    #[test]
+    fn from_hsv() {
+        assert_eq_approx!(
+            Color::from_hsv(0.0, 1.0, 1.0),
+            Color::from_rgb(1.0, 0.0, 0.0)
+        );
+        assert_eq_approx!(
+            Color::from_hsv(1.0 / 3.0, 1.0, 1.0),
+            Color::from_rgb(0.0, 1.0, 0.0)
+        );
+        assert_eq_approx!(
+            Color::from_hsv(2.0 / 3.0, 1.0, 0.5),
+            Color::from_rgb(0.0, 0.0, 0.5)
+        );
+        assert_eq_approx!(
+            Color::from_hsv(0.5, 0.0, 0.25),
+            Color::from_rgb(0.25, 0.25, 0.25)
+        );
+    }
+
+    #[test]
+    fn from_rgbe9995() {
+        // Exponent 15 (neutral) and mantissa 256 yield 0.5 in each channel.
+        let rgbe = 15 << 27 | 256 << 18 | 256 << 9 | 256;
+        assert_eq_approx!(Color::from_rgbe9995(rgbe), Color::from_rgb(0.5, 0.5, 0.5));
+    }
+
+    #[test]
+    fn blend() {
+        let red = Color::from_rgb(1.0, 0.0, 0.0);
+        let green = Color::from_rgb(0.0, 1.0, 0.0);
+        assert_eq!(red.blend(green), green);
+        assert_eq_approx!(
+            red.blend(green.with_alpha(0.5)),
+            Color::from_rgb(0.5, 0.5, 0.0)
+        );
+        assert_eq!(
+            Color::TRANSPARENT_WHITE.blend(Color::TRANSPARENT_WHITE),
+            Color::TRANSPARENT_BLACK
+        );
+    }
+
+    #[test]
+    fn adjustments() {
+        let c = Color::from_rgba(0.2, 0.4, 0.8, 0.5);
+        assert_eq_approx!(c.darkened(0.5), Color::from_rgba(0.1, 0.2, 0.4, 0.5));
+        assert_eq_approx!(c.lightened(0.5), Color::from_rgba(0.6, 0.7, 0.9, 0.5));
+        assert_eq_approx!(c.inverted(), Color::from_rgba(0.8, 0.6, 0.2, 0.5));
+        assert_eq_approx!(
+            c.lerp(Color::WHITE, 0.5),
+            Color::from_rgba(0.6, 0.7, 0.9, 0.75)
+        );
+        assert_eq_approx!(
+            c.clamp(
+                Color::from_rgba(0.3, 0.0, 0.0, 0.0),
+                Color::from_rgba(1.0, 1.0, 0.5, 1.0)
+            ),
+            Color::from_rgba(0.3, 0.4, 0.5, 0.5)
+        );
+        assert_eq_approx!(c.luminance(), 0.2126 * 0.2 + 0.7152 * 0.4 + 0.0722 * 0.8);
+    }
+
+    #[test]
+    fn srgb_roundtrip() {
+        let c = Color::from_rgba(0.001, 0.2, 0.9, 0.3);
+        assert_eq_approx!(c.linear_to_srgb().srgb_to_linear(), c);
+        assert_eq_approx!(c.srgb_to_linear().linear_to_srgb(), c);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
-        let color = super::Color::WHITE;
+        let color = Color::WHITE;
         let expected_json = "{\"r\":1.0,\"g\":1.0,\"b\":1.0,\"a\":1.0}";
 
         crate::builtin::test_utils::roundtrip(&color, expected_json);
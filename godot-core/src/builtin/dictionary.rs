@@ -6,39 +6,61 @@
 
 use godot_ffi as sys;
 
-use crate::builtin::{inner, FromVariant, ToVariant, Variant};
-use crate::obj::Share;
-use crate::property::{Export, ExportInfo, Property};
+use crate::builtin::meta::VariantMetadata;
+use crate::builtin::*;
+use crate::obj::{EngineEnum, Gd, GodotClass, Share};
+use crate::property::{Export, ExportInfo, Property, TypeStringHint};
 use std::fmt;
 use std::marker::PhantomData;
 use std::ptr::addr_of_mut;
 use sys::types::OpaqueDictionary;
 use sys::{ffi_methods, interface_fn, AsUninit, GodotFfi};
 
-use super::VariantArray;
+#[cfg(since_api = "4.4")]
+use super::array_inner::TypeInfo;
 
 /// Godot's `Dictionary` type.
 ///
-/// The keys and values of the dictionary are all `Variant`s, so they can be of different types.
-/// Variants are designed to be generally cheap to clone.
+/// # Typed dictionaries
+///
+/// An untyped dictionary can contain keys and values of any kind of [`Variant`], even different types in the same
+/// dictionary. We represent this in Rust as `Dictionary`, which is short for `Dictionary<Variant, Variant>`.
+///
+/// A `Dictionary<K, V>` only accepts keys of type `K` and values of type `V`, and converts them back when they are read.
+/// Since Godot 4.4, the engine supports typed dictionaries as well, and checks at runtime that no keys or values of the
+/// wrong type are put into them. Before 4.4, such a dictionary is untyped on the Godot side; entries of the wrong type
+/// that were inserted elsewhere (e.g. from GDScript) cause a panic once they are converted.
+///
+/// Due to the default type parameters, `Dictionary` in type position means the untyped dictionary. Like `HashMap::new()`
+/// for the default hasher, [`Dictionary::new()`] only exists for the untyped dictionary, so its type is always inferred.
+/// Typed dictionaries are created with `Dictionary::<K, V>::default()` or by collecting an iterator. For other trait
+/// functions, the types must be inferable, e.g. `let dict: Dictionary = Dictionary::default();` or `<Dictionary>::default()`.
 ///
 /// # Thread safety
 ///
 /// The same principles apply as for [`VariantArray`]. Consult its documentation for details.
 #[repr(C)]
-pub struct Dictionary {
+pub struct Dictionary<K: VariantMetadata = Variant, V: VariantMetadata = Variant> {
     opaque: OpaqueDictionary,
+    _phantom: PhantomData<(K, V)>,
 }
 
 impl Dictionary {
-    fn from_opaque(opaque: OpaqueDictionary) -> Self {
-        Self { opaque }
-    }
-
-    /// Constructs an empty `Dictionary`.
+    /// Constructs an empty untyped `Dictionary`.
+    ///
+    /// To construct a typed dictionary, use `Dictionary::<K, V>::default()`.
     pub fn new() -> Self {
         Self::default()
     }
+}
+
+impl<K: VariantMetadata, V: VariantMetadata> Dictionary<K, V> {
+    fn from_opaque(opaque: OpaqueDictionary) -> Self {
+        Self {
+            opaque,
+            _phantom: PhantomData,
+        }
+    }
 
     /// Removes all key-value pairs from the dictionary.
     pub fn clear(&mut self) {
@@ -54,7 +76,9 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `dict.duplicate(true)`_
     pub fn duplicate_deep(&self) -> Self {
-        self.as_inner().duplicate(true)
+        let duplicate = self.as_inner().duplicate(true);
+        // SAFETY: duplicate() returns a dictionary with the same key and value types as Self.
+        unsafe { duplicate.assume_type() }
     }
 
     /// Returns a shallow copy of the dictionary. All dictionary keys and values are copied, but
@@ -66,51 +90,9 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `dict.duplicate(false)`_
     pub fn duplicate_shallow(&self) -> Self {
-        self.as_inner().duplicate(false)
-    }
-
-    /// Removes a key from the map, and returns the value associated with
-    /// the key if the key was in the dictionary.
-    ///
-    /// _Godot equivalent: `erase`_
-    #[doc(alias = "erase")]
-    pub fn remove<K: ToVariant>(&mut self, key: K) -> Option<Variant> {
-        let key = key.to_variant();
-        let old_value = self.get(key.clone());
-        self.as_inner().erase(key);
-        old_value
-    }
-
-    /// Reverse-search a key by its value.
-    ///
-    /// Unlike Godot, this will return `None` if the key does not exist and `Some(Variant::nil())` the key is `NIL`.
-    ///
-    /// This operation is rarely needed and very inefficient. If you find yourself needing it a lot, consider
-    /// using a `HashMap` or `Dictionary` with the inverse mapping (`V` -> `K`).
-    ///
-    /// _Godot equivalent: `find_key`_
-    #[doc(alias = "find_key")]
-    pub fn find_key_by_value<V: ToVariant>(&self, value: V) -> Option<Variant> {
-        let key = self.as_inner().find_key(value.to_variant());
-
-        if !key.is_nil() || self.contains_key(key.clone()) {
-            Some(key)
-        } else {
-            None
-        }
-    }
-
-    /// Returns the value for the given key, or `None`.
-    ///
-    /// Note that `NIL` values are returned as `Some(Variant::nil())`, while absent values are returned as `None`.
-    /// If you want to treat both as `NIL`, use [`Self::get_or_nil`].
-    pub fn get<K: ToVariant>(&self, key: K) -> Option<Variant> {
-        let key = key.to_variant();
-        if !self.contains_key(key.clone()) {
-            return None;
-        }
-
-        Some(self.get_or_nil(key))
+        let duplicate = self.as_inner().duplicate(false);
+        // SAFETY: duplicate() returns a dictionary with the same key and value types as Self.
+        unsafe { duplicate.assume_type() }
     }
 
     /// Returns the value at the key in the dictionary, or `NIL` otherwise.
@@ -120,15 +102,17 @@ impl Dictionary {
     ///
     /// _Godot equivalent: `dict.get(key, null)`_
     #[doc(alias = "get")]
-    pub fn get_or_nil<K: ToVariant>(&self, key: K) -> Variant {
+    pub fn get_or_nil<Q: ToVariant>(&self, key: Q) -> Variant {
         self.as_inner().get(key.to_variant(), Variant::nil())
     }
 
     /// Returns `true` if the dictionary contains the given key.
     ///
+    /// Like all lookups, this accepts any key type; keys not convertible to `K` are simply not found.
+    ///
     /// _Godot equivalent: `has`_
     #[doc(alias = "has")]
-    pub fn contains_key<K: ToVariant>(&self, key: K) -> bool {
+    pub fn contains_key<Q: ToVariant>(&self, key: Q) -> bool {
         let key = key.to_variant();
         self.as_inner().has(key)
     }
@@ -174,6 +158,8 @@ impl Dictionary {
     /// _Godot equivalent: `merge`_
     #[doc(alias = "merge")]
     pub fn extend_dictionary(&mut self, other: Self, overwrite: bool) {
+        // SAFETY: merge() only reads from `other`, and its entries have the same types as those in `self`.
+        let other: Dictionary = unsafe { other.assume_type() };
         self.as_inner().merge(other, overwrite)
     }
 
@@ -185,70 +171,294 @@ impl Dictionary {
         self.as_inner().size().try_into().unwrap()
     }
 
-    /// Insert a value at the given key, returning the previous value for that key (if available).
+    #[doc(hidden)]
+    pub fn as_inner(&self) -> inner::InnerDictionary {
+        // SAFETY: The memory layout of `Dictionary<K, V>` does not depend on `K` and `V`.
+        inner::InnerDictionary::from_outer_typed(self)
+    }
+
+    /// Changes the key and value types of this dictionary, without changing its contents.
     ///
-    /// If you don't need the previous value, use [`Self::set`] instead.
-    pub fn insert<K: ToVariant, V: ToVariant>(&mut self, key: K, value: V) -> Option<Variant> {
+    /// # Safety
+    ///
+    /// The same considerations as for `Array::assume_type()` apply: the returned dictionary should not be written to
+    /// unless `K` and `V` are `Variant`, and not be read from unless `K2` and `V2` are `Variant` or match the actual entries.
+    unsafe fn assume_type<K2: VariantMetadata, V2: VariantMetadata>(self) -> Dictionary<K2, V2> {
+        // SAFETY: The memory layout of `Dictionary<K, V>` does not depend on `K` and `V`.
+        unsafe { std::mem::transmute(self) }
+    }
+
+    /// Get the pointer corresponding to the given key in the dictionary.
+    ///
+    /// If there exists no value at the given key, a `NIL` variant will be inserted for that key.
+    fn get_ptr_mut(&mut self, key: Variant) -> *mut Variant {
+        // SAFETY: accessing an unknown key _mutably_ creates that entry in the dictionary, with value `NIL`.
+        let ptr = unsafe {
+            interface_fn!(dictionary_operator_index)(self.sys_mut(), key.var_sys_const())
+        };
+
+        // Never a null pointer, since entry either existed already or was inserted above.
+        Variant::ptr_from_sys_mut(ptr)
+    }
+
+    /// Returns the runtime type info of the keys and values of this dictionary.
+    #[cfg(since_api = "4.4")]
+    fn type_info(&self) -> (TypeInfo, TypeInfo) {
+        let inner = self.as_inner();
+
+        let key = TypeInfo {
+            variant_type: VariantType::from_sys(
+                inner.get_typed_key_builtin() as sys::GDExtensionVariantType
+            ),
+            class_name: inner.get_typed_key_class_name(),
+        };
+        let value = TypeInfo {
+            variant_type: VariantType::from_sys(
+                inner.get_typed_value_builtin() as sys::GDExtensionVariantType
+            ),
+            class_name: inner.get_typed_value_class_name(),
+        };
+
+        (key, value)
+    }
+
+    /// Checks that the inner dictionary has the correct types set on it for storing keys of type `K` and values of type `V`.
+    ///
+    /// Before Godot 4.4, dictionaries carry no type information, so there is nothing to check.
+    fn with_checked_type(self) -> Result<Self, VariantConversionError> {
+        #[cfg(since_api = "4.4")]
+        if self.type_info() != (TypeInfo::of::<K>(), TypeInfo::of::<V>()) {
+            return Err(VariantConversionError::BadType);
+        }
+
+        Ok(self)
+    }
+
+    /// Sets the key and value types of the inner dictionary. Can only be called once, directly after creation.
+    ///
+    /// Before Godot 4.4, this does nothing, as the engine does not support typed dictionaries.
+    fn init_inner_type(&mut self) {
+        debug_assert!(self.is_empty());
+
+        #[cfg(since_api = "4.4")]
+        {
+            let key_info = TypeInfo::of::<K>();
+            let value_info = TypeInfo::of::<V>();
+
+            if key_info.is_typed() || value_info.is_typed() {
+                let script = Variant::nil();
+                unsafe {
+                    interface_fn!(dictionary_set_typed)(
+                        self.sys(),
+                        key_info.variant_type.sys(),
+                        key_info.class_name.string_sys(),
+                        script.var_sys(),
+                        value_info.variant_type.sys(),
+                        value_info.class_name.string_sys(),
+                        script.var_sys(),
+                    );
+                }
+            }
+        }
+    }
+}
+
+impl<K: VariantMetadata + FromVariant, V: VariantMetadata + FromVariant> Dictionary<K, V> {
+    /// Removes a key from the map, and returns the value associated with
+    /// the key if the key was in the dictionary.
+    ///
+    /// _Godot equivalent: `erase`_
+    #[doc(alias = "erase")]
+    pub fn remove<Q: ToVariant>(&mut self, key: Q) -> Option<V> {
         let key = key.to_variant();
         let old_value = self.get(key.clone());
-        self.set(key, value);
+        self.as_inner().erase(key);
         old_value
     }
 
-    /// Set a key to a given value.
+    /// Reverse-search a key by its value.
     ///
-    /// If you are interested in the previous value, use [`Self::insert`] instead.
+    /// Unlike Godot, this will return `None` if the key does not exist and `Some(Variant::nil())` the key is `NIL`.
     ///
-    /// _Godot equivalent: `dict[key] = value`_
-    pub fn set<K: ToVariant, V: ToVariant>(&mut self, key: K, value: V) {
-        let key = key.to_variant();
+    /// This operation is rarely needed and very inefficient. If you find yourself needing it a lot, consider
+    /// using a `HashMap` or `Dictionary` with the inverse mapping (`V` -> `K`).
+    ///
+    /// _Godot equivalent: `find_key`_
+    #[doc(alias = "find_key")]
+    pub fn find_key_by_value<Q: ToVariant>(&self, value: Q) -> Option<K> {
+        let key = self.as_inner().find_key(value.to_variant());
 
-        // SAFETY: always returns a valid pointer to a value in the dictionary; either pre-existing or newly inserted.
-        unsafe {
-            *self.get_ptr_mut(key) = value.to_variant();
+        if !key.is_nil() || self.contains_key(key.clone()) {
+            Some(K::from_variant(&key))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value for the given key, or `None`.
+    ///
+    /// Note that `NIL` values are returned as `Some(Variant::nil())`, while absent values are returned as `None`.
+    /// If you want to treat both as `NIL`, use [`Self::get_or_nil`].
+    pub fn get<Q: ToVariant>(&self, key: Q) -> Option<V> {
+        let key = key.to_variant();
+        if !self.contains_key(key.clone()) {
+            return None;
         }
+
+        Some(V::from_variant(&self.get_or_nil(key)))
     }
 
-    /// Returns an iterator over the key-value pairs of the `Dictionary`. The pairs are each of type `(Variant, Variant)`.
+    /// Returns an iterator over the key-value pairs of the `Dictionary`. The pairs are each of type `(K, V)`.
     /// Each pair references the original `Dictionary`, but instead of a `&`-reference to key-value pairs as
     /// you might expect, the iterator returns a (cheap, shallow) copy of each key-value pair.
     ///
     /// Note that it's possible to modify the `Dictionary` through another reference while iterating
     /// over it. This will not result in unsoundness or crashes, but will cause the iterator to
     /// behave in an unspecified way.
-    pub fn iter_shared(&self) -> Iter<'_> {
+    pub fn iter_shared(&self) -> Iter<'_, K, V> {
         Iter::new(self)
     }
 
-    /// Returns an iterator over the keys `Dictionary`. The keys are each of type `Variant`. Each key references
+    /// Returns an iterator over the keys `Dictionary`. The keys are each of type `K`. Each key references
     /// the original `Dictionary`, but instead of a `&`-reference to keys pairs as you might expect, the
     /// iterator returns a (cheap, shallow) copy of each key pair.
     ///
     /// Note that it's possible to modify the `Dictionary` through another reference while iterating
     /// over it. This will not result in unsoundness or crashes, but will cause the iterator to
     /// behave in an unspecified way.
-    pub fn keys_shared(&self) -> Keys<'_> {
+    pub fn keys_shared(&self) -> Keys<'_, K> {
         Keys::new(self)
     }
+}
 
-    #[doc(hidden)]
-    pub fn as_inner(&self) -> inner::InnerDictionary {
-        inner::InnerDictionary::from_outer(self)
+impl<K: VariantMetadata, V: VariantMetadata> Dictionary<K, V> {
+    /// Insert a value at the given key, returning the previous value for that key (if available).
+    ///
+    /// If you don't need the previous value, use [`Self::set`] instead.
+    pub fn insert<KA, VA>(&mut self, key: KA, value: VA) -> Option<V>
+    where
+        KA: DictionaryArg<K>,
+        VA: DictionaryArg<V>,
+        V: FromVariant,
+    {
+        let key = key.into_dictionary_variant();
+        let old_value = self
+            .contains_key(key.clone())
+            .then(|| V::from_variant(&self.get_or_nil(key.clone())));
+
+        self.set_variant(key, value.into_dictionary_variant());
+        old_value
     }
 
-    /// Get the pointer corresponding to the given key in the dictionary.
+    /// Set a key to a given value.
     ///
-    /// If there exists no value at the given key, a `NIL` variant will be inserted for that key.
-    fn get_ptr_mut<K: ToVariant>(&mut self, key: K) -> *mut Variant {
-        let key = key.to_variant();
+    /// If you are interested in the previous value, use [`Self::insert`] instead.
+    ///
+    /// _Godot equivalent: `dict[key] = value`_
+    pub fn set<KA: DictionaryArg<K>, VA: DictionaryArg<V>>(&mut self, key: KA, value: VA) {
+        self.set_variant(
+            key.into_dictionary_variant(),
+            value.into_dictionary_variant(),
+        )
+    }
 
-        // SAFETY: accessing an unknown key _mutably_ creates that entry in the dictionary, with value `NIL`.
-        let ptr = unsafe {
-            interface_fn!(dictionary_operator_index)(self.sys_mut(), key.var_sys_const())
-        };
+    fn set_variant(&mut self, key: Variant, value: Variant) {
+        // SAFETY: always returns a valid pointer to a value in the dictionary; either pre-existing or newly inserted.
+        unsafe {
+            *self.get_ptr_mut(key) = value;
+        }
+    }
+}
 
-        // Never a null pointer, since entry either existed already or was inserted above.
-        Variant::ptr_from_sys_mut(ptr)
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Arguments
+
+/// Value that can be stored as a key or value of type `T` in a [`Dictionary`].
+///
+/// Untyped slots (`T` = `Variant`) accept anything that implements [`ToVariant`]. Typed slots accept values of exactly
+/// the type `T`; string types additionally accept `&str`. This lets `Dictionary` keep its dynamic API, while
+/// `Dictionary<K, V>` rejects keys and values of the wrong type at compile time.
+pub trait DictionaryArg<T> {
+    /// Converts the argument to the variant stored in the dictionary.
+    fn into_dictionary_variant(self) -> Variant;
+}
+
+impl<A: ToVariant> DictionaryArg<Variant> for A {
+    fn into_dictionary_variant(self) -> Variant {
+        self.to_variant()
+    }
+}
+
+macro_rules! impl_dictionary_arg {
+    ($($T:ty),* $(,)?) => {
+        $(
+            impl DictionaryArg<$T> for $T {
+                fn into_dictionary_variant(self) -> Variant {
+                    self.to_variant()
+                }
+            }
+        )*
+    };
+}
+
+impl_dictionary_arg! {
+    bool, i8, i16, i32, i64, u8, u16, u32, f32, f64,
+    GodotString, StringName, NodePath,
+    Vector2, Vector2i, Vector3, Vector3i, Vector4, Vector4i,
+    Rect2, Rect2i, Aabb, Plane, Quaternion, Basis, Transform2D, Transform3D, Projection,
+    Color, Rid, Callable, Signal,
+    PackedByteArray, PackedInt32Array, PackedInt64Array, PackedFloat32Array, PackedFloat64Array,
+    PackedStringArray, PackedVector2Array, PackedVector3Array, PackedColorArray,
+}
+
+impl DictionaryArg<GodotString> for &str {
+    fn into_dictionary_variant(self) -> Variant {
+        self.to_variant()
+    }
+}
+
+impl DictionaryArg<StringName> for &str {
+    fn into_dictionary_variant(self) -> Variant {
+        StringName::from(self).to_variant()
+    }
+}
+
+impl DictionaryArg<NodePath> for &str {
+    fn into_dictionary_variant(self) -> Variant {
+        NodePath::from(self).to_variant()
+    }
+}
+
+impl<T: VariantMetadata> DictionaryArg<Array<T>> for Array<T> {
+    fn into_dictionary_variant(self) -> Variant {
+        self.to_variant()
+    }
+}
+
+impl<K: VariantMetadata, V: VariantMetadata> DictionaryArg<Dictionary<K, V>> for Dictionary<K, V> {
+    fn into_dictionary_variant(self) -> Variant {
+        self.to_variant()
+    }
+}
+
+impl<T: GodotClass> DictionaryArg<Gd<T>> for Gd<T> {
+    fn into_dictionary_variant(self) -> Variant {
+        self.to_variant()
+    }
+}
+
+impl<T> DictionaryArg<Option<T>> for Option<T>
+where
+    Option<T>: ToVariant,
+{
+    fn into_dictionary_variant(self) -> Variant {
+        self.to_variant()
+    }
+}
+
+impl<E: EngineEnum> DictionaryArg<E> for E {
+    fn into_dictionary_variant(self) -> Variant {
+        self.to_variant()
     }
 }
 
@@ -264,7 +474,7 @@ impl Dictionary {
 //   Dictionaries are properly initialized through a `from_sys` call, but the ref-count should be
 //   incremented as that is the callee's responsibility. Which we do by calling
 //   `std::mem::forget(dictionary.clone())`.
-unsafe impl GodotFfi for Dictionary {
+unsafe impl<K: VariantMetadata, V: VariantMetadata> GodotFfi for Dictionary<K, V> {
     ffi_methods! { type sys::GDExtensionTypePtr = *mut Opaque;
         fn from_sys;
         fn from_sys_init;
@@ -285,15 +495,45 @@ unsafe impl GodotFfi for Dictionary {
     }
 }
 
-impl_builtin_traits! {
-    for Dictionary {
-        Default => dictionary_construct_default;
-        Drop => dictionary_destroy;
-        PartialEq => dictionary_operator_equal;
+// `impl_builtin_traits!` does not support generics, so Default, Drop and PartialEq are implemented by hand (like for `Array`).
+impl<K: VariantMetadata, V: VariantMetadata> Default for Dictionary<K, V> {
+    #[inline]
+    fn default() -> Self {
+        let mut dictionary = unsafe {
+            Self::from_sys_init(|self_ptr| {
+                let ctor = sys::builtin_fn!(dictionary_construct_default);
+                ctor(self_ptr, std::ptr::null_mut())
+            })
+        };
+        dictionary.init_inner_type();
+        dictionary
     }
 }
 
-impl fmt::Debug for Dictionary {
+impl<K: VariantMetadata, V: VariantMetadata> Drop for Dictionary<K, V> {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe {
+            let dictionary_destroy = sys::builtin_fn!(dictionary_destroy);
+            dictionary_destroy(self.sys_mut());
+        }
+    }
+}
+
+impl<K: VariantMetadata, V: VariantMetadata> PartialEq for Dictionary<K, V> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        unsafe {
+            let mut result = false;
+            sys::builtin_call! {
+                dictionary_operator_equal(self.sys(), other.sys(), result.sys_mut())
+            }
+            result
+        }
+    }
+}
+
+impl<K: VariantMetadata, V: VariantMetadata> fmt::Debug for Dictionary<K, V> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:?}", self.to_variant().stringify())
     }
@@ -304,26 +544,30 @@ impl fmt::Debug for Dictionary {
 ///
 /// To create a (mostly) independent copy instead, see [`Dictionary::duplicate_shallow()`] and
 /// [`Dictionary::duplicate_deep()`].
-impl Clone for Dictionary {
+impl<K: VariantMetadata, V: VariantMetadata> Clone for Dictionary<K, V> {
     fn clone(&self) -> Self {
         // SAFETY: `self` is a valid dictionary, since we have a reference that keeps it alive.
-        unsafe {
+        let dictionary = unsafe {
             Self::from_sys_init(|self_ptr| {
                 let ctor = sys::builtin_fn!(dictionary_construct_copy);
                 let args = [self.sys_const()];
                 ctor(self_ptr, args.as_ptr());
             })
-        }
+        };
+
+        dictionary
+            .with_checked_type()
+            .expect("copied dictionary should have same types as original dictionary")
     }
 }
 
-impl Share for Dictionary {
+impl<K: VariantMetadata, V: VariantMetadata> Share for Dictionary<K, V> {
     fn share(&self) -> Self {
         self.clone()
     }
 }
 
-impl Property for Dictionary {
+impl<K: VariantMetadata, V: VariantMetadata> Property for Dictionary<K, V> {
     type Intermediate = Self;
 
     fn get_property(&self) -> Self::Intermediate {
@@ -335,15 +579,71 @@ impl Property for Dictionary {
     }
 }
 
-impl Export for Dictionary {
+/// Exports a typed dictionary, using the key and value types as hint (`PROPERTY_HINT_DICTIONARY_TYPE`).
+///
+/// The editor only knows typed dictionaries since Godot 4.4; on earlier versions, the property is shown as untyped.
+impl<K, V> Export for Dictionary<K, V>
+where
+    K: VariantMetadata + TypeStringHint,
+    V: VariantMetadata + TypeStringHint,
+{
+    #[cfg(since_api = "4.4")]
+    fn default_export_info() -> ExportInfo {
+        ExportInfo {
+            hint: crate::engine::global::PropertyHint::PROPERTY_HINT_DICTIONARY_TYPE,
+            hint_string: format!("{};{}", K::type_string(), V::type_string()).into(),
+        }
+    }
+
+    #[cfg(before_api = "4.4")]
+    fn default_export_info() -> ExportInfo {
+        ExportInfo::with_hint_none()
+    }
+}
+
+impl Export for Dictionary<Variant, Variant> {
     fn default_export_info() -> ExportInfo {
         ExportInfo::with_hint_none()
     }
 }
 
+impl<K: VariantMetadata, V: VariantMetadata> VariantMetadata for Dictionary<K, V> {
+    fn variant_type() -> VariantType {
+        VariantType::Dictionary
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 // Conversion traits
 
+impl<K: VariantMetadata, V: VariantMetadata> ToVariant for Dictionary<K, V> {
+    fn to_variant(&self) -> Variant {
+        unsafe {
+            Variant::from_var_sys_init(|variant_ptr| {
+                let dictionary_to_variant = sys::builtin_fn!(dictionary_to_variant);
+                dictionary_to_variant(variant_ptr, self.sys());
+            })
+        }
+    }
+}
+
+impl<K: VariantMetadata, V: VariantMetadata> FromVariant for Dictionary<K, V> {
+    fn try_from_variant(variant: &Variant) -> Result<Self, VariantConversionError> {
+        if variant.get_type() != Self::variant_type() {
+            return Err(VariantConversionError::BadType);
+        }
+
+        let dictionary = unsafe {
+            sys::from_sys_init_or_init_default::<Self>(|self_ptr| {
+                let dictionary_from_variant = sys::builtin_fn!(dictionary_from_variant);
+                dictionary_from_variant(self_ptr, variant.var_sys());
+            })
+        };
+
+        dictionary.with_checked_type()
+    }
+}
+
 /// Creates a dictionary from the given iterator `I` over a `(&K, &V)` key-value pair.
 ///
 /// Each key and value are converted to a `Variant`.
@@ -365,36 +665,83 @@ where
 ///
 /// Inserts all key-value pairs from the iterator into the dictionary. Previous values for keys appearing
 /// in `iter` will be overwritten.
-impl<K: ToVariant, V: ToVariant> Extend<(K, V)> for Dictionary {
-    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+impl<K, V, KA, VA> Extend<(KA, VA)> for Dictionary<K, V>
+where
+    K: VariantMetadata,
+    V: VariantMetadata,
+    KA: DictionaryArg<K>,
+    VA: DictionaryArg<V>,
+{
+    fn extend<I: IntoIterator<Item = (KA, VA)>>(&mut self, iter: I) {
         for (k, v) in iter.into_iter() {
-            self.set(k.to_variant(), v.to_variant())
+            self.set(k, v)
         }
     }
 }
 
-impl<K: ToVariant, V: ToVariant> FromIterator<(K, V)> for Dictionary {
-    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
-        let mut dict = Dictionary::new();
+impl<K, V, KA, VA> FromIterator<(KA, VA)> for Dictionary<K, V>
+where
+    K: VariantMetadata,
+    V: VariantMetadata,
+    KA: DictionaryArg<K>,
+    VA: DictionaryArg<V>,
+{
+    fn from_iter<I: IntoIterator<Item = (KA, VA)>>(iter: I) -> Self {
+        let mut dict = Self::default();
         dict.extend(iter);
         dict
     }
 }
 
+/// Serialized as a sequence of `(key, value)` pairs, in iteration order.
+///
+/// Not a map, because keys can be arbitrary variants, which many formats (e.g. JSON) do not support as map keys.
+#[cfg(feature = "serde")]
+impl<K, V> serde::Serialize for Dictionary<K, V>
+where
+    K: VariantMetadata + FromVariant + serde::Serialize,
+    V: VariantMetadata + FromVariant + serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeSeq as _;
+
+        // The iterator has no exact size hint, but formats without delimiters need the length upfront.
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for element in self.iter_shared() {
+            seq.serialize_element(&element)?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, K, V> serde::Deserialize<'de> for Dictionary<K, V>
+where
+    K: VariantMetadata + DictionaryArg<K> + serde::Deserialize<'de>,
+    V: VariantMetadata + DictionaryArg<V> + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pairs = <Vec<(K, V)> as serde::Deserialize>::deserialize(deserializer)?;
+        Ok(pairs.into_iter().collect())
+    }
+}
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 /// Internal helper for different iterator impls -- not an iterator itself
 struct DictionaryIter<'a> {
     last_key: Option<Variant>,
-    dictionary: &'a Dictionary,
+    dictionary: inner::InnerDictionary<'a>,
+    dictionary_variant: Variant,
     is_first: bool,
 }
 
 impl<'a> DictionaryIter<'a> {
-    fn new(dictionary: &'a Dictionary) -> Self {
+    fn new<K: VariantMetadata, V: VariantMetadata>(dictionary: &'a Dictionary<K, V>) -> Self {
         Self {
             last_key: None,
-            dictionary,
+            dictionary: dictionary.as_inner(),
+            dictionary_variant: dictionary.to_variant(),
             is_first: true,
         }
     }
@@ -402,9 +749,9 @@ impl<'a> DictionaryIter<'a> {
     fn next_key(&mut self) -> Option<Variant> {
         let new_key = if self.is_first {
             self.is_first = false;
-            Self::call_init(self.dictionary)
+            Self::call_init(&self.dictionary_variant)
         } else {
-            Self::call_next(self.dictionary, self.last_key.take()?)
+            Self::call_next(&self.dictionary_variant, self.last_key.take()?)
         };
 
         self.last_key = new_key.clone();
@@ -413,15 +760,15 @@ impl<'a> DictionaryIter<'a> {
 
     fn next_key_value(&mut self) -> Option<(Variant, Variant)> {
         let key = self.next_key()?;
-        if !self.dictionary.contains_key(key.clone()) {
+        if !self.dictionary.has(key.clone()) {
             return None;
         }
 
-        let value = self.dictionary.as_inner().get(key.clone(), Variant::nil());
+        let value = self.dictionary.get(key.clone(), Variant::nil());
         Some((key, value))
     }
 
-    fn call_init(dictionary: &Dictionary) -> Option<Variant> {
+    fn call_init(dictionary: &Variant) -> Option<Variant> {
         let variant: Variant = Variant::nil();
         let iter_fn = |dictionary, next_value: sys::GDExtensionVariantPtr, valid| unsafe {
             interface_fn!(variant_iter_init)(dictionary, next_value.as_uninit(), valid)
@@ -430,7 +777,7 @@ impl<'a> DictionaryIter<'a> {
         Self::ffi_iterate(iter_fn, dictionary, variant)
     }
 
-    fn call_next(dictionary: &Dictionary, last_key: Variant) -> Option<Variant> {
+    fn call_next(dictionary: &Variant, last_key: Variant) -> Option<Variant> {
         let iter_fn = |dictionary, next_value, valid| unsafe {
             interface_fn!(variant_iter_next)(dictionary, next_value, valid)
         };
@@ -448,14 +795,13 @@ impl<'a> DictionaryIter<'a> {
             sys::GDExtensionVariantPtr,
             *mut sys::GDExtensionBool,
         ) -> sys::GDExtensionBool,
-        dictionary: &Dictionary,
+        dictionary: &Variant,
         next_value: Variant,
     ) -> Option<Variant> {
-        let dictionary = dictionary.to_variant();
         let mut valid_u8: u8 = 0;
 
         // SAFETY:
-        // `dictionary` is a valid `Dictionary` since we have a reference to it,
+        // `dictionary` is a valid `Dictionary` variant, since the iterator holds a reference to the dictionary,
         //    so this will call the implementation for dictionaries.
         // `last_key` is an initialized and valid `Variant`, since we own a copy of it.
         let has_next = unsafe {
@@ -482,29 +828,33 @@ impl<'a> DictionaryIter<'a> {
 /// An iterator over key-value pairs from a `Dictionary`.
 ///
 /// See [Dictionary::iter_shared()] for more information about iteration over dictionaries.
-pub struct Iter<'a> {
+pub struct Iter<'a, K = Variant, V = Variant> {
     iter: DictionaryIter<'a>,
+    _phantom: PhantomData<(K, V)>,
 }
 
-impl<'a> Iter<'a> {
-    fn new(dictionary: &'a Dictionary) -> Self {
+impl<'a, K, V> Iter<'a, K, V> {
+    fn new<DK: VariantMetadata, DV: VariantMetadata>(dictionary: &'a Dictionary<DK, DV>) -> Self {
         Self {
             iter: DictionaryIter::new(dictionary),
+            _phantom: PhantomData,
         }
     }
 
-    /// Creates an iterator that converts each `(Variant, Variant)` key-value pair into a `(K, V)` key-value
-    /// pair, panicking upon conversion failure.
-    pub fn typed<K: FromVariant, V: FromVariant>(self) -> TypedIter<'a, K, V> {
+    /// Creates an iterator that converts each key-value pair into a `(K2, V2)` key-value pair, panicking upon
+    /// conversion failure.
+    pub fn typed<K2: FromVariant, V2: FromVariant>(self) -> TypedIter<'a, K2, V2> {
         TypedIter::from_untyped(self)
     }
 }
 
-impl<'a> Iterator for Iter<'a> {
-    type Item = (Variant, Variant);
+impl<'a, K: FromVariant, V: FromVariant> Iterator for Iter<'a, K, V> {
+    type Item = (K, V);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next_key_value()
+        self.iter
+            .next_key_value()
+            .map(|(key, value)| (K::from_variant(&key), V::from_variant(&value)))
     }
 }
 
@@ -513,20 +863,22 @@ impl<'a> Iterator for Iter<'a> {
 /// An iterator over keys from a `Dictionary`.
 ///
 /// See [Dictionary::keys_shared()] for more information about iteration over dictionaries.
-pub struct Keys<'a> {
+pub struct Keys<'a, K = Variant> {
     iter: DictionaryIter<'a>,
+    _phantom: PhantomData<K>,
 }
 
-impl<'a> Keys<'a> {
-    fn new(dictionary: &'a Dictionary) -> Self {
+impl<'a, K> Keys<'a, K> {
+    fn new<DK: VariantMetadata, DV: VariantMetadata>(dictionary: &'a Dictionary<DK, DV>) -> Self {
         Self {
             iter: DictionaryIter::new(dictionary),
+            _phantom: PhantomData,
         }
     }
 
-    /// Creates an iterator that will convert each `Variant` key into a key of type `K`,
+    /// Creates an iterator that will convert each key into a key of type `K2`,
     /// panicking upon failure to convert.
-    pub fn typed<K: FromVariant>(self) -> TypedKeys<'a, K> {
+    pub fn typed<K2: FromVariant>(self) -> TypedKeys<'a, K2> {
         TypedKeys::from_untyped(self)
     }
 
@@ -534,15 +886,15 @@ impl<'a> Keys<'a> {
     pub fn array(self) -> VariantArray {
         // Can only be called
         assert!(self.iter.is_first);
-        self.iter.dictionary.keys_array()
+        self.iter.dictionary.keys()
     }
 }
 
-impl<'a> Iterator for Keys<'a> {
-    type Item = Variant;
+impl<'a, K: FromVariant> Iterator for Keys<'a, K> {
+    type Item = K;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next_key()
+        self.iter.next_key().map(|k| K::from_variant(&k))
     }
 }
 
@@ -559,7 +911,7 @@ pub struct TypedIter<'a, K, V> {
 }
 
 impl<'a, K, V> TypedIter<'a, K, V> {
-    fn from_untyped(value: Iter<'a>) -> Self {
+    fn from_untyped<UK, UV>(value: Iter<'a, UK, UV>) -> Self {
         Self {
             iter: value.iter,
             _k: PhantomData,
@@ -589,7 +941,7 @@ pub struct TypedKeys<'a, K> {
 }
 
 impl<'a, K> TypedKeys<'a, K> {
-    fn from_untyped(value: Keys<'a>) -> Self {
+    fn from_untyped<UK>(value: Keys<'a, UK>) -> Self {
         Self {
             iter: value.iter,
             _k: PhantomData,
@@ -628,7 +980,7 @@ impl<'a, K: FromVariant> Iterator for TypedKeys<'a, K> {
 macro_rules! dict {
     ($($key:tt: $value:expr),* $(,)?) => {
         {
-            let mut d = $crate::builtin::Dictionary::new();
+            let mut d = <$crate::builtin::Dictionary>::new();
             $(
                 // `cargo check` complains that `(1 + 2): true` has unused parens, even though it's not
                 // possible to omit the parens.
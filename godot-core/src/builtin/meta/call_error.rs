@@ -0,0 +1,101 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+use std::error::Error;
+use std::fmt;
+
+use crate::builtin::VariantType;
+
+/// Error returned by a failed dynamic call, for example through `try_call()` or `try_emit_signal()`.
+///
+/// Mirrors the call error kinds reported by GDExtension. Argument counts and indices are those reported by Godot,
+/// i.e. they refer to the parameters of the function that was ultimately invoked.
+///
+/// Typed calls such as [`Gd::call_typed()`][crate::obj::Gd::call_typed] can additionally fail with
+/// [`InvalidReturnValue`][Self::InvalidReturnValue].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum CallError {
+    /// The method does not exist on the object.
+    InvalidMethod,
+
+    /// The argument at position `index` (0-based) cannot be converted to the `expected` type.
+    InvalidArgument { index: usize, expected: VariantType },
+
+    /// More arguments were passed than the method accepts.
+    TooManyArguments { expected: usize },
+
+    /// Fewer arguments were passed than the method requires.
+    TooFewArguments { expected: usize },
+
+    /// The method was called on a null instance.
+    InstanceIsNull,
+
+    /// A non-const method was called on a const instance.
+    MethodNotConst,
+
+    /// An error code unknown to gdext.
+    Unknown { code: sys::GDExtensionCallErrorType },
+
+    /// The call succeeded, but its return value of type `actual` could not be converted to the requested Rust type.
+    InvalidReturnValue { actual: VariantType },
+}
+
+impl CallError {
+    /// Converts the FFI error, returning `None` if it signals a successful call.
+    pub(crate) fn try_from_sys(err: &sys::GDExtensionCallError) -> Option<Self> {
+        let sys::GDExtensionCallError {
+            error,
+            argument,
+            expected,
+        } = *err;
+
+        let error = match error {
+            sys::GDEXTENSION_CALL_OK => return None,
+            sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD => Self::InvalidMethod,
+            sys::GDEXTENSION_CALL_ERROR_INVALID_ARGUMENT => Self::InvalidArgument {
+                index: argument as usize,
+                expected: VariantType::from_sys(expected as sys::GDExtensionVariantType),
+            },
+            sys::GDEXTENSION_CALL_ERROR_TOO_MANY_ARGUMENTS => Self::TooManyArguments {
+                expected: argument as usize,
+            },
+            sys::GDEXTENSION_CALL_ERROR_TOO_FEW_ARGUMENTS => Self::TooFewArguments {
+                expected: argument as usize,
+            },
+            sys::GDEXTENSION_CALL_ERROR_INSTANCE_IS_NULL => Self::InstanceIsNull,
+            sys::GDEXTENSION_CALL_ERROR_METHOD_NOT_CONST => Self::MethodNotConst,
+            code => Self::Unknown { code },
+        };
+
+        Some(error)
+    }
+}
+
+impl fmt::Display for CallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidMethod => write!(f, "method not found"),
+            Self::InvalidArgument { index, expected } => {
+                write!(f, "cannot convert argument #{} to {expected:?}", index + 1)
+            }
+            Self::TooManyArguments { expected } => {
+                write!(f, "too many arguments; expected {expected}")
+            }
+            Self::TooFewArguments { expected } => {
+                write!(f, "too few arguments; expected {expected}")
+            }
+            Self::InstanceIsNull => write!(f, "instance is null"),
+            Self::MethodNotConst => write!(f, "method is not const"),
+            Self::Unknown { code } => write!(f, "unknown reason (error code {code})"),
+            Self::InvalidReturnValue { actual } => {
+                write!(f, "cannot convert return value of type {actual:?}")
+            }
+        }
+    }
+}
+
+impl Error for CallError {}
@@ -70,13 +70,18 @@ impl ClassName {
         self.with_string_name(|s| s.clone())
     }
 
-    /// The returned pointer is valid indefinitely, as entries are never deleted from the cache.
+    /// The returned pointer is valid until the library is unloaded, as entries are only deleted from the cache at that point.
     /// Since we use Box<StringName>, HashMap reallocations don't affect the validity of the StringName.
     #[doc(hidden)]
     pub fn string_sys(&self) -> sys::GDExtensionStringNamePtr {
         self.with_string_name(|s| s.string_sys())
     }
 
+    /// Destroys all cached string names. Must happen while Godot is still reachable, before the library is unloaded.
+    pub(crate) fn cleanup() {
+        *CACHED_STRING_NAMES.lock().unwrap() = None;
+    }
+
     // Takes a closure because the mutex guard protects the reference; so the &StringName cannot leave the scope.
     fn with_string_name<R>(&self, func: impl FnOnce(&StringName) -> R) -> R {
         let mut guard = CACHED_STRING_NAMES.lock().unwrap();
@@ -6,10 +6,12 @@
 
 pub mod registration;
 
+mod call_error;
 mod class_name;
 mod return_marshal;
 mod signature;
 
+pub use call_error::*;
 pub use class_name::*;
 #[doc(hidden)]
 pub use return_marshal::*;
@@ -102,6 +104,32 @@ impl PropertyInfo {
         }
     }
 
+    /// Like [`property_sys()`][Self::property_sys], but the returned struct owns copies of the strings.
+    ///
+    /// Used for property lists handed out to Godot, which outlive `self`. Free with [`free_owned_property_sys()`][Self::free_owned_property_sys].
+    pub(crate) fn property_sys_owned(&self) -> sys::GDExtensionPropertyInfo {
+        use crate::obj::EngineEnum as _;
+
+        sys::GDExtensionPropertyInfo {
+            type_: self.variant_type.sys(),
+            name: Box::into_raw(Box::new(self.property_name.clone()))
+                as sys::GDExtensionStringNamePtr,
+            // Class names are cached globally and never freed.
+            class_name: self.class_name.string_sys(),
+            hint: u32::try_from(self.hint.ord()).expect("hint.ord()"),
+            hint_string: Box::into_raw(Box::new(self.hint_string.clone()))
+                as sys::GDExtensionStringPtr,
+            usage: u32::try_from(self.usage.ord()).expect("usage.ord()"),
+        }
+    }
+
+    /// # Safety
+    /// `info` must have been created by [`property_sys_owned()`][Self::property_sys_owned], and not been freed before.
+    pub(crate) unsafe fn free_owned_property_sys(info: &sys::GDExtensionPropertyInfo) {
+        drop(Box::from_raw(info.name as *mut StringName));
+        drop(Box::from_raw(info.hint_string as *mut GodotString));
+    }
+
     pub fn empty_sys() -> sys::GDExtensionPropertyInfo {
         use crate::obj::EngineEnum as _;
 
@@ -96,8 +96,6 @@ impl MethodInfo {
     }
 
     pub fn register_extension_class_method(&self) {
-        use crate::obj::EngineEnum as _;
-
         let (return_value_info, return_value_metadata) = match &self.return_value {
             Some(info) => (Some(&info.info), info.metadata),
             None => (None, 0),
@@ -125,7 +123,7 @@ impl MethodInfo {
             method_userdata: std::ptr::null_mut(),
             call_func: self.call_func,
             ptrcall_func: self.ptrcall_func,
-            method_flags: self.method_flags.ord() as u32,
+            method_flags: self.method_flags_sys(),
             has_return_value: self.return_value.is_some() as u8,
             return_value_info: std::ptr::addr_of_mut!(return_value_sys),
             return_value_metadata,
@@ -149,6 +147,23 @@ impl MethodInfo {
         }
     }
 
+    /// Godot 4.0 does not support static methods of extension classes: it would dereference the (null) instance when
+    /// they are called on the class. Such methods are thus registered as regular methods, callable on instances only.
+    #[cfg(before_api = "4.1")]
+    fn method_flags_sys(&self) -> u32 {
+        use crate::obj::EngineEnum as _;
+
+        let static_flag = MethodFlags::METHOD_FLAG_STATIC.ord() as u32;
+        self.method_flags.ord() as u32 & !static_flag
+    }
+
+    #[cfg(since_api = "4.1")]
+    fn method_flags_sys(&self) -> u32 {
+        use crate::obj::EngineEnum as _;
+
+        self.method_flags.ord() as u32
+    }
+
     fn argument_count(&self) -> u32 {
         self.arguments
             .len()
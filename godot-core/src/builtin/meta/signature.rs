@@ -25,13 +25,21 @@ pub trait VarcallSignatureTuple: PtrcallSignatureTuple {
     // TODO(uninit) - can we use this for varcall/ptrcall?
     // ret: sys::GDExtensionUninitializedVariantPtr
     // ret: sys::GDExtensionUninitializedTypePtr
+    /// Invokes a Rust function from a Godot varcall.
+    ///
+    /// The last `default_count` parameters are optional. If the caller omits some of them, `make_default_args` is invoked to
+    /// obtain their values.
+    #[allow(clippy::too_many_arguments)]
     unsafe fn in_varcall(
         instance_ptr: sys::GDExtensionClassInstancePtr,
         args_ptr: *const sys::GDExtensionConstVariantPtr,
+        arg_count: sys::GDExtensionInt,
         ret: sys::GDExtensionVariantPtr,
         err: *mut sys::GDExtensionCallError,
         func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
         method_name: &str,
+        default_count: usize,
+        make_default_args: fn() -> Vec<Variant>,
     );
 
     unsafe fn out_class_varcall(
@@ -43,6 +51,16 @@ pub trait VarcallSignatureTuple: PtrcallSignatureTuple {
         varargs: &[Variant],
     ) -> Self::Ret;
 
+    /// Like [`out_class_varcall()`][Self::out_class_varcall], but returns call errors instead of panicking.
+    unsafe fn try_out_class_varcall(
+        method_bind: sys::GDExtensionMethodBindPtr,
+        method_name: &'static str,
+        object_ptr: sys::GDExtensionObjectPtr,
+        maybe_instance_id: Option<InstanceId>, // if not static
+        args: Self::Params,
+        varargs: &[Variant],
+    ) -> Result<Self::Ret, CallError>;
+
     unsafe fn out_utility_ptrcall_varargs(
         utility_fn: UtilityFunctionBind,
         args: Self::Params,
@@ -147,13 +165,30 @@ macro_rules! impl_varcall_signature_for_tuple {
             unsafe fn in_varcall(
                 instance_ptr: sys::GDExtensionClassInstancePtr,
                 args_ptr: *const sys::GDExtensionConstVariantPtr,
+                arg_count: sys::GDExtensionInt,
                 ret: sys::GDExtensionVariantPtr,
                 err: *mut sys::GDExtensionCallError,
                 func: fn(sys::GDExtensionClassInstancePtr, Self::Params) -> Self::Ret,
                 method_name: &str,
+                default_count: usize,
+                make_default_args: fn() -> Vec<Variant>,
             ) {
                 $crate::out!("varcall: {}", method_name);
 
+                // Keeps the padded argument list alive until the end of the call.
+                let padded_args;
+                let args_ptr = match varcall_pad_args(args_ptr, arg_count, $PARAM_COUNT, default_count, make_default_args) {
+                    Ok(Some(args)) => {
+                        padded_args = args;
+                        padded_args.ptrs.as_ptr()
+                    }
+                    Ok(None) => args_ptr,
+                    Err(call_error) => {
+                        *err = call_error;
+                        return;
+                    }
+                };
+
                 let args = ($(
                     unsafe { varcall_arg::<$Pn, $n>(args_ptr, method_name) },
                 )*) ;
@@ -176,34 +211,44 @@ macro_rules! impl_varcall_signature_for_tuple {
                     crate::engine::ensure_object_alive(instance_id, object_ptr, method_name);
                 }
 
-                let class_fn = sys::interface_fn!(object_method_bind_call);
-
-                let explicit_args = [
+                let explicit_args: [Variant; $PARAM_COUNT] = [
                     $(
                         <$Pn as ToVariant>::to_variant(&args.$n),
                     )*
                 ];
 
-                let mut variant_ptrs = Vec::with_capacity(explicit_args.len() + varargs.len());
-                variant_ptrs.extend(explicit_args.iter().map(Variant::var_sys_const));
-                variant_ptrs.extend(varargs.iter().map(Variant::var_sys_const));
-
-                let variant = Variant::from_var_sys_init(|return_ptr| {
-                    let mut err = sys::default_call_error();
-                    class_fn(
-                        method_bind,
-                        object_ptr,
-                        variant_ptrs.as_ptr(),
-                        variant_ptrs.len() as i64,
-                        return_ptr,
-                        std::ptr::addr_of_mut!(err),
-                    );
-
-                    check_varcall_error(&err, method_name, &explicit_args, varargs);
-                });
+                let (variant, err) = class_varcall_raw(method_bind, object_ptr, &explicit_args, varargs);
+                check_varcall_error(&err, method_name, &explicit_args, varargs);
+
                 <Self::Ret as FromVariantIndirect>::convert(variant)
             }
 
+            #[inline]
+            unsafe fn try_out_class_varcall(
+                method_bind: ClassMethodBind,
+                method_name: &'static str,
+                object_ptr: sys::GDExtensionObjectPtr,
+                maybe_instance_id: Option<InstanceId>, // if not static
+                args: Self::Params,
+                varargs: &[Variant],
+            ) -> Result<Self::Ret, CallError> {
+                if let Some(instance_id) = maybe_instance_id {
+                    crate::engine::ensure_object_alive(instance_id, object_ptr, method_name);
+                }
+
+                let explicit_args: [Variant; $PARAM_COUNT] = [
+                    $(
+                        <$Pn as ToVariant>::to_variant(&args.$n),
+                    )*
+                ];
+
+                let (variant, err) = class_varcall_raw(method_bind, object_ptr, &explicit_args, varargs);
+                match CallError::try_from_sys(&err) {
+                    None => Ok(<Self::Ret as FromVariantIndirect>::convert(variant)),
+                    Some(call_error) => Err(call_error),
+                }
+            }
+
             // Note: this is doing a ptrcall, but uses variant conversions for it
             #[inline]
             unsafe fn out_utility_ptrcall_varargs(
@@ -369,6 +414,94 @@ unsafe fn varcall_arg<P: FromVariant, const N: isize>(
         .unwrap_or_else(|_| param_error::<P>(method_name, N as i32, variant))
 }
 
+/// Argument list of a varcall, completed with default arguments.
+struct PaddedArgs {
+    ptrs: Vec<sys::GDExtensionConstVariantPtr>,
+    /// Owns the variants that the trailing `ptrs` point to.
+    _default_args: Vec<Variant>,
+}
+
+/// Validates the number of arguments of a varcall, and appends default arguments if the caller omitted trailing ones.
+///
+/// The default arguments are only created in the latter case. Returns `Ok(None)` if `args_ptr` can be used as-is.
+///
+/// # Safety
+/// `args_ptr` must point to `arg_count` valid variant pointers.
+unsafe fn varcall_pad_args(
+    args_ptr: *const sys::GDExtensionConstVariantPtr,
+    arg_count: sys::GDExtensionInt,
+    param_count: usize,
+    default_count: usize,
+    make_default_args: fn() -> Vec<Variant>,
+) -> Result<Option<PaddedArgs>, sys::GDExtensionCallError> {
+    let arg_count = arg_count as usize;
+    let required_count = param_count - default_count;
+
+    let mut error = sys::default_call_error();
+    if arg_count > param_count {
+        error.error = sys::GDEXTENSION_CALL_ERROR_TOO_MANY_ARGUMENTS;
+        error.argument = param_count as i32;
+        return Err(error);
+    }
+    if arg_count < required_count {
+        error.error = sys::GDEXTENSION_CALL_ERROR_TOO_FEW_ARGUMENTS;
+        error.argument = required_count as i32;
+        return Err(error);
+    }
+    if arg_count == param_count {
+        return Ok(None);
+    }
+
+    let default_args = make_default_args();
+    debug_assert_eq!(default_args.len(), default_count);
+
+    let mut ptrs = Vec::with_capacity(param_count);
+    if arg_count > 0 {
+        ptrs.extend_from_slice(std::slice::from_raw_parts(args_ptr, arg_count));
+    }
+
+    let first_missing_default = arg_count - required_count;
+    ptrs.extend(
+        default_args[first_missing_default..]
+            .iter()
+            .map(Variant::var_sys_const),
+    );
+
+    Ok(Some(PaddedArgs {
+        ptrs,
+        _default_args: default_args,
+    }))
+}
+
+/// Invokes a variadic Rust function from a Godot varcall, passing all arguments as one slice.
+///
+/// Used for `#[func(vararg)]` methods, which have no fixed signature and thus no ptrcall.
+///
+/// # Safety
+/// - `args_ptr` must point to `arg_count` valid variant pointers.
+/// - `ret` and `err` must fulfill the requirements of [`varcall_return`].
+#[doc(hidden)]
+pub unsafe fn in_varcall_vararg<R: ToVariant>(
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+    args_ptr: *const sys::GDExtensionConstVariantPtr,
+    arg_count: sys::GDExtensionInt,
+    ret: sys::GDExtensionVariantPtr,
+    err: *mut sys::GDExtensionCallError,
+    func: fn(sys::GDExtensionClassInstancePtr, &[Variant]) -> R,
+    method_name: &str,
+) {
+    crate::out!("varcall (vararg): {}", method_name);
+
+    let args: Vec<Variant> = (0..arg_count as usize)
+        .map(|i| {
+            let variant = &*(*args_ptr.add(i) as *const Variant);
+            variant.clone()
+        })
+        .collect();
+
+    varcall_return::<R>(func(instance_ptr, &args), ret, err)
+}
+
 /// Moves `ret_val` into `ret`.
 ///
 /// # Safety
@@ -428,6 +561,34 @@ fn return_error<R>(method_name: &str, arg: &impl Debug) -> ! {
     panic!("{method_name}: return type {return_ty} is unable to store value {arg:?}",);
 }
 
+/// Performs a varcall on an object, without interpreting the outcome.
+unsafe fn class_varcall_raw(
+    method_bind: ClassMethodBind,
+    object_ptr: sys::GDExtensionObjectPtr,
+    explicit_args: &[Variant],
+    varargs: &[Variant],
+) -> (Variant, sys::GDExtensionCallError) {
+    let class_fn = sys::interface_fn!(object_method_bind_call);
+
+    let mut variant_ptrs = Vec::with_capacity(explicit_args.len() + varargs.len());
+    variant_ptrs.extend(explicit_args.iter().map(Variant::var_sys_const));
+    variant_ptrs.extend(varargs.iter().map(Variant::var_sys_const));
+
+    let mut err = sys::default_call_error();
+    let variant = Variant::from_var_sys_init(|return_ptr| {
+        class_fn(
+            method_bind,
+            object_ptr,
+            variant_ptrs.as_ptr(),
+            variant_ptrs.len() as i64,
+            return_ptr,
+            std::ptr::addr_of_mut!(err),
+        );
+    });
+
+    (variant, err)
+}
+
 fn check_varcall_error<T>(
     err: &sys::GDExtensionCallError,
     fn_name: &str,
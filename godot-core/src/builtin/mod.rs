@@ -41,7 +41,6 @@ pub use basis::*;
 pub use callable::*;
 pub use color::*;
 pub use dictionary_inner::Dictionary;
-pub use others::*;
 pub use packed_array::*;
 pub use plane::*;
 pub use projection::*;
@@ -50,6 +49,7 @@ pub use real_inner::*;
 pub use rect2::*;
 pub use rect2i::*;
 pub use rid::*;
+pub use signal::*;
 pub use string::*;
 pub use transform2d::*;
 pub use transform3d::*;
@@ -69,7 +69,7 @@ pub mod array {
 
 /// Specialized types related to dictionaries.
 pub mod dictionary {
-    pub use super::dictionary_inner::{Iter, Keys, TypedIter, TypedKeys};
+    pub use super::dictionary_inner::{DictionaryArg, Iter, Keys, TypedIter, TypedKeys};
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -83,7 +83,6 @@ mod aabb;
 mod basis;
 mod callable;
 mod color;
-mod others;
 mod packed_array;
 mod plane;
 mod projection;
@@ -91,6 +90,7 @@ mod quaternion;
 mod rect2;
 mod rect2i;
 mod rid;
+mod signal;
 mod string;
 mod transform2d;
 mod transform3d;
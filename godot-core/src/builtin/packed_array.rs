@@ -430,6 +430,22 @@ macro_rules! impl_packed_array {
 
         impl_builtin_froms!($PackedArray; VariantArray => $from_array);
 
+        /// Serialized as a sequence of elements.
+        #[cfg(feature = "serde")]
+        impl serde::Serialize for $PackedArray {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_seq(self.as_slice())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> serde::Deserialize<'de> for $PackedArray {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let elements = <Vec<$Element> as serde::Deserialize>::deserialize(deserializer)?;
+                Ok(Self::from(elements.as_slice()))
+            }
+        }
+
         impl fmt::Debug for $PackedArray {
             fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
                 // Going through `Variant` because there doesn't seem to be a direct way.
@@ -7,9 +7,8 @@
 use godot_ffi as sys;
 use sys::{ffi_methods, GodotFfi};
 
-use crate::builtin::inner::InnerProjection;
 use crate::builtin::math::{ApproxEq, GlamConv, GlamType};
-use crate::builtin::{real, Plane, RMat4, RealConv, Transform3D, Vector2, Vector4, Vector4Axis};
+use crate::builtin::{real, Plane, RMat4, Transform3D, Vector2, Vector3, Vector4, Vector4Axis};
 
 use std::ops::Mul;
 
@@ -22,9 +21,6 @@ use std::ops::Mul;
 /// memory footprint.
 ///
 /// Used internally as Camera3D's projection matrix.
-///
-/// Note: The current implementation largely makes calls to godot for its
-/// methods and as such are not as performant as other types.
 #[derive(Copy, Clone, PartialEq, Debug)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
@@ -312,7 +308,8 @@ impl Projection {
     ///
     /// _Godot equivalent: Projection.get_aspect()_
     pub fn aspect(&self) -> real {
-        real::from_f64(self.as_inner().get_aspect())
+        let half_extents = self.viewport_half_extents();
+        half_extents.x / half_extents.y
     }
 
     /// Returns the dimensions of the far clipping plane of the projection,
@@ -320,14 +317,23 @@ impl Projection {
     ///
     /// _Godot equivalent: Projection.get_far_plane_half_extents()_
     pub fn far_plane_half_extents(&self) -> Vector2 {
-        self.as_inner().get_far_plane_half_extents()
+        self.plane_half_extents(self.projection_plane(ProjectionPlane::Far))
     }
 
     /// Returns the horizontal field of view of the projection (in degrees).
     ///
     /// _Godot equivalent: Projection.get_fov()_
     pub fn fov(&self) -> real {
-        real::from_f64(self.as_inner().get_fov())
+        let right_plane = self.projection_plane(ProjectionPlane::Right);
+        let right_angle = right_plane.normal.x.abs().acos().to_degrees();
+
+        if self.cols[2].x == 0.0 && self.cols[2].y == 0.0 {
+            right_angle * 2.0
+        } else {
+            // Asymmetrical frustum: the angle of the left plane is calculated separately.
+            let left_plane = self.projection_plane(ProjectionPlane::Left);
+            left_plane.normal.x.abs().acos().to_degrees() + right_angle
+        }
     }
 
     /// Returns the vertical field of view of a projection (in degrees) which
@@ -335,7 +341,10 @@ impl Projection {
     ///
     /// _Godot equivalent: Projection.get_fovy()_
     pub fn fovy_of(fov_x: real, aspect: real) -> real {
-        real::from_f64(InnerProjection::get_fovy(fov_x.as_f64(), aspect.as_f64()))
+        (aspect * (fov_x.to_radians() * 0.5).tan())
+            .atan()
+            .to_degrees()
+            * 2.0
     }
 
     /// Returns the factor by which the visible level of detail is scaled by
@@ -343,7 +352,12 @@ impl Projection {
     ///
     /// _Godot equivalent: Projection.get_lod_multiplier()_
     pub fn lod_multiplier(&self) -> real {
-        real::from_f64(self.as_inner().get_lod_multiplier())
+        if self.is_orthogonal() {
+            self.viewport_half_extents().x
+        } else {
+            let width = self.viewport_half_extents().x * 2.0;
+            1.0 / (self.z_near() / width)
+        }
     }
 
     /// Returns the number of pixels with the given pixel width displayed per
@@ -351,7 +365,11 @@ impl Projection {
     ///
     /// _Godot equivalent: Projection.get_pixels_per_meter()_
     pub fn pixels_per_meter(&self, pixel_width: i64) -> i64 {
-        self.as_inner().get_pixels_per_meter(pixel_width)
+        // Projects the point (1, 0, -1) and maps its x coordinate from [-1, 1] to [0, pixel_width].
+        let [x, _, z, w] = self.cols;
+        let projected_x = (x.x - z.x + w.x) / (x.w - z.w + w.w);
+
+        ((projected_x * 0.5 + 0.5) * pixel_width as real) as i64
     }
 
     /// Returns the clipping plane of this Projection whose index is given by
@@ -359,7 +377,23 @@ impl Projection {
     ///
     /// _Godot equivalent: Projection.get_projection_plane()_
     pub fn projection_plane(&self, plane: ProjectionPlane) -> Plane {
-        self.as_inner().get_projection_plane(plane as i64)
+        // Each plane is the sum or difference of the last row and one of the other rows.
+        let (row, sign) = match plane {
+            ProjectionPlane::Near => (self.row(Vector4Axis::Z), 1.0),
+            ProjectionPlane::Far => (self.row(Vector4Axis::Z), -1.0),
+            ProjectionPlane::Left => (self.row(Vector4Axis::X), 1.0),
+            ProjectionPlane::Top => (self.row(Vector4Axis::Y), -1.0),
+            ProjectionPlane::Right => (self.row(Vector4Axis::X), -1.0),
+            ProjectionPlane::Bottom => (self.row(Vector4Axis::Y), 1.0),
+        };
+
+        let plane = self.row(Vector4Axis::W) + row * sign;
+        let unnormalized = Plane {
+            normal: -Vector3::new(plane.x, plane.y, plane.z),
+            d: plane.w,
+        };
+
+        unnormalized.normalized()
     }
 
     /// Returns the dimensions of the viewport plane that this Projection
@@ -367,7 +401,7 @@ impl Projection {
     ///
     /// _Godot equivalent: Projection.get_viewport_half_extents()_
     pub fn viewport_half_extents(&self) -> Vector2 {
-        self.as_inner().get_viewport_half_extents()
+        self.plane_half_extents(self.projection_plane(ProjectionPlane::Near))
     }
 
     /// Returns the distance for this Projection beyond which positions are
@@ -375,7 +409,7 @@ impl Projection {
     ///
     /// _Godot equivalent: Projection.get_z_far()_
     pub fn z_far(&self) -> real {
-        real::from_f64(self.as_inner().get_z_far())
+        self.projection_plane(ProjectionPlane::Far).d
     }
 
     /// Returns the distance for this Projection before which positions are
@@ -383,7 +417,7 @@ impl Projection {
     ///
     /// _Godot equivalent: Projection.get_z_near()_
     pub fn z_near(&self) -> real {
-        real::from_f64(self.as_inner().get_z_near())
+        -self.projection_plane(ProjectionPlane::Near).d
     }
 
     /// Returns a Projection that performs the inverse of this Projection's
@@ -429,13 +463,32 @@ impl Projection {
     ///
     /// _Godot equivalent: Projection.perspective_znear_adjusted()_
     pub fn perspective_znear_adjusted(&self, new_znear: real) -> Self {
-        self.as_inner()
-            .perspective_znear_adjusted(new_znear.as_f64())
+        let z_far = self.z_far();
+        let delta_z = z_far - new_znear;
+
+        let mut adjusted = *self;
+        adjusted.cols[2].z = -(z_far + new_znear) / delta_z;
+        adjusted.cols[3].z = -2.0 * new_znear * z_far / delta_z;
+        adjusted
     }
 
-    #[doc(hidden)]
-    pub(crate) fn as_inner(&self) -> InnerProjection {
-        InnerProjection::from_outer(self)
+    /// Returns the row of the matrix with the given index.
+    fn row(&self, axis: Vector4Axis) -> Vector4 {
+        let [x, y, z, w] = self.cols;
+        Vector4::new(x[axis], y[axis], z[axis], w[axis])
+    }
+
+    /// Returns the half extents of `plane`, delimited by the right and top planes.
+    fn plane_half_extents(&self, plane: Plane) -> Vector2 {
+        let right_plane = self.projection_plane(ProjectionPlane::Right);
+        let top_plane = self.projection_plane(ProjectionPlane::Top);
+
+        // Like Godot, fall back to zero if the planes do not intersect.
+        let corner = plane
+            .intersect_3(&right_plane, &top_plane)
+            .unwrap_or(Vector3::ZERO);
+
+        Vector2::new(corner.x, corner.y)
     }
 }
 
@@ -979,6 +1032,44 @@ mod test {
         }
     }
 
+    /// Test the getters of a symmetric perspective projection.
+    /// All outputs are manually computed.
+    #[test]
+    fn test_perspective_getters() {
+        let proj = Projection::create_perspective(90.0, 1.0, 1.0, 2.0, false);
+
+        assert_eq_approx!(proj.z_near(), 1.0);
+        assert_eq_approx!(proj.z_far(), 2.0);
+        assert_eq_approx!(proj.fov(), 90.0);
+        assert_eq_approx!(proj.aspect(), 1.0);
+        assert_eq_approx!(proj.viewport_half_extents(), Vector2::new(1.0, 1.0));
+        assert_eq_approx!(proj.far_plane_half_extents(), Vector2::new(2.0, 2.0));
+        assert_eq_approx!(proj.lod_multiplier(), 2.0);
+        assert_eq_approx!(Projection::fovy_of(90.0, 1.0), 90.0);
+
+        let adjusted = proj.perspective_znear_adjusted(0.5);
+        assert_eq_approx!(adjusted.z_near(), 0.5);
+        assert_eq_approx!(adjusted.z_far(), 2.0);
+    }
+
+    /// Test the getters of an orthogonal projection.
+    /// All outputs are manually computed.
+    #[test]
+    fn test_orthogonal_getters() {
+        let proj = Projection::create_orthogonal(-2.0, 2.0, -1.0, 1.0, 0.5, 10.0);
+
+        assert_eq_approx!(proj.z_near(), 0.5);
+        assert_eq_approx!(proj.z_far(), 10.0);
+        assert_eq_approx!(proj.aspect(), 2.0);
+        assert_eq_approx!(proj.viewport_half_extents(), Vector2::new(2.0, 1.0));
+        assert_eq_approx!(proj.lod_multiplier(), 2.0);
+        assert_eq!(proj.pixels_per_meter(100), 75);
+
+        let near = proj.projection_plane(ProjectionPlane::Near);
+        assert_eq_approx!(near.normal, Vector3::new(0.0, 0.0, 1.0));
+        assert_eq_approx!(near.d, -0.5);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde_roundtrip() {
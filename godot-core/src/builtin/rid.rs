@@ -118,3 +118,31 @@ impl std::fmt::Display for Rid {
 unsafe impl GodotFfi for Rid {
     ffi_methods! { type sys::GDExtensionTypePtr = *mut Self; .. }
 }
+
+/// Serialized as the numeric ID, see [`Rid::to_u64`].
+#[cfg(feature = "serde")]
+impl serde::Serialize for Rid {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.to_u64())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Rid {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <u64 as serde::Deserialize>::deserialize(deserializer).map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        use super::Rid;
+        use crate::builtin::test_utils::roundtrip;
+
+        roundtrip(&Rid::new(42), "42");
+        roundtrip(&Rid::Invalid, "0");
+    }
+}
@@ -0,0 +1,192 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot_ffi as sys;
+
+use crate::builtin::{inner, Callable, StringName, ToVariant, Variant, VariantArray};
+use crate::engine::{global, Object};
+use crate::obj::mem::Memory;
+use crate::obj::{EngineEnum, Gd, GodotClass, InstanceId};
+use std::fmt;
+use sys::{ffi_methods, GodotFfi};
+
+/// A `Signal` represents a signal of an `Object` instance in Godot.
+///
+/// Signals are identified by the object emitting them and the signal's name. They are mostly used to connect [`Callable`]s, which
+/// are invoked every time the signal is emitted.
+///
+/// If you declare signals on your own classes with `#[signal]`, prefer the typed handles generated for them
+/// (see [`TypedSignal`][crate::obj::TypedSignal]). `Signal` is the untyped counterpart, e.g. for signals returned from engine APIs.
+#[repr(C, align(8))]
+pub struct Signal {
+    opaque: sys::types::OpaqueSignal,
+}
+
+impl Signal {
+    fn from_opaque(opaque: sys::types::OpaqueSignal) -> Self {
+        Self { opaque }
+    }
+
+    /// Create a signal for the signal `object::signal_name`.
+    ///
+    /// _Godot equivalent: `Signal(Object object, StringName signal)`_
+    pub fn from_object_signal<T, S>(object: &Gd<T>, signal_name: S) -> Self
+    where
+        T: GodotClass,
+        S: Into<StringName>,
+    {
+        let signal_name = signal_name.into();
+        unsafe {
+            sys::from_sys_init_or_init_default::<Self>(|self_ptr| {
+                let ctor = sys::builtin_fn!(signal_from_object_signal);
+                let args = [object.as_arg_ptr(), signal_name.sys_const()];
+                ctor(self_ptr, args.as_ptr());
+            })
+        }
+    }
+
+    /// Creates an invalid/empty signal that is not able to be emitted or connected.
+    ///
+    /// _Godot equivalent: `Signal()`_
+    pub fn invalid() -> Self {
+        unsafe {
+            Self::from_sys_init(|self_ptr| {
+                let ctor = sys::builtin_fn!(signal_construct_default);
+                ctor(self_ptr, std::ptr::null_mut())
+            })
+        }
+    }
+
+    /// Emits this signal, passing `arguments` to all connected callables.
+    ///
+    /// Does nothing if the signal has no object, for example because it has been created by [`Signal::invalid()`].
+    ///
+    /// _Godot equivalent: `emit`_
+    pub fn emit(&self, arguments: &[Variant]) {
+        if let Some(mut object) = self.object() {
+            object.emit_signal(self.name(), arguments);
+        }
+    }
+
+    /// Connects this signal to `callable`, using connection flags `flags` (see `Object.ConnectFlags`).
+    ///
+    /// Returns `Error::OK` on success.
+    ///
+    /// _Godot equivalent: `connect`_
+    pub fn connect(&self, callable: Callable, flags: i64) -> global::Error {
+        let error = self.as_inner().connect(callable, flags);
+        global::Error::from_ord(error as i32)
+    }
+
+    /// Disconnects `callable` from this signal.
+    ///
+    /// _Godot equivalent: `disconnect`_
+    pub fn disconnect(&self, callable: Callable) {
+        self.as_inner().disconnect(callable);
+    }
+
+    /// Returns true if `callable` is connected to this signal.
+    ///
+    /// _Godot equivalent: `is_connected`_
+    pub fn is_connected(&self, callable: Callable) -> bool {
+        self.as_inner().is_connected(callable)
+    }
+
+    /// Returns all connections of this signal.
+    ///
+    /// Each element is a `Dictionary` with the keys `signal` (this signal), `callable` (the connected [`Callable`]) and `flags`.
+    ///
+    /// _Godot equivalent: `get_connections`_
+    pub fn connections(&self) -> VariantArray {
+        self.as_inner().get_connections()
+    }
+
+    /// Returns the name of this signal.
+    ///
+    /// _Godot equivalent: `get_name`_
+    pub fn name(&self) -> StringName {
+        self.as_inner().get_name()
+    }
+
+    /// Returns the object emitting this signal.
+    ///
+    /// Returns `None` when this signal doesn't have any object, e.g. when it was created by [`Signal::invalid()`].
+    ///
+    /// _Godot equivalent: `get_object`_
+    pub fn object(&self) -> Option<Gd<Object>> {
+        // Increment refcount because we're getting a reference, and `InnerSignal::get_object` doesn't
+        // increment the refcount.
+        self.as_inner().get_object().map(|object| {
+            <Object as GodotClass>::Mem::maybe_inc_ref(&object);
+            object
+        })
+    }
+
+    /// Returns the ID of this signal's object, see also [`Gd::instance_id`].
+    ///
+    /// Returns `None` when this signal doesn't have any object.
+    ///
+    /// _Godot equivalent: `get_object_id`_
+    pub fn object_id(&self) -> Option<InstanceId> {
+        let id = self.as_inner().get_object_id();
+        InstanceId::try_from_i64(id)
+    }
+
+    /// Returns true if this signal has no object, or its name is empty.
+    ///
+    /// _Godot equivalent: `is_null`_
+    pub fn is_null(&self) -> bool {
+        self.as_inner().is_null()
+    }
+
+    #[doc(hidden)]
+    pub fn as_inner(&self) -> inner::InnerSignal {
+        inner::InnerSignal::from_outer(self)
+    }
+}
+
+impl_builtin_traits! {
+    for Signal {
+        // Currently no Default::default() to encourage explicit valid initialization.
+        //Default => signal_construct_default;
+
+        // Signals are equal if both object and name are equal, so equality is total.
+        Eq => signal_operator_equal;
+        Clone => signal_construct_copy;
+        Drop => signal_destroy;
+    }
+}
+
+// SAFETY:
+// The `opaque` in `Signal` is a pair of object ID and name, and requires no special initialization or cleanup
+// beyond what is done in `from_opaque` and `drop`. So using `*mut Opaque` is safe.
+unsafe impl GodotFfi for Signal {
+    ffi_methods! { type sys::GDExtensionTypePtr = *mut Opaque; .. }
+
+    unsafe fn from_sys_init_default(init_fn: impl FnOnce(sys::GDExtensionTypePtr)) -> Self {
+        let mut result = Self::invalid();
+        init_fn(result.sys_mut());
+        result
+    }
+}
+
+impl fmt::Debug for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = self.name();
+        let object = self.object();
+
+        f.debug_struct("Signal")
+            .field("name", &name)
+            .field("object", &object)
+            .finish()
+    }
+}
+
+impl fmt::Display for Signal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_variant())
+    }
+}
@@ -42,3 +42,33 @@ impl VariantMetadata for String {
         VariantType::String
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// serde support
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use super::*;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// All Godot string types are (de)serialized as plain strings.
+    macro_rules! impl_serde_as_str {
+        ($Ty:ty) => {
+            impl Serialize for $Ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.serialize_str(&String::from(self))
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $Ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    String::deserialize(deserializer).map(Self::from)
+                }
+            }
+        };
+    }
+
+    impl_serde_as_str!(GodotString);
+    impl_serde_as_str!(StringName);
+    impl_serde_as_str!(NodePath);
+}
@@ -12,6 +12,8 @@ use sys::types::OpaqueVariant;
 use sys::{ffi_methods, interface_fn};
 
 mod impls;
+#[cfg(feature = "serde")]
+mod serialize;
 mod variant_traits;
 
 pub use impls::*;
@@ -0,0 +1,159 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! `serde` support for `Variant`.
+//!
+//! A variant is represented as an externally tagged enum, with the [`VariantType`] name as the tag, e.g. `{"Int":7}` in JSON.
+//! `Nil` is a unit variant. Variants holding an `Object`, `Callable` or `Signal` cannot be serialized.
+
+use std::fmt;
+
+use serde::de::{self, EnumAccess, Unexpected, VariantAccess, Visitor};
+use serde::ser::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::builtin::*;
+
+const NAME: &str = "Variant";
+
+macro_rules! impl_variant_serde {
+    ( $( $Ty:ident => $RustTy:ty ),* $(,)? ) => {
+        /// Tags of all variant types which can be (de)serialized.
+        const VARIANT_TAGS: &[&str] = &["Nil", $( stringify!($Ty), )*];
+
+        impl Serialize for Variant {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                match self.get_type() {
+                    VariantType::Nil => serializer.serialize_unit_variant(NAME, 0, "Nil"),
+                    $(
+                        VariantType::$Ty => serializer.serialize_newtype_variant(
+                            NAME,
+                            tag_index(stringify!($Ty)),
+                            stringify!($Ty),
+                            &self.to::<$RustTy>(),
+                        ),
+                    )*
+                    other => Err(S::Error::custom(format_args!(
+                        "cannot serialize Variant of type {other:?}"
+                    ))),
+                }
+            }
+        }
+
+        impl<'de> Visitor<'de> for TagVisitor {
+            type Value = VariantTag;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a serializable variant type")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+                match usize::try_from(value).ok().and_then(|index| VARIANT_TAGS.get(index)) {
+                    Some(tag) => self.visit_str(tag),
+                    None => Err(E::invalid_value(Unexpected::Unsigned(value), &self)),
+                }
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+                let ty = match value {
+                    "Nil" => VariantType::Nil,
+                    $( stringify!($Ty) => VariantType::$Ty, )*
+                    _ => return Err(E::unknown_variant(value, VARIANT_TAGS)),
+                };
+
+                Ok(VariantTag(ty))
+            }
+        }
+
+        impl<'de> Visitor<'de> for VariantVisitor {
+            type Value = Variant;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a Variant")
+            }
+
+            fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+                let (VariantTag(ty), access) = data.variant()?;
+
+                match ty {
+                    VariantType::Nil => {
+                        access.unit_variant()?;
+                        Ok(Variant::nil())
+                    }
+                    $(
+                        VariantType::$Ty => access
+                            .newtype_variant::<$RustTy>()
+                            .map(|value| value.to_variant()),
+                    )*
+                    // TagVisitor only accepts the types listed above.
+                    other => unreachable!("unexpected variant tag {other:?}"),
+                }
+            }
+        }
+    };
+}
+
+impl_variant_serde! {
+    Bool => bool,
+    Int => i64,
+    Float => f64,
+    String => GodotString,
+    Vector2 => Vector2,
+    Vector2i => Vector2i,
+    Rect2 => Rect2,
+    Rect2i => Rect2i,
+    Vector3 => Vector3,
+    Vector3i => Vector3i,
+    Transform2D => Transform2D,
+    Vector4 => Vector4,
+    Vector4i => Vector4i,
+    Plane => Plane,
+    Quaternion => Quaternion,
+    Aabb => Aabb,
+    Basis => Basis,
+    Transform3D => Transform3D,
+    Projection => Projection,
+    Color => Color,
+    StringName => StringName,
+    NodePath => NodePath,
+    Rid => Rid,
+    Dictionary => Dictionary,
+    Array => VariantArray,
+    PackedByteArray => PackedByteArray,
+    PackedInt32Array => PackedInt32Array,
+    PackedInt64Array => PackedInt64Array,
+    PackedFloat32Array => PackedFloat32Array,
+    PackedFloat64Array => PackedFloat64Array,
+    PackedStringArray => PackedStringArray,
+    PackedVector2Array => PackedVector2Array,
+    PackedVector3Array => PackedVector3Array,
+    PackedColorArray => PackedColorArray,
+}
+
+/// Index of the enum variant with the given tag, used by formats which identify variants by index rather than name.
+fn tag_index(tag: &str) -> u32 {
+    let index = VARIANT_TAGS.iter().position(|&t| t == tag);
+    index.expect("tag of a serializable variant type") as u32
+}
+
+/// Variant type identifying the enum variant, deserialized from either its name or its index in `VARIANT_TAGS`.
+struct VariantTag(VariantType);
+
+impl<'de> Deserialize<'de> for VariantTag {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_identifier(TagVisitor)
+    }
+}
+
+struct TagVisitor;
+
+struct VariantVisitor;
+
+impl<'de> Deserialize<'de> for Variant {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_enum(NAME, VARIANT_TAGS, VariantVisitor)
+    }
+}
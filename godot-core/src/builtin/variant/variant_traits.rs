@@ -47,6 +47,55 @@ pub trait ToVariant {
     fn to_variant(&self) -> Variant;
 }
 
+/// Argument list of a dynamic call, such as [`Gd::call_typed()`][crate::obj::Gd::call_typed].
+///
+/// Implemented for tuples of up to 10 [`ToVariant`] elements, so that arguments can be passed as Rust values, e.g. `(5, "text")`.
+/// The empty tuple `()` represents no arguments. Slices of variants are accepted as well, for argument lists built at runtime.
+pub trait ToVariantArgs {
+    /// Converts each argument to a `Variant`.
+    fn to_variant_args(&self) -> Vec<Variant>;
+}
+
+impl ToVariantArgs for [Variant] {
+    fn to_variant_args(&self) -> Vec<Variant> {
+        self.to_vec()
+    }
+}
+
+impl<const N: usize> ToVariantArgs for [Variant; N] {
+    fn to_variant_args(&self) -> Vec<Variant> {
+        self.to_vec()
+    }
+}
+
+impl<A: ToVariantArgs + ?Sized> ToVariantArgs for &A {
+    fn to_variant_args(&self) -> Vec<Variant> {
+        (**self).to_variant_args()
+    }
+}
+
+macro_rules! impl_to_variant_args_for_tuple {
+    ($($Pn:ident : $n:tt),*) => {
+        impl<$($Pn: ToVariant,)*> ToVariantArgs for ($($Pn,)*) {
+            fn to_variant_args(&self) -> Vec<Variant> {
+                vec![$(self.$n.to_variant()),*]
+            }
+        }
+    };
+}
+
+impl_to_variant_args_for_tuple!();
+impl_to_variant_args_for_tuple!(P0: 0);
+impl_to_variant_args_for_tuple!(P0: 0, P1: 1);
+impl_to_variant_args_for_tuple!(P0: 0, P1: 1, P2: 2);
+impl_to_variant_args_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3);
+impl_to_variant_args_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4);
+impl_to_variant_args_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5);
+impl_to_variant_args_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5, P6: 6);
+impl_to_variant_args_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5, P6: 6, P7: 7);
+impl_to_variant_args_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5, P6: 6, P7: 7, P8: 8);
+impl_to_variant_args_for_tuple!(P0: 0, P1: 1, P2: 2, P3: 3, P4: 4, P5: 5, P6: 6, P7: 7, P8: 8, P9: 9);
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 #[derive(Eq, PartialEq, Debug)]
@@ -0,0 +1,176 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Documentation of user classes, shown in the editor's built-in help.
+//!
+//! The proc-macros turn `///` comments into fragments of Godot's XML class reference. Since a class can be declared across several
+//! macro invocations, the fragments are combined here into one document per class, which is loaded by the editor (Godot 4.3+).
+
+use crate::builtin::meta::ClassName;
+#[cfg(since_api = "4.3")]
+use crate::{out, sys};
+
+use std::sync::Mutex;
+
+/// Documentation of a `#[derive(GodotClass)]` struct and its properties, as XML fragments.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct StructDocs {
+    pub brief_description: &'static str,
+    pub description: &'static str,
+    /// `<member>` elements for all `#[var]` and `#[export]` fields.
+    pub members: &'static str,
+    /// Whether any of the above has been documented with `///` comments.
+    pub has_docs: bool,
+}
+
+/// Documentation of the `#[func]`, `#[signal]` and `#[constant]` items in a `#[godot_api]` impl block, as XML fragments.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct InherentImplDocs {
+    pub methods: &'static str,
+    pub signals: &'static str,
+    pub constants: &'static str,
+    /// Whether any of the above has been documented with `///` comments.
+    pub has_docs: bool,
+}
+
+/// All documentation of one class.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ClassDocs {
+    pub struct_docs: StructDocs,
+    pub impl_docs: Vec<InherentImplDocs>,
+}
+
+impl ClassDocs {
+    /// Classes without any `///` comments keep the help page that the editor generates on its own.
+    fn has_docs(&self) -> bool {
+        self.struct_docs.has_docs || self.impl_docs.iter().any(|docs| docs.has_docs)
+    }
+
+    fn to_xml(&self, class_name: ClassName, base_class_name: ClassName) -> String {
+        let StructDocs {
+            brief_description,
+            description,
+            members,
+            ..
+        } = self.struct_docs;
+
+        let methods: String = self.impl_docs.iter().map(|docs| docs.methods).collect();
+        let signals: String = self.impl_docs.iter().map(|docs| docs.signals).collect();
+        let constants: String = self.impl_docs.iter().map(|docs| docs.constants).collect();
+
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8" ?>
+<class name="{class_name}" inherits="{base_class_name}">
+<brief_description>{brief_description}</brief_description>
+<description>{description}</description>
+<methods>{methods}</methods>
+<members>{members}</members>
+<signals>{signals}</signals>
+<constants>{constants}</constants>
+</class>"#
+        )
+    }
+}
+
+/// XML class reference of all registered classes with documentation, loaded once the editor is available.
+static CLASS_DOCS_XML: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+/// Remembers the documentation of a class that has just been registered.
+pub(crate) fn add_class_docs(class_name: ClassName, base_class_name: ClassName, docs: &ClassDocs) {
+    if docs.has_docs() {
+        let xml = docs.to_xml(class_name, base_class_name);
+        CLASS_DOCS_XML.lock().unwrap().push(xml);
+    }
+}
+
+/// Loads the documentation of all classes into the editor help. Requires Godot 4.3 or later, otherwise docs are discarded.
+pub(crate) fn register_class_docs() {
+    let class_docs = std::mem::take(&mut *CLASS_DOCS_XML.lock().unwrap());
+
+    #[cfg(since_api = "4.3")]
+    for xml in class_docs {
+        out!("Load class docs: {} bytes", xml.len());
+        unsafe {
+            #[allow(clippy::let_unit_value)]
+            let _: () = sys::interface_fn!(editor_help_load_xml_from_utf8_chars_and_len)(
+                xml.as_ptr() as *const std::ffi::c_char,
+                xml.len() as sys::GDExtensionInt,
+            );
+        }
+    }
+
+    #[cfg(before_api = "4.3")]
+    let _ = class_docs;
+}
+
+/// Discards documentation that has not been loaded, e.g. because the library was not loaded by the editor.
+pub(crate) fn clear_class_docs() {
+    CLASS_DOCS_XML.lock().unwrap().clear();
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn class_docs_xml() {
+        let docs = ClassDocs {
+            struct_docs: StructDocs {
+                brief_description: "A player &amp; their &lt;weapon&gt;.",
+                description: "Spawned by [code]Level[/code].",
+                members: r#"<member name="health" type="int">Current health.</member>"#,
+                has_docs: true,
+            },
+            impl_docs: vec![
+                InherentImplDocs {
+                    methods: concat!(
+                        r#"<method name="spawn" qualifiers="static"><return type="Player" />"#,
+                        r#"<param index="0" name="name" type="String" default="&quot;Bob&quot;" />"#,
+                        r#"<description>Creates a player.</description></method>"#,
+                    ),
+                    signals: r#"<signal name="hit"><param index="0" name="damage" type="int" /><description></description></signal>"#,
+                    constants: r#"<constant name="MAX_HEALTH" value="100">Upper bound.</constant>"#,
+                    has_docs: true,
+                },
+                InherentImplDocs {
+                    methods: r#"<method name="log_all" qualifiers="vararg"><return type="void" /><description></description></method>"#,
+                    signals: "",
+                    constants: "",
+                    has_docs: false,
+                },
+            ],
+        };
+
+        let xml = docs.to_xml(
+            ClassName::from_ascii_cstr(b"Player\0"),
+            ClassName::from_ascii_cstr(b"Node\0"),
+        );
+
+        let expected = concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\" ?>\n",
+            "<class name=\"Player\" inherits=\"Node\">\n",
+            "<brief_description>A player &amp; their &lt;weapon&gt;.</brief_description>\n",
+            "<description>Spawned by [code]Level[/code].</description>\n",
+            "<methods>",
+            r#"<method name="spawn" qualifiers="static"><return type="Player" />"#,
+            r#"<param index="0" name="name" type="String" default="&quot;Bob&quot;" />"#,
+            r#"<description>Creates a player.</description></method>"#,
+            r#"<method name="log_all" qualifiers="vararg"><return type="void" /><description></description></method>"#,
+            "</methods>\n",
+            r#"<members><member name="health" type="int">Current health.</member></members>"#,
+            "\n",
+            r#"<signals><signal name="hit"><param index="0" name="damage" type="int" /><description></description></signal></signals>"#,
+            "\n",
+            r#"<constants><constant name="MAX_HEALTH" value="100">Upper bound.</constant></constants>"#,
+            "\n</class>",
+        );
+
+        assert!(docs.has_docs());
+        assert_eq!(xml, expected);
+    }
+}
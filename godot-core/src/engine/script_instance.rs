@@ -0,0 +1,618 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::RefCell;
+use std::ffi::c_void;
+use std::panic::AssertUnwindSafe;
+
+use godot_ffi as sys;
+
+use crate::builtin::meta::{ClassName, PropertyInfo};
+use crate::builtin::{GodotString, StringName, Variant, VariantType};
+use crate::engine::global::MethodFlags;
+use crate::engine::{Script, ScriptLanguage};
+use crate::obj::{EngineEnum, Gd};
+
+/// Method of a script, as reported by [`ScriptInstance::get_method_list()`].
+#[derive(Debug)]
+pub struct ScriptMethodInfo {
+    pub id: i32,
+    pub method_name: StringName,
+    pub class_name: ClassName,
+    pub return_type: PropertyInfo,
+    pub arguments: Vec<PropertyInfo>,
+    pub default_arguments: Vec<Variant>,
+    pub flags: MethodFlags,
+}
+
+/// Implement custom scripts that can be attached to objects in Godot.
+///
+/// A script instance holds the state of one script attached to one object. Godot routes property accesses, method calls and
+/// notifications of that object through the script instance first.
+///
+/// Script instances are created by your [`ScriptExtension`][crate::engine::ScriptExtension] implementation, which returns the
+/// result of [`create_script_instance()`] from its `instance_create()` virtual method. Together with a
+/// [`ScriptLanguageExtension`][crate::engine::ScriptLanguageExtension], this allows hosting a custom scripting language on top of gdext.
+///
+/// All methods are invoked by Godot. Panics are caught at the FFI boundary and reported as errors. Note that the instance is
+/// borrowed for the duration of every call; calls that re-enter the same script instance (e.g. a script method that, through
+/// Godot, reads one of its own properties) will fail while a `&mut self` method is running.
+pub trait ScriptInstance {
+    /// Name of the script's class; used for the default [`to_string()`][Self::to_string] implementation.
+    fn class_name(&self) -> GodotString;
+
+    /// Sets the property `name` to `value`.
+    ///
+    /// Returns `true` if the script handled the property, `false` to let Godot continue with the object's own properties.
+    fn set(&mut self, name: StringName, value: &Variant) -> bool;
+
+    /// Returns the value of property `name`, or `None` if the script doesn't handle it.
+    fn get(&self, name: StringName) -> Option<Variant>;
+
+    /// All properties provided by the script.
+    fn get_property_list(&self) -> &[PropertyInfo];
+
+    /// All methods provided by the script.
+    fn get_method_list(&self) -> &[ScriptMethodInfo];
+
+    /// Calls the script method `method` with `args`.
+    ///
+    /// Return `Err(sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD)` if the script has no such method, so that Godot falls back to the
+    /// object's native methods. Other [`GDExtensionCallErrorType`][sys::GDExtensionCallErrorType] values report invalid calls.
+    fn call(
+        &mut self,
+        method: StringName,
+        args: &[&Variant],
+    ) -> Result<Variant, sys::GDExtensionCallErrorType>;
+
+    /// Returns `true` if the script provides the method `method`.
+    fn has_method(&self, method: StringName) -> bool;
+
+    /// The script resource this instance belongs to.
+    fn get_script(&self) -> &Gd<Script>;
+
+    /// Language of the script.
+    fn get_language(&self) -> Gd<ScriptLanguage>;
+
+    /// Type of the property `name`, or `None` if the script doesn't provide it.
+    fn get_property_type(&self, name: StringName) -> Option<VariantType>;
+
+    /// Called for each notification the object receives.
+    fn notification(&mut self, what: i32) {
+        let _ = what;
+    }
+
+    /// String representation of the object, if the script overrides it.
+    fn to_string(&self) -> GodotString {
+        GodotString::from(format!("[{}]", self.class_name()))
+    }
+
+    /// Property values to be stored when the object is serialized.
+    ///
+    /// By default, reads all properties of [`get_property_list()`][Self::get_property_list].
+    fn get_property_state(&self) -> Vec<(StringName, Variant)> {
+        self.get_property_list()
+            .iter()
+            .filter_map(|info| {
+                let name = info.property_name.clone();
+                self.get(name.clone()).map(|value| (name, value))
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the property `name` can be reverted to a default value in the editor.
+    fn property_can_revert(&self, name: StringName) -> bool {
+        let _ = name;
+        false
+    }
+
+    /// Default value of property `name`, if it can be reverted.
+    fn property_get_revert(&self, name: StringName) -> Option<Variant> {
+        let _ = name;
+        None
+    }
+
+    /// Whether this is a placeholder instance, i.e. one for a script that cannot run (e.g. tool-less scripts in the editor).
+    fn is_placeholder(&self) -> bool {
+        false
+    }
+
+    /// Like [`set()`][Self::set], but only invoked for placeholder instances, if the regular property could not be set.
+    fn property_set_fallback(&mut self, name: StringName, value: &Variant) -> bool {
+        let _ = (name, value);
+        false
+    }
+
+    /// Like [`get()`][Self::get], but only invoked for placeholder instances, if the regular property could not be read.
+    fn property_get_fallback(&self, name: StringName) -> Option<Variant> {
+        let _ = name;
+        None
+    }
+
+    /// Called when the reference count of a `RefCounted` owner is incremented.
+    fn on_refcount_incremented(&self) {}
+
+    /// Called when the reference count of a `RefCounted` owner is decremented.
+    ///
+    /// Returns whether the object may be freed, if the count drops to zero.
+    fn on_refcount_decremented(&self) -> bool {
+        true
+    }
+}
+
+/// Creates a Godot script instance from the Rust `rust_instance`.
+///
+/// The returned pointer is meant to be returned from `ScriptExtension::instance_create()`. Godot takes ownership of the instance
+/// and drops it when the script is detached or the object is freed.
+pub fn create_script_instance<T: ScriptInstance + 'static>(rust_instance: T) -> *mut c_void {
+    let info = sys::GDExtensionScriptInstanceInfo {
+        set_func: Some(script_instance_info::set_func::<T>),
+        get_func: Some(script_instance_info::get_func::<T>),
+        get_property_list_func: Some(script_instance_info::get_property_list_func::<T>),
+        free_property_list_func: Some(script_instance_info::free_property_list_func::<T>),
+        property_can_revert_func: Some(script_instance_info::property_can_revert_func::<T>),
+        property_get_revert_func: Some(script_instance_info::property_get_revert_func::<T>),
+        get_owner_func: None,
+        get_property_state_func: Some(script_instance_info::get_property_state_func::<T>),
+        get_method_list_func: Some(script_instance_info::get_method_list_func::<T>),
+        free_method_list_func: Some(script_instance_info::free_method_list_func::<T>),
+        get_property_type_func: Some(script_instance_info::get_property_type_func::<T>),
+        has_method_func: Some(script_instance_info::has_method_func::<T>),
+        call_func: Some(script_instance_info::call_func::<T>),
+        notification_func: Some(script_instance_info::notification_func::<T>),
+        to_string_func: Some(script_instance_info::to_string_func::<T>),
+        refcount_incremented_func: Some(script_instance_info::refcount_incremented_func::<T>),
+        refcount_decremented_func: Some(script_instance_info::refcount_decremented_func::<T>),
+        get_script_func: Some(script_instance_info::get_script_func::<T>),
+        is_placeholder_func: Some(script_instance_info::is_placeholder_func::<T>),
+        set_fallback_func: Some(script_instance_info::set_fallback_func::<T>),
+        get_fallback_func: Some(script_instance_info::get_fallback_func::<T>),
+        get_language_func: Some(script_instance_info::get_language_func::<T>),
+        free_func: Some(script_instance_info::free_func::<T>),
+    };
+
+    let data = ScriptInstanceData {
+        inner: RefCell::new(rust_instance),
+        info,
+    };
+
+    let data_ptr = Box::into_raw(Box::new(data));
+
+    // SAFETY: `data_ptr` stays valid until Godot calls `free_func`, after which Godot no longer accesses `info`.
+    unsafe {
+        let info_ptr = std::ptr::addr_of!((*data_ptr).info);
+        sys::interface_fn!(script_instance_create)(info_ptr, data_ptr as *mut c_void)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Instance data
+
+/// Owned by Godot through the `GDExtensionScriptInstanceDataPtr`.
+struct ScriptInstanceData<T: ScriptInstance> {
+    inner: RefCell<T>,
+    /// Godot keeps a pointer to this struct instead of copying it, so it must live as long as the instance.
+    info: sys::GDExtensionScriptInstanceInfo,
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Conversions with owned FFI representations
+
+fn method_info_into_owned_sys(info: &ScriptMethodInfo) -> sys::GDExtensionMethodInfo {
+    let arguments: Box<[_]> = info
+        .arguments
+        .iter()
+        .map(PropertyInfo::property_sys_owned)
+        .collect();
+
+    let default_arguments: Box<[_]> = info
+        .default_arguments
+        .iter()
+        .map(|arg| Box::into_raw(Box::new(arg.clone())) as sys::GDExtensionVariantPtr)
+        .collect();
+
+    sys::GDExtensionMethodInfo {
+        name: Box::into_raw(Box::new(info.method_name.clone())) as sys::GDExtensionStringNamePtr,
+        return_value: info.return_type.property_sys_owned(),
+        flags: u32::try_from(info.flags.ord()).expect("flags.ord()"),
+        id: info.id,
+        argument_count: arguments.len() as u32,
+        arguments: Box::into_raw(arguments) as *mut sys::GDExtensionPropertyInfo,
+        default_argument_count: default_arguments.len() as u32,
+        default_arguments: Box::into_raw(default_arguments) as *mut sys::GDExtensionVariantPtr,
+    }
+}
+
+/// # Safety
+/// `info` must have been created by [`method_info_into_owned_sys()`].
+unsafe fn free_method_info_sys(info: &sys::GDExtensionMethodInfo) {
+    drop(Box::from_raw(info.name as *mut StringName));
+    PropertyInfo::free_owned_property_sys(&info.return_value);
+
+    let arguments = Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+        info.arguments,
+        info.argument_count as usize,
+    ));
+    for argument in arguments.iter() {
+        PropertyInfo::free_owned_property_sys(argument);
+    }
+
+    let default_arguments = Box::from_raw(std::ptr::slice_from_raw_parts_mut(
+        info.default_arguments,
+        info.default_argument_count as usize,
+    ));
+    for default_argument in default_arguments.iter() {
+        drop(Box::from_raw(*default_argument as *mut Variant));
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Callbacks
+
+mod script_instance_info {
+    use super::*;
+
+    unsafe fn instance_data<'a, T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+    ) -> &'a ScriptInstanceData<T> {
+        &*(p_instance as *const ScriptInstanceData<T>)
+    }
+
+    /// Runs `code` with the instance data, catching panics. Returns `None` on panic.
+    unsafe fn with_data<T: ScriptInstance, R>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        method: &str,
+        code: impl FnOnce(&ScriptInstanceData<T>) -> R,
+    ) -> Option<R> {
+        let data = instance_data::<T>(p_instance);
+
+        crate::private::handle_panic(
+            || format!("ScriptInstance::{method}() failed"),
+            AssertUnwindSafe(|| code(data)),
+        )
+    }
+
+    /// The name is not ours, so we cannot call the destructor on it.
+    unsafe fn borrow_string_name(name: sys::GDExtensionConstStringNamePtr) -> StringName {
+        let borrowed = StringName::from_string_sys(sys::force_mut_ptr(name));
+        let owned = borrowed.clone();
+        std::mem::forget(borrowed);
+        owned
+    }
+
+    /// Writes `value` into `r_ret` and returns `true` if present.
+    unsafe fn write_variant(
+        value: Option<Option<Variant>>,
+        r_ret: sys::GDExtensionVariantPtr,
+    ) -> sys::GDExtensionBool {
+        match value.flatten() {
+            Some(value) => {
+                *(r_ret as *mut Variant) = value;
+                true as sys::GDExtensionBool
+            }
+            None => false as sys::GDExtensionBool,
+        }
+    }
+
+    pub(super) unsafe extern "C" fn set_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_name: sys::GDExtensionConstStringNamePtr,
+        p_value: sys::GDExtensionConstVariantPtr,
+    ) -> sys::GDExtensionBool {
+        let name = borrow_string_name(p_name);
+        let value = &*(p_value as *const Variant);
+
+        let result = with_data::<T, _>(p_instance, "set", |data| {
+            data.inner.borrow_mut().set(name, value)
+        });
+
+        result.unwrap_or(false) as sys::GDExtensionBool
+    }
+
+    pub(super) unsafe extern "C" fn get_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_name: sys::GDExtensionConstStringNamePtr,
+        r_ret: sys::GDExtensionVariantPtr,
+    ) -> sys::GDExtensionBool {
+        let name = borrow_string_name(p_name);
+        let result = with_data::<T, _>(p_instance, "get", |data| data.inner.borrow().get(name));
+
+        write_variant(result, r_ret)
+    }
+
+    pub(super) unsafe extern "C" fn get_property_list_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        r_count: *mut u32,
+    ) -> *const sys::GDExtensionPropertyInfo {
+        let result = with_data::<T, _>(p_instance, "get_property_list", |data| {
+            let list: Vec<_> = data
+                .inner
+                .borrow()
+                .get_property_list()
+                .iter()
+                .map(PropertyInfo::property_sys_owned)
+                .collect();
+
+            (list.len() as u32, sys::leak_list(list))
+        });
+
+        let (count, list_ptr) = result.unwrap_or((0, std::ptr::null()));
+        *r_count = count;
+        list_ptr
+    }
+
+    pub(super) unsafe extern "C" fn free_property_list_func<T: ScriptInstance>(
+        _p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_list: *const sys::GDExtensionPropertyInfo,
+    ) {
+        if p_list.is_null() {
+            return;
+        }
+
+        let list = sys::unleak_list(p_list);
+
+        for info in list.iter() {
+            PropertyInfo::free_owned_property_sys(info);
+        }
+    }
+
+    pub(super) unsafe extern "C" fn property_can_revert_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_name: sys::GDExtensionConstStringNamePtr,
+    ) -> sys::GDExtensionBool {
+        let name = borrow_string_name(p_name);
+        let result = with_data::<T, _>(p_instance, "property_can_revert", |data| {
+            data.inner.borrow().property_can_revert(name)
+        });
+
+        result.unwrap_or(false) as sys::GDExtensionBool
+    }
+
+    pub(super) unsafe extern "C" fn property_get_revert_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_name: sys::GDExtensionConstStringNamePtr,
+        r_ret: sys::GDExtensionVariantPtr,
+    ) -> sys::GDExtensionBool {
+        let name = borrow_string_name(p_name);
+        let result = with_data::<T, _>(p_instance, "property_get_revert", |data| {
+            data.inner.borrow().property_get_revert(name)
+        });
+
+        write_variant(result, r_ret)
+    }
+
+    pub(super) unsafe extern "C" fn get_property_state_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_add_func: sys::GDExtensionScriptInstancePropertyStateAdd,
+        p_userdata: *mut c_void,
+    ) {
+        let result = with_data::<T, _>(p_instance, "get_property_state", |data| {
+            data.inner.borrow().get_property_state()
+        });
+
+        let (Some(state), Some(add_func)) = (result, p_add_func) else {
+            return;
+        };
+
+        for (name, value) in state {
+            add_func(name.string_sys(), value.var_sys_const(), p_userdata);
+        }
+    }
+
+    pub(super) unsafe extern "C" fn get_method_list_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        r_count: *mut u32,
+    ) -> *const sys::GDExtensionMethodInfo {
+        let result = with_data::<T, _>(p_instance, "get_method_list", |data| {
+            let list: Vec<_> = data
+                .inner
+                .borrow()
+                .get_method_list()
+                .iter()
+                .map(method_info_into_owned_sys)
+                .collect();
+
+            (list.len() as u32, sys::leak_list(list))
+        });
+
+        let (count, list_ptr) = result.unwrap_or((0, std::ptr::null()));
+        *r_count = count;
+        list_ptr
+    }
+
+    pub(super) unsafe extern "C" fn free_method_list_func<T: ScriptInstance>(
+        _p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_list: *const sys::GDExtensionMethodInfo,
+    ) {
+        if p_list.is_null() {
+            return;
+        }
+
+        let list = sys::unleak_list(p_list);
+
+        for info in list.iter() {
+            free_method_info_sys(info);
+        }
+    }
+
+    pub(super) unsafe extern "C" fn get_property_type_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_name: sys::GDExtensionConstStringNamePtr,
+        r_is_valid: *mut sys::GDExtensionBool,
+    ) -> sys::GDExtensionVariantType {
+        let name = borrow_string_name(p_name);
+        let result = with_data::<T, _>(p_instance, "get_property_type", |data| {
+            data.inner.borrow().get_property_type(name)
+        });
+
+        match result.flatten() {
+            Some(variant_type) => {
+                *r_is_valid = true as sys::GDExtensionBool;
+                variant_type.sys()
+            }
+            None => {
+                *r_is_valid = false as sys::GDExtensionBool;
+                VariantType::Nil.sys()
+            }
+        }
+    }
+
+    pub(super) unsafe extern "C" fn has_method_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_method: sys::GDExtensionConstStringNamePtr,
+    ) -> sys::GDExtensionBool {
+        let method = borrow_string_name(p_method);
+        let result = with_data::<T, _>(p_instance, "has_method", |data| {
+            data.inner.borrow().has_method(method)
+        });
+
+        result.unwrap_or(false) as sys::GDExtensionBool
+    }
+
+    pub(super) unsafe extern "C" fn call_func<T: ScriptInstance>(
+        p_self: sys::GDExtensionScriptInstanceDataPtr,
+        p_method: sys::GDExtensionConstStringNamePtr,
+        p_args: *const sys::GDExtensionConstVariantPtr,
+        p_argument_count: sys::GDExtensionInt,
+        r_return: sys::GDExtensionVariantPtr,
+        r_error: *mut sys::GDExtensionCallError,
+    ) {
+        let method = borrow_string_name(p_method);
+        let args: Vec<&Variant> = (0..p_argument_count as isize)
+            .map(|i| &*(*p_args.offset(i) as *const Variant))
+            .collect();
+
+        let result = with_data::<T, _>(p_self, "call", |data| {
+            data.inner.borrow_mut().call(method, &args)
+        });
+
+        match result {
+            Some(Ok(ret)) => {
+                *(r_return as *mut Variant) = ret;
+                (*r_error).error = sys::GDEXTENSION_CALL_OK;
+            }
+            Some(Err(error)) => {
+                (*r_error).error = error;
+            }
+            None => {
+                // Panicked; no better fitting error.
+                (*r_error).error = sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD;
+            }
+        }
+    }
+
+    pub(super) unsafe extern "C" fn notification_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_what: i32,
+    ) {
+        let _ = with_data::<T, _>(p_instance, "notification", |data| {
+            data.inner.borrow_mut().notification(p_what)
+        });
+    }
+
+    pub(super) unsafe extern "C" fn to_string_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        r_is_valid: *mut sys::GDExtensionBool,
+        r_out: sys::GDExtensionStringPtr,
+    ) {
+        let result = with_data::<T, _>(p_instance, "to_string", |data| {
+            data.inner.borrow().to_string()
+        });
+
+        match result {
+            Some(string) => {
+                // Transfer ownership to Godot
+                string.move_string_ptr(r_out);
+                *r_is_valid = true as sys::GDExtensionBool;
+            }
+            None => *r_is_valid = false as sys::GDExtensionBool,
+        }
+    }
+
+    pub(super) unsafe extern "C" fn refcount_incremented_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+    ) {
+        let _ = with_data::<T, _>(p_instance, "on_refcount_incremented", |data| {
+            data.inner.borrow().on_refcount_incremented()
+        });
+    }
+
+    pub(super) unsafe extern "C" fn refcount_decremented_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+    ) -> sys::GDExtensionBool {
+        let result = with_data::<T, _>(p_instance, "on_refcount_decremented", |data| {
+            data.inner.borrow().on_refcount_decremented()
+        });
+
+        result.unwrap_or(true) as sys::GDExtensionBool
+    }
+
+    pub(super) unsafe extern "C" fn get_script_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+    ) -> sys::GDExtensionObjectPtr {
+        // The script is kept alive by the instance; Godot increments the reference count itself.
+        let result = with_data::<T, _>(p_instance, "get_script", |data| {
+            data.inner.borrow().get_script().obj_sys()
+        });
+
+        result.unwrap_or(std::ptr::null_mut())
+    }
+
+    pub(super) unsafe extern "C" fn is_placeholder_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+    ) -> sys::GDExtensionBool {
+        let result = with_data::<T, _>(p_instance, "is_placeholder", |data| {
+            data.inner.borrow().is_placeholder()
+        });
+
+        result.unwrap_or(false) as sys::GDExtensionBool
+    }
+
+    pub(super) unsafe extern "C" fn set_fallback_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_name: sys::GDExtensionConstStringNamePtr,
+        p_value: sys::GDExtensionConstVariantPtr,
+    ) -> sys::GDExtensionBool {
+        let name = borrow_string_name(p_name);
+        let value = &*(p_value as *const Variant);
+
+        let result = with_data::<T, _>(p_instance, "property_set_fallback", |data| {
+            data.inner.borrow_mut().property_set_fallback(name, value)
+        });
+
+        result.unwrap_or(false) as sys::GDExtensionBool
+    }
+
+    pub(super) unsafe extern "C" fn get_fallback_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+        p_name: sys::GDExtensionConstStringNamePtr,
+        r_ret: sys::GDExtensionVariantPtr,
+    ) -> sys::GDExtensionBool {
+        let name = borrow_string_name(p_name);
+        let result = with_data::<T, _>(p_instance, "property_get_fallback", |data| {
+            data.inner.borrow().property_get_fallback(name)
+        });
+
+        write_variant(result, r_ret)
+    }
+
+    pub(super) unsafe extern "C" fn get_language_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+    ) -> sys::GDExtensionScriptLanguagePtr {
+        // Script languages are manually managed singletons, the pointer stays valid after the Gd is dropped.
+        let result = with_data::<T, _>(p_instance, "get_language", |data| {
+            data.inner.borrow().get_language().obj_sys() as sys::GDExtensionScriptLanguagePtr
+        });
+
+        result.unwrap_or(std::ptr::null_mut())
+    }
+
+    pub(super) unsafe extern "C" fn free_func<T: ScriptInstance>(
+        p_instance: sys::GDExtensionScriptInstanceDataPtr,
+    ) {
+        drop(Box::from_raw(p_instance as *mut ScriptInstanceData<T>));
+    }
+}
@@ -75,6 +75,12 @@ unsafe extern "C" fn ffi_deinitialize_layer<E: ExtensionLibrary>(
     let _ = crate::private::handle_panic(ctx, || {
         E::on_level_deinit(level);
         gdext_on_level_deinit(level);
+
+        // Last level to be unloaded: reset global state, so the library can be loaded again (hot reload).
+        if level == E::min_level() {
+            crate::builtin::meta::ClassName::cleanup();
+            sys::deinitialize();
+        }
     });
 }
 
@@ -96,14 +102,26 @@ fn gdext_on_level_init(level: InitLevel) {
             }
             InitLevel::Editor => {
                 sys::load_class_method_table(sys::ClassApiLevel::Editor);
+                crate::registry::register_editor_plugins();
+                crate::docs::register_class_docs();
             }
         }
     }
 }
 
 /// Tasks needed to be done by gdext internally upon unloading an initialization level. Called after user code.
-fn gdext_on_level_deinit(_level: InitLevel) {
-    // No logic at the moment.
+fn gdext_on_level_deinit(level: InitLevel) {
+    if level == InitLevel::Editor {
+        crate::registry::unregister_editor_plugins();
+    }
+
+    if level == InitLevel::Scene {
+        // Pending async tasks may hold objects and callables, release them while the engine is still alive.
+        #[cfg(since_api = "4.2")]
+        crate::task::cleanup();
+
+        crate::registry::unregister_classes();
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
@@ -4,6 +4,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod docs;
 mod registry;
 mod storage;
 
@@ -13,6 +14,7 @@ pub mod init;
 pub mod log;
 pub mod obj;
 pub mod property;
+pub mod task;
 
 pub use godot_ffi as sys;
 #[doc(hidden)]
@@ -47,12 +49,15 @@ pub mod private {
     // If someone forgets #[godot_api], this causes a compile error, rather than virtual functions not being called at runtime.
     #[allow(non_camel_case_types)]
     pub trait You_forgot_the_attribute__godot_api {}
-    pub use crate::property::Cannot_export_without_godot_api_impl;
 
     use std::sync::{Arc, Mutex};
 
+    pub use crate::docs::{InherentImplDocs, StructDocs};
     pub use crate::gen::classes::class_macros;
-    pub use crate::registry::{callbacks, ClassPlugin, ErasedRegisterFn, PluginComponent};
+    pub use crate::registry::{
+        callbacks, editor_plugins, register_class_from_plugins, unregister_class, BaseCallbacks,
+        ClassPlugin, ErasedRegisterFn, PluginComponent,
+    };
     pub use crate::storage::as_storage;
     pub use godot_ffi::out;
 
@@ -81,6 +86,71 @@ pub mod private {
             && *global_config.is_editor.get_or_init(is_editor)
     }
 
+    // Crates depending on godot cannot write `#[cfg(feature = "threads")]` for godot's features. These macros expand the given
+    // items only if the feature is enabled -- or, with a leading `!`, only if it is disabled. Used by integration tests.
+    pub use crate::{__cfg_serde as cfg_serde, __cfg_threads as cfg_threads};
+
+    #[doc(hidden)]
+    #[macro_export]
+    #[cfg(feature = "threads")]
+    macro_rules! __cfg_threads {
+        (! $($item:item)*) => {};
+        ($($item:item)*) => { $($item)* };
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    #[cfg(not(feature = "threads"))]
+    macro_rules! __cfg_threads {
+        (! $($item:item)*) => { $($item)* };
+        ($($item:item)*) => {};
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    #[cfg(feature = "serde")]
+    macro_rules! __cfg_serde {
+        (! $($item:item)*) => {};
+        ($($item:item)*) => { $($item)* };
+    }
+
+    #[doc(hidden)]
+    #[macro_export]
+    #[cfg(not(feature = "serde"))]
+    macro_rules! __cfg_serde {
+        (! $($item:item)*) => { $($item)* };
+        ($($item:item)*) => {};
+    }
+
+    /// Calls `method_name` on the object with ID `object_id`, if the script attached to it defines such a method.
+    ///
+    /// Used by `#[func(virtual)]` methods to dispatch to overrides in scripts extending the Rust class. The script is queried on
+    /// every call, so edits and reloads of the script take effect immediately.
+    pub fn call_script_override(
+        object_id: crate::obj::InstanceId,
+        method_name: &str,
+        args: &[crate::builtin::Variant],
+    ) -> Option<crate::builtin::Variant> {
+        use crate::builtin::ToVariant as _;
+        use crate::engine::{Object, Script};
+        use crate::obj::Gd;
+
+        let mut object = Gd::<Object>::from_instance_id(object_id);
+        let script = object.get_script().try_to::<Gd<Script>>().ok()?;
+
+        let method_name_variant = method_name.to_variant();
+        let is_overridden = script
+            .get_script_method_list()
+            .iter_shared()
+            .any(|method| method.get_or_nil("name") == method_name_variant);
+
+        if is_overridden {
+            Some(object.call(method_name.into(), args))
+        } else {
+            None
+        }
+    }
+
     fn print_panic(err: Box<dyn std::any::Any + Send>) {
         if let Some(s) = err.downcast_ref::<&'static str>() {
             print_panic_message(s);
@@ -15,14 +15,17 @@ use sys::{
     ffi_methods, interface_fn, static_assert_eq_size, GodotFfi, GodotNullablePtr, PtrcallType,
 };
 
-use crate::builtin::meta::{ClassName, VariantMetadata};
+use crate::builtin::meta::{CallError, ClassName, VariantMetadata};
 use crate::builtin::{
-    Callable, FromVariant, StringName, ToVariant, Variant, VariantConversionError, VariantType,
+    Callable, FromVariant, Signal, StringName, ToVariant, ToVariantArgs, Variant,
+    VariantConversionError, VariantType,
 };
+use crate::engine::global;
 use crate::obj::dom::Domain as _;
 use crate::obj::mem::Memory as _;
 use crate::obj::{
-    cap, dom, mem, EngineEnum, GdMut, GdRef, GodotClass, Inherits, InstanceId, Share,
+    cap, dom, mem, EngineEnum, ExportableObject, GdMut, GdRef, GodotClass, Inherits, InstanceId,
+    Share,
 };
 use crate::property::{Export, ExportInfo, Property, TypeStringHint};
 use crate::storage::InstanceStorage;
@@ -50,7 +53,17 @@ use crate::{callbacks, engine, out};
 ///   reference-counted or manually-managed types at runtime. The behavior corresponds to one of the two previous points.
 ///   Note that if the dynamic type is also `Object`, the memory is manually-managed.
 ///
+/// # Threading
+///
+/// By default, `Gd<T>` is neither `Send` nor `Sync`. User instances are stored without synchronization, and
+/// [`bind()`][Self::bind] and [`bind_mut()`][Self::bind_mut] panic when called from a thread other than the main thread.
+///
+/// With the Cargo feature `threads`, user instances are guarded by a `RwLock`, and `Gd<T>` is `Send` and `Sync` as long as
+/// `T` is. Engine classes are not thread-safe in general (see [Godot's thread-safety rules][thread-safe-apis]), so pointers to
+/// them, including a `Base<T>` field in your class, cannot be sent to other threads.
+///
 /// [`Object`]: crate::engine::Object
+/// [thread-safe-apis]: https://docs.godotengine.org/en/stable/tutorials/performance/thread_safe_apis.html
 /// [`RefCounted`]: crate::engine::RefCounted
 #[repr(C)] // must be layout compatible with engine classes
 pub struct Gd<T: GodotClass> {
@@ -460,6 +473,106 @@ impl<T: GodotClass> Gd<T> {
     pub fn callable<S: Into<StringName>>(&self, method_name: S) -> Callable {
         Callable::from_object_method(self.clone(), method_name)
     }
+
+    /// Returns a signal referencing the signal of this object named `signal_name`.
+    ///
+    /// For signals declared with `#[signal]`, the typed handles generated for the class are usually more convenient.
+    pub fn signal<S: Into<StringName>>(&self, signal_name: S) -> Signal {
+        Signal::from_object_signal(self, signal_name)
+    }
+
+    /// Dynamically calls the method `method_name`, converting arguments and return value from/to Rust types.
+    ///
+    /// `args` is a tuple of [`ToVariant`] values, for example `(5, "text")`; pass `()` for no arguments. The method can be
+    /// declared by the engine, by a script attached to the object, or in Rust with `#[func]`. Methods without return value
+    /// return `Variant::nil()`, so use `R = Variant` to call them.
+    ///
+    /// Returns an error if Godot cannot perform the call (e.g. unknown method or wrong arguments), or
+    /// [`CallError::InvalidReturnValue`] if the returned value cannot be converted to `R`.
+    ///
+    /// ```no_run
+    /// # use godot::prelude::*;
+    /// # use godot::builtin::meta::CallError;
+    /// # fn example(mut node: Gd<Node>) -> Result<(), CallError> {
+    /// let count: i64 = node.call_typed("get_child_count", ())?;
+    /// node.call_typed::<Variant, _, _>("set_name", ("Player",))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// _Godot equivalent: `Object.call(method, ...)`_
+    pub fn call_typed<R, S, A>(&mut self, method_name: S, args: A) -> Result<R, CallError>
+    where
+        R: FromVariant,
+        S: Into<StringName>,
+        A: ToVariantArgs,
+    {
+        let method_name = method_name.into();
+        let args = args.to_variant_args();
+
+        let result = self.as_object(|obj| obj.try_call(method_name.clone(), &args))?;
+        R::try_from_variant(&result).map_err(|_| CallError::InvalidReturnValue {
+            actual: result.get_type(),
+        })
+    }
+
+    /// Reads the property `property` and converts it to `V`.
+    ///
+    /// Godot returns `null` for properties that don't exist, so those fail with a conversion error unless `V` accepts nil
+    /// (e.g. `Variant` or `Option<Gd<U>>`).
+    ///
+    /// _Godot equivalent: `Object.get(property)`_
+    pub fn get_as<V, S>(&self, property: S) -> Result<V, VariantConversionError>
+    where
+        V: FromVariant,
+        S: Into<StringName>,
+    {
+        let property = property.into();
+        let value = self.as_object(|obj| obj.get(property.clone()));
+
+        V::try_from_variant(&value)
+    }
+
+    /// Sets the property `property` to `value`, converted from a Rust type. Counterpart to [`get_as()`][Self::get_as].
+    ///
+    /// Like in GDScript, assigning a property that doesn't exist or has an incompatible type is silently ignored.
+    ///
+    /// _Godot equivalent: `Object.set(property, value)`_
+    pub fn set_as<S, V>(&mut self, property: S, value: V)
+    where
+        S: Into<StringName>,
+        V: ToVariant,
+    {
+        let property = property.into();
+        let value = value.to_variant();
+
+        self.as_object(|obj| obj.set(property.clone(), value.clone()));
+    }
+
+    /// Connects the signal `signal_name` of this object to the method `method_name` of `receiver`.
+    ///
+    /// Shorthand for `connect(signal_name, receiver.callable(method_name))`, with Godot's error code mapped to a `Result`.
+    ///
+    /// _Godot equivalent: `Object.connect(signal, Callable(receiver, method))`_
+    pub fn connect_obj<U, S1, S2>(
+        &mut self,
+        signal_name: S1,
+        receiver: &Gd<U>,
+        method_name: S2,
+    ) -> Result<(), global::Error>
+    where
+        U: GodotClass,
+        S1: Into<StringName>,
+        S2: Into<StringName>,
+    {
+        let signal_name = signal_name.into();
+        let callable = receiver.callable(method_name);
+
+        match self.as_object(|obj| obj.connect(signal_name.clone(), callable.clone())) {
+            global::Error::OK => Ok(()),
+            error => Err(error),
+        }
+    }
 }
 
 /// _The methods in this impl block are only available for objects `T` that are manually managed,
@@ -510,7 +623,7 @@ where
 impl<T: GodotClass> Deref for Gd<T> {
     // Target is always an engine class:
     // * if T is an engine class => T
-    // * if T is a user class => T::Base
+    // * if T is a user class => T::Base, or its closest engine class if that is a user class too
     type Target = <<T as GodotClass>::Declarer as dom::Domain>::DerefTarget<T>;
 
     fn deref(&self) -> &Self::Target {
@@ -723,7 +836,7 @@ impl<T: GodotClass> TypeStringHint for Gd<T> {
     fn type_string() -> String {
         use engine::global::PropertyHint;
 
-        match Self::default_export_info().hint {
+        match object_export_hint::<T>() {
             hint @ (PropertyHint::PROPERTY_HINT_RESOURCE_TYPE
             | PropertyHint::PROPERTY_HINT_NODE_TYPE) => {
                 format!(
@@ -750,21 +863,30 @@ impl<T: GodotClass> Property for Gd<T> {
     }
 }
 
-impl<T: GodotClass> Export for Gd<T> {
+// Only resources and nodes can be exported; the editor then lets the user pick objects of class `T` or a subclass.
+impl<T: ExportableObject> Export for Gd<T> {
     fn default_export_info() -> ExportInfo {
-        let hint = if T::inherits::<engine::Resource>() {
-            engine::global::PropertyHint::PROPERTY_HINT_RESOURCE_TYPE
-        } else if T::inherits::<engine::Node>() {
-            engine::global::PropertyHint::PROPERTY_HINT_NODE_TYPE
-        } else {
-            engine::global::PropertyHint::PROPERTY_HINT_NONE
-        };
-
         // Godot does this by default too; the hint is needed when the class is a resource/node,
         // but doesn't seem to make a difference otherwise.
         let hint_string = T::class_name().to_godot_string();
 
-        ExportInfo { hint, hint_string }
+        ExportInfo {
+            hint: object_export_hint::<T>(),
+            hint_string,
+        }
+    }
+}
+
+/// Property hint with which an object of class `T` is exported, either on its own or as an array element.
+///
+/// Works for both engine and user classes, since the latter are checked through their `Base` chain.
+fn object_export_hint<T: GodotClass>() -> global::PropertyHint {
+    if T::inherits::<engine::Resource>() {
+        global::PropertyHint::PROPERTY_HINT_RESOURCE_TYPE
+    } else if T::inherits::<engine::Node>() {
+        global::PropertyHint::PROPERTY_HINT_NODE_TYPE
+    } else {
+        global::PropertyHint::PROPERTY_HINT_NONE
     }
 }
 
@@ -876,3 +998,11 @@ impl<T: GodotClass> VariantMetadata for Gd<T> {
 // its mutability is anyway present, in the Godot engine.
 impl<T: GodotClass> std::panic::UnwindSafe for Gd<T> {}
 impl<T: GodotClass> std::panic::RefUnwindSafe for Gd<T> {}
+
+// SAFETY: with the `threads` feature, user instances are synchronized through a RwLock inside the instance storage, which is
+// Sync if T is Send + Sync. The object pointer itself may be shared freely; `cached_instance_id` is only written during construction.
+#[cfg(feature = "threads")]
+unsafe impl<T: GodotClass + Send + Sync> Send for Gd<T> {}
+
+#[cfg(feature = "threads")]
+unsafe impl<T: GodotClass + Send + Sync> Sync for Gd<T> {}
@@ -15,9 +15,11 @@ mod gd;
 mod guards;
 mod instance_id;
 mod traits;
+mod typed_signal;
 
 pub use base::*;
 pub use gd::*;
 pub use guards::*;
 pub use instance_id::*;
 pub use traits::*;
+pub use typed_signal::*;
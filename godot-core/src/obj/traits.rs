@@ -24,7 +24,7 @@ pub unsafe trait GodotClass: 'static
 where
     Self: Sized,
 {
-    /// The immediate superclass of `T`. This is always a Godot engine class.
+    /// The immediate superclass of `T`. This is a Godot engine class, or another user class declared with `#[class(rust_base)]`.
     type Base: GodotClass; // not EngineClass because it can be ()
 
     /// Whether this class is a core Godot class provided by the engine, or declared by the user as a Rust struct.
@@ -125,9 +125,29 @@ impl<T: GodotClass> Inherits<T> for T {}
 
 /// Trait implemented for all objects that inherit from `Resource` or `Node`.
 ///
-/// Those are the only objects you can export to the editor.
+/// Those are the only objects you can export to the editor. User classes implement this automatically if their base class does.
 pub trait ExportableObject: GodotClass {}
 
+impl<T> ExportableObject for T
+where
+    T: GodotClass<Declarer = dom::UserDomain>,
+    T::Base: ExportableObject,
+{
+}
+
+/// Recreates user instances after the extension library has been hot-reloaded (Godot 4.2+).
+///
+/// Implement this for classes declared with `#[class(reloadable)]`. When the library is reloaded, existing objects keep
+/// living in the engine, but their Rust instances are gone; `recreate()` is invoked for each of them instead of `init()`.
+///
+/// Godot restores all registered properties after recreation, so this method only needs to set up state that is not
+/// exported as a property. Once the properties are restored, the object receives `NOTIFICATION_EXTENSION_RELOADED`,
+/// which can be handled in `on_notification()` to migrate any remaining state.
+pub trait Reloadable: GodotClass {
+    /// Creates a new Rust instance for an object that already existed before the reload.
+    fn recreate(base: Base<Self::Base>) -> Self;
+}
+
 /// Auto-implemented for all engine-provided classes.
 pub trait EngineClass: GodotClass {
     fn as_object_ptr(&self) -> sys::GDExtensionObjectPtr;
@@ -178,6 +198,8 @@ pub trait IndexEnum: EngineEnum {
 /// Capability traits, providing dedicated functionalities for Godot classes
 pub mod cap {
     use super::*;
+    use crate::builtin::meta::PropertyInfo;
+    use crate::builtin::{StringName, Variant};
     use crate::obj::Gd;
 
     /// Trait for all classes that are constructible from the Godot engine.
@@ -220,6 +242,36 @@ pub mod cap {
         fn __godot_notification(&mut self, what: i32);
     }
 
+    #[doc(hidden)]
+    pub trait GodotGet: GodotClass {
+        #[doc(hidden)]
+        fn __godot_get_property(&self, property: StringName) -> Option<Variant>;
+    }
+
+    #[doc(hidden)]
+    pub trait GodotSet: GodotClass {
+        #[doc(hidden)]
+        fn __godot_set_property(&mut self, property: StringName, value: Variant) -> bool;
+    }
+
+    #[doc(hidden)]
+    pub trait GodotGetPropertyList: GodotClass {
+        #[doc(hidden)]
+        fn __godot_get_property_list(&self) -> Vec<PropertyInfo>;
+    }
+
+    #[doc(hidden)]
+    pub trait GodotPropertyCanRevert: GodotClass {
+        #[doc(hidden)]
+        fn __godot_property_can_revert(&self, property: StringName) -> bool;
+    }
+
+    #[doc(hidden)]
+    pub trait GodotPropertyGetRevert: GodotClass {
+        #[doc(hidden)]
+        fn __godot_property_get_revert(&self, property: StringName) -> Option<Variant>;
+    }
+
     // TODO Evaluate whether we want this public or not
     #[doc(hidden)]
     pub trait GodotRegisterClass: GodotClass {
@@ -227,7 +279,10 @@ pub mod cap {
         fn __godot_register_class(builder: &mut ClassBuilder<Self>);
     }
 
-    /// Auto-implemented for `#[godot_api] impl MyClass` blocks
+    /// Registers methods and constants of a class, through `callbacks::register_user_binds()`.
+    ///
+    /// `#[godot_api] impl MyClass` blocks do not implement this trait, but register a free function each, so that a class
+    /// can have multiple such blocks. Implement it manually only for registrations the proc-macros cannot express yet.
     pub trait ImplementsGodotApi: GodotClass {
         #[doc(hidden)]
         fn __register_methods();
@@ -235,6 +290,7 @@ pub mod cap {
         fn __register_constants();
     }
 
+    /// Auto-implemented by `#[derive(GodotClass)]`, registering all `#[var]` and `#[export]` properties.
     pub trait ImplementsGodotExports: GodotClass {
         #[doc(hidden)]
         fn __register_exports();
@@ -244,6 +300,10 @@ pub mod cap {
     pub trait ImplementsGodotVirtual: GodotClass {
         #[doc(hidden)]
         fn __virtual_call(_name: &str) -> sys::GDExtensionClassCallVirtual;
+
+        /// Callbacks of the Rust base class which this class overrides. Set once, when the class is registered.
+        #[doc(hidden)]
+        fn __base_callbacks() -> &'static std::sync::OnceLock<crate::BaseCallbacks>;
     }
 }
 
@@ -289,7 +349,9 @@ pub mod dom {
     pub enum UserDomain {}
     impl Sealed for UserDomain {}
     impl Domain for UserDomain {
-        type DerefTarget<T: GodotClass> = T::Base;
+        // The closest engine class, skipping Rust base classes.
+        type DerefTarget<T: GodotClass> =
+            <<T::Base as GodotClass>::Declarer as Domain>::DerefTarget<T::Base>;
 
         fn scoped_mut<T, F, R>(obj: &mut Gd<T>, closure: F) -> R
         where
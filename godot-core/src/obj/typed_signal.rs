@@ -0,0 +1,141 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::builtin::{Callable, StringName, ToVariant};
+use crate::engine::Object;
+use crate::obj::{Gd, GodotClass, Inherits};
+
+/// Typed handle to a signal declared with `#[signal]` on a user class.
+///
+/// The type parameter `Ps` is a tuple of the signal's parameter types. It determines the signature of [`emit()`][Self::emit],
+/// so that arity and argument types are checked at compile time, rather than failing inside Godot at runtime.
+///
+/// You do not construct this type yourself. For every `#[signal] fn my_signal(...)` in a `#[godot_api]` block, an associated function
+/// `signal_my_signal(&Gd<O>)` is generated on the class, which returns the handle for a given object. `O` must be the class itself or
+/// a class inheriting from it, so the signal is always declared on the object:
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     #[base]
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[signal]
+///     fn hit(damage: i64, source: Gd<Node>);
+///
+///     #[func]
+///     fn take_damage(&mut self, damage: i64, source: Gd<Node>) {
+///         let this = self.base.clone().cast::<Self>();
+///         Self::signal_hit(&this).emit(damage, source);
+///     }
+/// }
+/// ```
+pub struct TypedSignal<Ps> {
+    object: Gd<Object>,
+    name: StringName,
+    _signature: PhantomData<fn(Ps)>,
+}
+
+impl<Ps> TypedSignal<Ps> {
+    #[doc(hidden)]
+    pub fn __new<O>(object: &Gd<O>, name: &str) -> Self
+    where
+        O: Inherits<Object>,
+    {
+        Self {
+            object: object.clone().upcast(),
+            name: StringName::from(name),
+            _signature: PhantomData,
+        }
+    }
+
+    /// Name of the signal, as registered in Godot.
+    pub fn name(&self) -> StringName {
+        self.name.clone()
+    }
+
+    /// Object which emits this signal.
+    pub fn object(&self) -> Gd<Object> {
+        self.object.clone()
+    }
+
+    /// Connects this signal to `callable`, which is invoked on every emission.
+    ///
+    /// _Godot equivalent: `Object.connect(signal, callable)`_
+    pub fn connect(&mut self, callable: Callable) {
+        self.object.connect(self.name.clone(), callable);
+    }
+
+    /// Connects this signal to the method `method_name` of `receiver`.
+    ///
+    /// Shorthand for `self.connect(receiver.callable(method_name))`.
+    pub fn connect_obj<T, S>(&mut self, receiver: &Gd<T>, method_name: S)
+    where
+        T: GodotClass,
+        S: Into<StringName>,
+    {
+        self.connect(receiver.callable(method_name));
+    }
+
+    /// Disconnects `callable` from this signal, if it has previously been connected.
+    ///
+    /// _Godot equivalent: `Object.disconnect(signal, callable)`_
+    pub fn disconnect(&mut self, callable: Callable) {
+        self.object.disconnect(self.name.clone(), callable);
+    }
+
+    /// Returns whether `callable` is connected to this signal.
+    ///
+    /// _Godot equivalent: `Object.is_connected(signal, callable)`_
+    pub fn is_connected(&self, callable: Callable) -> bool {
+        self.object.is_connected(self.name.clone(), callable)
+    }
+}
+
+impl<Ps> fmt::Debug for TypedSignal<Ps> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedSignal")
+            .field("object", &self.object)
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Emission, one impl per arity
+
+macro_rules! impl_typed_signal_emit {
+    ($($Pn:ident : $pn:ident),*) => {
+        impl<$($Pn: ToVariant,)*> TypedSignal<($($Pn,)*)> {
+            /// Emits the signal with the given arguments.
+            ///
+            /// _Godot equivalent: `Object.emit_signal(signal, ...)`_
+            pub fn emit(&mut self, $($pn: $Pn),*) {
+                self.object.emit_signal(self.name.clone(), &[$($pn.to_variant()),*]);
+            }
+        }
+    };
+}
+
+impl_typed_signal_emit!();
+impl_typed_signal_emit!(P0: p0);
+impl_typed_signal_emit!(P0: p0, P1: p1);
+impl_typed_signal_emit!(P0: p0, P1: p1, P2: p2);
+impl_typed_signal_emit!(P0: p0, P1: p1, P2: p2, P3: p3);
+impl_typed_signal_emit!(P0: p0, P1: p1, P2: p2, P3: p3, P4: p4);
+impl_typed_signal_emit!(P0: p0, P1: p1, P2: p2, P3: p3, P4: p4, P5: p5);
+impl_typed_signal_emit!(P0: p0, P1: p1, P2: p2, P3: p3, P4: p4, P5: p5, P6: p6);
+impl_typed_signal_emit!(P0: p0, P1: p1, P2: p2, P3: p3, P4: p4, P5: p5, P6: p6, P7: p7);
+impl_typed_signal_emit!(P0: p0, P1: p1, P2: p2, P3: p3, P4: p4, P5: p5, P6: p6, P7: p7, P8: p8);
+impl_typed_signal_emit!(P0: p0, P1: p1, P2: p2, P3: p3, P4: p4, P5: p5, P6: p6, P7: p7, P8: p8, P9: p9);
@@ -100,23 +100,6 @@ impl ExportInfo {
     }
 }
 
-/// To export properties to Godot, you must have an impl-block with the `#[godot_api]` attribute, even if
-/// it is empty.
-///
-/// This trait is automatically implemented when such an impl-block is present. If Rust complains that it is
-/// not implemented, then you can usually fix this by adding:
-///
-/// ```ignore
-/// #[godot_api]
-/// impl MyClass {}
-/// ```
-///
-/// Where you replace `MyClass` with the name of your class.
-#[allow(non_camel_case_types)]
-pub trait Cannot_export_without_godot_api_impl {
-    const EXISTS: () = ();
-}
-
 /// Functions used to translate user-provided arguments into export hints.
 pub mod export_info_functions {
     use crate::builtin::GodotString;
@@ -316,10 +299,12 @@ mod export_impls {
     macro_rules! impl_property_by_clone {
         ($Ty:ty => $variant_type:ident, no_export) => {
             impl_property_by_clone!(@property $Ty => $variant_type);
+            impl_property_by_clone!(@type_string_hint $Ty => $variant_type);
         };
 
         ($Ty:ty => $variant_type:ident) => {
             impl_property_by_clone!(@property $Ty => $variant_type);
+            impl_property_by_clone!(@type_string_hint $Ty => $variant_type);
             impl_property_by_clone!(@export $Ty);
         };
 
@@ -337,6 +322,14 @@ mod export_impls {
             }
         };
 
+        (@type_string_hint $Ty:ty => $variant_type:ident) => {
+            impl TypeStringHint for $Ty {
+                fn type_string() -> String {
+                    format!("{}:", VariantType::$variant_type as i32)
+                }
+            }
+        };
+
         (@export $Ty:ty) => {
             impl Export for $Ty {
                 fn default_export_info() -> ExportInfo {
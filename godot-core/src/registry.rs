@@ -13,11 +13,14 @@ use godot_ffi as sys;
 
 use sys::interface_fn;
 
-use crate::builtin::meta::ClassName;
-use crate::builtin::StringName;
+use crate::builtin::meta::{ClassName, PropertyInfo};
+use crate::builtin::{StringName, Variant};
+use crate::docs::{ClassDocs, InherentImplDocs, StructDocs};
 use crate::out;
 use std::any::Any;
 use std::collections::HashMap;
+use std::panic::AssertUnwindSafe;
+use std::sync::{Mutex, OnceLock};
 use std::{fmt, ptr};
 
 // TODO(bromeon): some information coming from the proc-macro API is deferred through PluginComponent, while others is directly
@@ -64,14 +67,50 @@ pub enum PluginComponent {
             _class_user_data: *mut std::ffi::c_void,
             instance: sys::GDExtensionClassInstancePtr,
         ),
+
+        /// Godot low-level `recreate` function, wired up to `Reloadable::recreate()` for `#[class(reloadable)]`.
+        ///
+        /// Only used on Godot 4.2+, earlier versions cannot hot-reload extensions.
+        recreate_fn: Option<
+            unsafe extern "C" fn(
+                _class_userdata: *mut std::ffi::c_void,
+                object: sys::GDExtensionObjectPtr,
+            ) -> sys::GDExtensionClassInstancePtr,
+        >,
+
+        /// Whether the class is added to the editor as an `EditorPlugin`, via `#[class(editor_plugin)]`.
+        is_editor_plugin: bool,
+
+        /// Whether the class is declared `#[class(abstract)]`, i.e. hidden from the editor's "Create New Node" dialog.
+        ///
+        /// Classes without a constructor are additionally registered as abstract, so Godot refuses to instantiate them.
+        is_abstract: bool,
+
+        /// Documentation of the struct and its properties, shown in the editor help.
+        docs: StructDocs,
+
+        /// Callback to library-generated function which registers `#[var]` and `#[export]` properties.
+        ///
+        /// Invoked after all `#[godot_api]` blocks have been registered, since properties can refer to their methods.
+        register_properties_fn: ErasedRegisterFn,
     },
 
+    /// Constants registered separately from the class, e.g. enums with `#[derive(GodotEnum)]`.
+    ///
+    /// Unlike other components, a class can have any number of these.
+    UserConstants { register_fn: ErasedRegisterFn },
+
     /// Collected from `#[godot_api] impl MyClass`
+    ///
+    /// A class can have any number of these, e.g. when its API is split across several modules.
     UserMethodBinds {
         /// Callback to library-generated function which registers functions in the `impl`
         ///
         /// Always present since that's the entire point of this `impl` block.
         generated_register_fn: ErasedRegisterFn,
+
+        /// Documentation of the methods, signals and constants in the `impl`, shown in the editor help.
+        docs: InherentImplDocs,
     },
 
     /// Collected from `#[godot_api] impl GodotExt for MyClass`
@@ -96,11 +135,49 @@ pub enum PluginComponent {
         >,
 
         /// User-defined `on_notification` function
-        user_on_notification_fn: Option<
+        user_on_notification_fn: Option<NotificationFn>,
+
+        /// User-defined `set_property` function
+        user_set_fn: Option<
             unsafe extern "C" fn(
-                p_instance: sys::GDExtensionClassInstancePtr, //
-                p_what: i32,
-            ),
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_name: sys::GDExtensionConstStringNamePtr,
+                p_value: sys::GDExtensionConstVariantPtr,
+            ) -> sys::GDExtensionBool,
+        >,
+
+        /// User-defined `get_property` function
+        user_get_fn: Option<
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_name: sys::GDExtensionConstStringNamePtr,
+                r_ret: sys::GDExtensionVariantPtr,
+            ) -> sys::GDExtensionBool,
+        >,
+
+        /// User-defined `get_property_list` function; the list is freed again through `callbacks::free_property_list`.
+        user_get_property_list_fn: Option<
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                r_count: *mut u32,
+            ) -> *const sys::GDExtensionPropertyInfo,
+        >,
+
+        /// User-defined `property_can_revert` function
+        user_property_can_revert_fn: Option<
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_name: sys::GDExtensionConstStringNamePtr,
+            ) -> sys::GDExtensionBool,
+        >,
+
+        /// User-defined `property_get_revert` function
+        user_property_get_revert_fn: Option<
+            unsafe extern "C" fn(
+                p_instance: sys::GDExtensionClassInstancePtr,
+                p_name: sys::GDExtensionConstStringNamePtr,
+                r_ret: sys::GDExtensionVariantPtr,
+            ) -> sys::GDExtensionBool,
         >,
 
         /// Callback for other virtuals
@@ -108,20 +185,67 @@ pub enum PluginComponent {
             p_userdata: *mut std::os::raw::c_void,
             p_name: sys::GDExtensionConstStringNamePtr,
         ) -> sys::GDExtensionClassCallVirtual,
+
+        /// Per-class storage for the callbacks of a Rust base class, which this class overrides
+        base_callbacks_fn: fn() -> &'static OnceLock<BaseCallbacks>,
     },
 }
 
+/// Signature of the `notification` callback; Godot 4.2 added a `reversed` parameter.
+#[cfg(before_api = "4.2")]
+pub type NotificationFn = unsafe extern "C" fn(
+    p_instance: sys::GDExtensionClassInstancePtr, //
+    p_what: i32,
+);
+
+/// Signature of the `notification` callback; Godot 4.2 added a `reversed` parameter.
+#[cfg(since_api = "4.2")]
+pub type NotificationFn = unsafe extern "C" fn(
+    p_instance: sys::GDExtensionClassInstancePtr,
+    p_what: i32,
+    p_reversed: sys::GDExtensionBool,
+);
+
+// Godot 4.2 introduced a second version of the creation info, which is required for hot reloading.
+#[cfg(before_api = "4.2")]
+type GodotCreationInfo = sys::GDExtensionClassCreationInfo;
+#[cfg(since_api = "4.2")]
+type GodotCreationInfo = sys::GDExtensionClassCreationInfo2;
+
 // ----------------------------------------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug)]
 struct ClassRegistrationInfo {
     class_name: ClassName,
     parent_class_name: Option<ClassName>,
-    generated_register_fn: Option<ErasedRegisterFn>,
+    generated_register_fns: Vec<ErasedRegisterFn>,
+    register_properties_fn: Option<ErasedRegisterFn>,
     user_register_fn: Option<ErasedRegisterFn>,
-    godot_params: sys::GDExtensionClassCreationInfo,
+    constant_register_fns: Vec<ErasedRegisterFn>,
+    godot_params: GodotCreationInfo,
+    is_editor_plugin: bool,
+    is_abstract: bool,
+    docs: ClassDocs,
+    base_callbacks_fn: Option<fn() -> &'static OnceLock<BaseCallbacks>>,
 }
 
+/// Classes registered with Godot, in order of registration. Unregistered in reverse order upon unloading the library.
+static LOADED_CLASSES: Mutex<Vec<ClassName>> = Mutex::new(Vec::new());
+
+/// Callbacks of a Rust base class which are overridden by a derived class.
+///
+/// Godot only invokes the callbacks of the most-derived class. Notifications and virtual methods are forwarded to these ones.
+/// Stored per derived class, see [`cap::ImplementsGodotVirtual::__base_callbacks()`].
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug)]
+pub struct BaseCallbacks {
+    notification_func: Option<NotificationFn>,
+    get_virtual_func: sys::GDExtensionClassGetVirtual,
+}
+
+/// Registered classes declared with `#[class(editor_plugin)]`. Added to the editor once the `Editor` init level is loaded.
+static EDITOR_PLUGINS: Mutex<Vec<ClassName>> = Mutex::new(Vec::new());
+
 /// Registers a class with static type information.
 pub fn register_class<
     T: cap::GodotInit
@@ -134,7 +258,7 @@ pub fn register_class<
 
     out!("Manually register class {}", std::any::type_name::<T>());
 
-    let godot_params = sys::GDExtensionClassCreationInfo {
+    let godot_params = GodotCreationInfo {
         to_string_func: Some(callbacks::to_string::<T>),
         notification_func: Some(callbacks::on_notification::<T>),
         reference_func: Some(callbacks::reference::<T>),
@@ -150,18 +274,32 @@ pub fn register_class<
     register_class_raw(ClassRegistrationInfo {
         class_name: T::class_name(),
         parent_class_name: Some(T::Base::class_name()),
-        generated_register_fn: None,
+        generated_register_fns: Vec::new(),
+        register_properties_fn: None,
         user_register_fn: Some(ErasedRegisterFn {
             raw: callbacks::register_class_by_builder::<T>,
         }),
+        constant_register_fns: Vec::new(),
         godot_params,
+        is_editor_plugin: false,
+        is_abstract: false,
+        docs: ClassDocs::default(),
+        base_callbacks_fn: Some(T::__base_callbacks),
     });
 }
 
 /// Lets Godot know about all classes that have self-registered through the plugin system.
+///
+/// Classes declared with `#[class(editor_plugin)]` are skipped, as `EditorPlugin` only exists from the `Editor` init level on.
+/// They are registered by [`register_editor_plugins()`] instead.
 pub fn auto_register_classes() {
     out!("Auto-register classes...");
+    register_plugin_classes(|info| !info.is_editor_plugin);
+    out!("All classes auto-registered.");
+}
 
+/// Registers all classes from the plugin registry for which `filter` returns true.
+fn register_plugin_classes(filter: impl Fn(&ClassRegistrationInfo) -> bool) {
     // Note: many errors are already caught by the compiler, before this runtime validation even takes place:
     // * missing #[derive(GodotClass)] or impl GodotClass for T
     // * duplicate impl GodotInit for T
@@ -181,13 +319,79 @@ pub fn auto_register_classes() {
     });
 
     //out!("Class-map: {map:#?}");
+    map.retain(|_, info| filter(info));
 
-    for info in map.into_values() {
-        out!("Register class:   {}", info.class_name);
-        register_class_raw(info);
+    let class_names: Vec<ClassName> = map.keys().copied().collect();
+    let mut registered = HashMap::new();
+    for class_name in class_names {
+        register_with_rust_bases(class_name, &mut map, &mut registered);
     }
+}
 
-    out!("All classes auto-registered.");
+/// Registers a class from `map`, after its Rust base classes (Godot requires bases to be registered first).
+///
+/// `registered` holds the creation info of all classes registered so far, so that derived classes can fall back to it.
+fn register_with_rust_bases(
+    class_name: ClassName,
+    map: &mut HashMap<ClassName, ClassRegistrationInfo>,
+    registered: &mut HashMap<ClassName, GodotCreationInfo>,
+) {
+    // Already registered as base of another class.
+    let Some(mut info) = map.remove(&class_name) else {
+        return;
+    };
+
+    let parent_class_name = info
+        .parent_class_name
+        .expect("class defined (parent_class_name)");
+
+    if map.contains_key(&parent_class_name) {
+        register_with_rust_bases(parent_class_name, map, registered);
+    }
+    if let Some(base_params) = registered.get(&parent_class_name) {
+        inherit_callbacks(&mut info, base_params);
+    }
+
+    out!("Register class:   {}", info.class_name);
+    let godot_params = info.godot_params;
+    register_class_raw(info);
+    registered.insert(class_name, godot_params);
+}
+
+/// Lets a class inheriting from a Rust class use the callbacks of its base, where it doesn't provide its own.
+///
+/// The base's callbacks work with derived objects, since they look up the storage of their own class.
+fn inherit_callbacks(info: &mut ClassRegistrationInfo, base_params: &GodotCreationInfo) {
+    let params = &mut info.godot_params;
+    params.to_string_func = params.to_string_func.or(base_params.to_string_func);
+    params.set_func = params.set_func.or(base_params.set_func);
+    params.get_func = params.get_func.or(base_params.get_func);
+    if params.get_property_list_func.is_none() {
+        params.get_property_list_func = base_params.get_property_list_func;
+        params.free_property_list_func = base_params.free_property_list_func;
+    }
+    params.property_can_revert_func = params
+        .property_can_revert_func
+        .or(base_params.property_can_revert_func);
+    params.property_get_revert_func = params
+        .property_get_revert_func
+        .or(base_params.property_get_revert_func);
+
+    // Notifications and virtual methods are not replaced, but forwarded to the base (see callbacks).
+    let overridden = BaseCallbacks {
+        notification_func: params.notification_func.and(base_params.notification_func),
+        get_virtual_func: params.get_virtual_func.and(base_params.get_virtual_func),
+    };
+    params.notification_func = params.notification_func.or(base_params.notification_func);
+    params.get_virtual_func = params.get_virtual_func.or(base_params.get_virtual_func);
+
+    // Overriding notifications or virtuals requires a `#[godot_api]` trait impl, which provides `base_callbacks_fn`.
+    // When classes are registered again in the same process, the callbacks are the same, so an already set value is kept.
+    if overridden.notification_func.is_some() || overridden.get_virtual_func.is_some() {
+        if let Some(base_callbacks_fn) = info.base_callbacks_fn {
+            let _ = base_callbacks_fn().set(overridden);
+        }
+    }
 }
 
 /// Populate `c` with all the relevant data from `component` (depending on component type).
@@ -199,19 +403,41 @@ fn fill_class_info(component: PluginComponent, c: &mut ClassRegistrationInfo) {
             base_class_name,
             generated_create_fn,
             free_fn,
+            recreate_fn,
+            is_editor_plugin,
+            is_abstract,
+            docs,
+            register_properties_fn,
         } => {
             c.parent_class_name = Some(base_class_name);
+            c.is_editor_plugin = is_editor_plugin;
+            c.is_abstract = is_abstract;
+            c.docs.struct_docs = docs;
+            c.register_properties_fn = Some(register_properties_fn);
             fill_into(
                 &mut c.godot_params.create_instance_func,
                 generated_create_fn,
             );
             c.godot_params.free_instance_func = Some(free_fn);
+
+            #[cfg(since_api = "4.2")]
+            {
+                c.godot_params.recreate_instance_func = recreate_fn;
+            }
+            #[cfg(before_api = "4.2")]
+            let _ = recreate_fn;
+        }
+
+        PluginComponent::UserConstants { register_fn } => {
+            c.constant_register_fns.push(register_fn);
         }
 
         PluginComponent::UserMethodBinds {
             generated_register_fn,
+            docs,
         } => {
-            c.generated_register_fn = Some(generated_register_fn);
+            c.generated_register_fns.push(generated_register_fn);
+            c.docs.impl_docs.push(docs);
         }
 
         PluginComponent::UserVirtuals {
@@ -219,13 +445,28 @@ fn fill_class_info(component: PluginComponent, c: &mut ClassRegistrationInfo) {
             user_create_fn,
             user_to_string_fn,
             user_on_notification_fn,
+            user_set_fn,
+            user_get_fn,
+            user_get_property_list_fn,
+            user_property_can_revert_fn,
+            user_property_get_revert_fn,
             get_virtual_fn,
+            base_callbacks_fn,
         } => {
             c.user_register_fn = user_register_fn;
             fill_into(&mut c.godot_params.create_instance_func, user_create_fn);
             c.godot_params.to_string_func = user_to_string_fn;
             c.godot_params.notification_func = user_on_notification_fn;
+            c.godot_params.set_func = user_set_fn;
+            c.godot_params.get_func = user_get_fn;
+            c.godot_params.get_property_list_func = user_get_property_list_fn;
+            if user_get_property_list_fn.is_some() {
+                c.godot_params.free_property_list_func = Some(callbacks::free_property_list);
+            }
+            c.godot_params.property_can_revert_func = user_property_can_revert_fn;
+            c.godot_params.property_get_revert_func = user_property_get_revert_fn;
             c.godot_params.get_virtual_func = Some(get_virtual_fn);
+            c.base_callbacks_fn = Some(base_callbacks_fn);
         }
     }
     // out!("|   reg (after):     {c:?}");
@@ -242,9 +483,16 @@ fn fill_into<T>(dst: &mut Option<T>, src: Option<T>) {
 }
 
 /// Registers a class with given the dynamic type information `info`.
-fn register_class_raw(info: ClassRegistrationInfo) {
+fn register_class_raw(mut info: ClassRegistrationInfo) {
     // First register class...
 
+    if info.is_abstract {
+        // Virtual classes are not offered in the editor's "Create New Node" dialog, but can still be constructed through subclasses
+        // (including scripts). Without any constructor, the class is abstract and cannot be instantiated at all.
+        info.godot_params.is_virtual = true as u8;
+        info.godot_params.is_abstract = info.godot_params.create_instance_func.is_none() as u8;
+    }
+
     let class_name = info.class_name;
     let parent_class_name = info
         .parent_class_name
@@ -252,8 +500,13 @@ fn register_class_raw(info: ClassRegistrationInfo) {
 
     unsafe {
         // Try to register class...
+        #[cfg(before_api = "4.2")]
+        let register_fn = interface_fn!(classdb_register_extension_class);
+        #[cfg(since_api = "4.2")]
+        let register_fn = interface_fn!(classdb_register_extension_class2);
+
         #[allow(clippy::let_unit_value)] // notifies us if Godot API ever adds a return type.
-        let _: () = interface_fn!(classdb_register_extension_class)(
+        let _: () = register_fn(
             sys::get_library(),
             class_name.string_sys(),
             parent_class_name.string_sys(),
@@ -269,6 +522,12 @@ fn register_class_raw(info: ClassRegistrationInfo) {
         );
     }
 
+    LOADED_CLASSES.lock().unwrap().push(class_name);
+    if info.is_editor_plugin {
+        EDITOR_PLUGINS.lock().unwrap().push(class_name);
+    }
+    crate::docs::add_class_docs(class_name, parent_class_name, &info.docs);
+
     // ...then custom symbols
 
     //let mut class_builder = crate::builder::ClassBuilder::<?>::new();
@@ -276,12 +535,127 @@ fn register_class_raw(info: ClassRegistrationInfo) {
 
     // First call generated (proc-macro) registration function, then user-defined one.
     // This mimics the intuition that proc-macros are running "before" normal runtime code.
-    if let Some(register_fn) = info.generated_register_fn {
+    for register_fn in info.generated_register_fns {
+        (register_fn.raw)(&mut class_builder);
+    }
+    if let Some(register_fn) = info.register_properties_fn {
         (register_fn.raw)(&mut class_builder);
     }
     if let Some(register_fn) = info.user_register_fn {
         (register_fn.raw)(&mut class_builder);
     }
+    for register_fn in info.constant_register_fns {
+        (register_fn.raw)(&mut class_builder);
+    }
+}
+
+/// Unregisters all classes from Godot, so that the library can be unloaded (and possibly reloaded) while the engine keeps running.
+pub(crate) fn unregister_classes() {
+    let classes = std::mem::take(&mut *LOADED_CLASSES.lock().unwrap());
+    crate::docs::clear_class_docs();
+
+    // Reverse order, so that derived classes are unregistered before their bases.
+    for class_name in classes.into_iter().rev() {
+        unregister_class_raw(class_name);
+    }
+}
+
+/// Unregisters a single class, as happens when the library is unloaded. Used to test hot reloading.
+///
+/// No instances of the class may exist, and no other registered class may inherit from it.
+#[doc(hidden)]
+pub fn unregister_class(class_name: ClassName) {
+    LOADED_CLASSES
+        .lock()
+        .unwrap()
+        .retain(|loaded| *loaded != class_name);
+
+    unregister_class_raw(class_name);
+}
+
+/// Registers a single class from its plugins again, after [`unregister_class()`]. Used to test hot reloading.
+///
+/// Callbacks of Rust base classes are not inherited, so this is only suitable for classes without a `rust_base`.
+#[doc(hidden)]
+pub fn register_class_from_plugins(class_name: ClassName) {
+    let mut info = default_registration_info(class_name);
+    crate::private::iterate_plugins(|elem: &ClassPlugin| {
+        if elem.class_name == class_name {
+            fill_class_info(elem.component.clone(), &mut info);
+        }
+    });
+
+    register_class_raw(info);
+}
+
+fn unregister_class_raw(class_name: ClassName) {
+    out!("Unregister class: {class_name}");
+    unsafe {
+        #[allow(clippy::let_unit_value)]
+        let _: () = interface_fn!(classdb_unregister_extension_class)(
+            sys::get_library(),
+            class_name.string_sys(),
+        );
+    }
+}
+
+/// Returns all registered classes that are declared as editor plugins, in order of registration.
+#[doc(hidden)]
+pub fn editor_plugins() -> Vec<ClassName> {
+    EDITOR_PLUGINS.lock().unwrap().clone()
+}
+
+/// Registers all classes declared with `#[class(editor_plugin)]` and adds them to the editor. Does nothing outside the editor.
+pub(crate) fn register_editor_plugins() {
+    // Editor plugins are useless outside the editor, e.g. when running a game from an editor build.
+    // SAFETY: only invoked after global library initialization.
+    let is_editor = unsafe { sys::config() }
+        .is_editor
+        .get_or_init(|| crate::engine::Engine::singleton().is_editor_hint());
+    if !*is_editor {
+        return;
+    }
+
+    out!("Register editor plugin classes...");
+    register_plugin_classes(|info| info.is_editor_plugin);
+
+    let plugins = EDITOR_PLUGINS.lock().unwrap();
+
+    #[cfg(since_api = "4.1")]
+    for class_name in plugins.iter() {
+        out!("Add editor plugin: {class_name}");
+        unsafe {
+            #[allow(clippy::let_unit_value)]
+            let _: () = interface_fn!(editor_add_plugin)(class_name.string_sys());
+        }
+    }
+
+    #[cfg(before_api = "4.1")]
+    if !plugins.is_empty() {
+        crate::log::godot_warn!(
+            "#[class(editor_plugin)] requires Godot 4.1 or later; not adding editor plugins {:?}",
+            *plugins
+        );
+    }
+}
+
+/// Removes all editor plugins previously added by [`register_editor_plugins()`] in reverse order, and unregisters their classes.
+pub(crate) fn unregister_editor_plugins() {
+    let plugins = std::mem::take(&mut *EDITOR_PLUGINS.lock().unwrap());
+
+    for class_name in plugins.into_iter().rev() {
+        #[cfg(since_api = "4.1")]
+        {
+            out!("Remove editor plugin: {class_name}");
+            unsafe {
+                #[allow(clippy::let_unit_value)]
+                let _: () = interface_fn!(editor_remove_plugin)(class_name.string_sys());
+            }
+        }
+
+        // Editor classes are gone before the Scene level is unloaded.
+        unregister_class(class_name);
+    }
 }
 
 /// Callbacks that are passed as function pointers to Godot upon class registration.
@@ -304,7 +678,6 @@ pub mod callbacks {
         T: GodotClass,
         F: FnOnce(Base<T::Base>) -> T,
     {
-        let class_name = T::class_name();
         let base_class_name = T::Base::class_name();
 
         //out!("create callback: {}", class_name.backing);
@@ -312,27 +685,61 @@ pub mod callbacks {
         let base_ptr =
             unsafe { interface_fn!(classdb_construct_object)(base_class_name.string_sys()) };
 
-        let base = unsafe { Base::from_sys(base_ptr) };
+        unsafe { attach_instance::<T>(base_ptr, make_user_instance) };
+
+        // std::mem::forget(base_class_name);
+        base_ptr
+    }
+
+    /// Creates the Rust instance for an existing Godot object and attaches it to that object.
+    unsafe fn attach_instance<T: GodotClass>(
+        base_ptr: sys::GDExtensionObjectPtr,
+        make_user_instance: impl FnOnce(Base<T::Base>) -> T,
+    ) -> sys::GDExtensionClassInstancePtr {
+        let class_name = T::class_name();
+        let token = sys::get_library() as *mut std::ffi::c_void;
+
+        // If `T` inherits from a Rust class, the object was constructed as that class and already has a Rust instance.
+        // Passing no callbacks only looks up the binding, without creating one.
+        let first_storage =
+            interface_fn!(object_get_instance_binding)(base_ptr, token, ptr::null());
+        let base_storage = if first_storage.is_null() {
+            ptr::null_mut()
+        } else {
+            crate::storage::most_derived_storage(first_storage)
+        };
+
+        let base = Base::from_sys(base_ptr);
         let user_instance = make_user_instance(base);
 
-        let instance = InstanceStorage::<T>::construct(user_instance);
+        let instance = InstanceStorage::<T>::construct(user_instance, base_storage);
         let instance_ptr = instance.into_raw();
         let instance_ptr = instance_ptr as sys::GDExtensionClassInstancePtr;
 
-        let binding_data_callbacks = crate::storage::nop_instance_callbacks();
-        unsafe {
-            interface_fn!(object_set_instance)(base_ptr, class_name.string_sys(), instance_ptr);
+        // Godot calls into the most-derived class, so the extension instance is replaced. The instance binding can only be set
+        // once; it keeps pointing to the first Rust storage, from which the others are reachable.
+        interface_fn!(object_set_instance)(base_ptr, class_name.string_sys(), instance_ptr);
+        if base_storage.is_null() {
+            let binding_data_callbacks = crate::storage::nop_instance_callbacks();
             interface_fn!(object_set_instance_binding)(
                 base_ptr,
-                sys::get_library() as *mut std::ffi::c_void,
+                token,
                 instance_ptr as *mut std::ffi::c_void,
                 &binding_data_callbacks,
             );
+        } else {
+            crate::storage::link_to_base(instance_ptr);
         }
 
-        // std::mem::forget(class_name);
-        // std::mem::forget(base_class_name);
-        base_ptr
+        instance_ptr
+    }
+
+    /// Recreates the Rust instance of an object that survived a hot reload of the library.
+    pub unsafe extern "C" fn recreate<T: Reloadable>(
+        _class_userdata: *mut std::ffi::c_void,
+        object: sys::GDExtensionObjectPtr,
+    ) -> sys::GDExtensionClassInstancePtr {
+        attach_instance::<T>(object, T::recreate)
     }
 
     pub unsafe extern "C" fn free<T: GodotClass>(
@@ -348,7 +755,7 @@ pub mod callbacks {
     }
 
     pub unsafe extern "C" fn get_virtual<T: cap::ImplementsGodotVirtual>(
-        _class_user_data: *mut std::ffi::c_void,
+        class_user_data: *mut std::ffi::c_void,
         name: sys::GDExtensionConstStringNamePtr,
     ) -> sys::GDExtensionClassCallVirtual {
         // This string is not ours, so we cannot call the destructor on it.
@@ -356,7 +763,11 @@ pub mod callbacks {
         let method_name = borrowed_string.to_string();
         std::mem::forget(borrowed_string);
 
-        T::__virtual_call(method_name.as_str())
+        // Virtual methods not overridden by this class may still be implemented by a Rust base class.
+        T::__virtual_call(method_name.as_str()).or_else(|| {
+            let base_fn = T::__base_callbacks().get()?.get_virtual_func?;
+            base_fn(class_user_data, name)
+        })
     }
 
     pub unsafe extern "C" fn to_string<T: cap::GodotToString>(
@@ -375,7 +786,50 @@ pub mod callbacks {
         string.move_string_ptr(out_string);
     }
 
-    pub unsafe extern "C" fn on_notification<T: cap::GodotNotification>(
+    #[cfg(before_api = "4.2")]
+    pub unsafe extern "C" fn on_notification<
+        T: cap::GodotNotification + cap::ImplementsGodotVirtual,
+    >(
+        instance: sys::GDExtensionClassInstancePtr,
+        what: i32,
+    ) {
+        // A Rust base class that handles notifications itself receives them first.
+        let base_fn = T::__base_callbacks()
+            .get()
+            .and_then(|c| c.notification_func);
+        if let Some(base_fn) = base_fn {
+            base_fn(instance, what);
+        }
+
+        notify::<T>(instance, what);
+    }
+
+    #[cfg(since_api = "4.2")]
+    pub unsafe extern "C" fn on_notification<
+        T: cap::GodotNotification + cap::ImplementsGodotVirtual,
+    >(
+        instance: sys::GDExtensionClassInstancePtr,
+        what: i32,
+        reversed: sys::GDExtensionBool,
+    ) {
+        // A Rust base class that handles notifications itself receives them first, unless Godot requests reversed order.
+        let base_fn = T::__base_callbacks()
+            .get()
+            .and_then(|c| c.notification_func);
+        let reversed_order = reversed != 0;
+
+        if let (Some(base_fn), false) = (base_fn, reversed_order) {
+            base_fn(instance, what, reversed);
+        }
+
+        notify::<T>(instance, what);
+
+        if let (Some(base_fn), true) = (base_fn, reversed_order) {
+            base_fn(instance, what, reversed);
+        }
+    }
+
+    unsafe fn notify<T: cap::GodotNotification>(
         instance: sys::GDExtensionClassInstancePtr,
         what: i32,
     ) {
@@ -385,6 +839,139 @@ pub mod callbacks {
         T::__godot_notification(&mut *instance, what);
     }
 
+    pub unsafe extern "C" fn set_property<T: cap::GodotSet>(
+        instance: sys::GDExtensionClassInstancePtr,
+        name: sys::GDExtensionConstStringNamePtr,
+        value: sys::GDExtensionConstVariantPtr,
+    ) -> sys::GDExtensionBool {
+        let property = borrow_string_name(name);
+        let value = (*(value as *const Variant)).clone();
+
+        let handled = crate::private::handle_panic(
+            || format!("{}::set_property() failed", T::class_name()),
+            AssertUnwindSafe(|| {
+                let storage = as_storage::<T>(instance);
+                let mut instance = storage.get_mut();
+                T::__godot_set_property(&mut *instance, property, value)
+            }),
+        );
+
+        handled.unwrap_or(false) as sys::GDExtensionBool
+    }
+
+    pub unsafe extern "C" fn get_property<T: cap::GodotGet>(
+        instance: sys::GDExtensionClassInstancePtr,
+        name: sys::GDExtensionConstStringNamePtr,
+        r_ret: sys::GDExtensionVariantPtr,
+    ) -> sys::GDExtensionBool {
+        let property = borrow_string_name(name);
+
+        let value = crate::private::handle_panic(
+            || format!("{}::get_property() failed", T::class_name()),
+            AssertUnwindSafe(|| {
+                let storage = as_storage::<T>(instance);
+                let instance = storage.get();
+                T::__godot_get_property(&*instance, property)
+            }),
+        );
+
+        write_variant(value.flatten(), r_ret)
+    }
+
+    pub unsafe extern "C" fn get_property_list<T: cap::GodotGetPropertyList>(
+        instance: sys::GDExtensionClassInstancePtr,
+        r_count: *mut u32,
+    ) -> *const sys::GDExtensionPropertyInfo {
+        let list = crate::private::handle_panic(
+            || format!("{}::get_property_list() failed", T::class_name()),
+            AssertUnwindSafe(|| {
+                let storage = as_storage::<T>(instance);
+                let instance = storage.get();
+                T::__godot_get_property_list(&*instance)
+            }),
+        );
+
+        let list: Vec<_> = list
+            .unwrap_or_default()
+            .iter()
+            .map(PropertyInfo::property_sys_owned)
+            .collect();
+
+        // Godot does not pass the length back when freeing the list, so it is stored alongside the list.
+        *r_count = list.len() as u32;
+        sys::leak_list(list)
+    }
+
+    pub unsafe extern "C" fn free_property_list(
+        _instance: sys::GDExtensionClassInstancePtr,
+        list_ptr: *const sys::GDExtensionPropertyInfo,
+    ) {
+        let list = sys::unleak_list(list_ptr);
+
+        for info in list.iter() {
+            PropertyInfo::free_owned_property_sys(info);
+        }
+    }
+
+    pub unsafe extern "C" fn property_can_revert<T: cap::GodotPropertyCanRevert>(
+        instance: sys::GDExtensionClassInstancePtr,
+        name: sys::GDExtensionConstStringNamePtr,
+    ) -> sys::GDExtensionBool {
+        let property = borrow_string_name(name);
+
+        let can_revert = crate::private::handle_panic(
+            || format!("{}::property_can_revert() failed", T::class_name()),
+            AssertUnwindSafe(|| {
+                let storage = as_storage::<T>(instance);
+                let instance = storage.get();
+                T::__godot_property_can_revert(&*instance, property)
+            }),
+        );
+
+        can_revert.unwrap_or(false) as sys::GDExtensionBool
+    }
+
+    pub unsafe extern "C" fn property_get_revert<T: cap::GodotPropertyGetRevert>(
+        instance: sys::GDExtensionClassInstancePtr,
+        name: sys::GDExtensionConstStringNamePtr,
+        r_ret: sys::GDExtensionVariantPtr,
+    ) -> sys::GDExtensionBool {
+        let property = borrow_string_name(name);
+
+        let value = crate::private::handle_panic(
+            || format!("{}::property_get_revert() failed", T::class_name()),
+            AssertUnwindSafe(|| {
+                let storage = as_storage::<T>(instance);
+                let instance = storage.get();
+                T::__godot_property_get_revert(&*instance, property)
+            }),
+        );
+
+        write_variant(value.flatten(), r_ret)
+    }
+
+    /// The name is not ours, so we cannot call the destructor on it.
+    unsafe fn borrow_string_name(name: sys::GDExtensionConstStringNamePtr) -> StringName {
+        let borrowed = StringName::from_string_sys(sys::force_mut_ptr(name));
+        let owned = borrowed.clone();
+        std::mem::forget(borrowed);
+        owned
+    }
+
+    /// Writes `value` into the already initialized variant `r_ret`, and returns `true` if present.
+    unsafe fn write_variant(
+        value: Option<Variant>,
+        r_ret: sys::GDExtensionVariantPtr,
+    ) -> sys::GDExtensionBool {
+        match value {
+            Some(value) => {
+                *(r_ret as *mut Variant) = value;
+                true as sys::GDExtensionBool
+            }
+            None => false as sys::GDExtensionBool,
+        }
+    }
+
     pub unsafe extern "C" fn reference<T: GodotClass>(instance: sys::GDExtensionClassInstancePtr) {
         let storage = as_storage::<T>(instance);
         storage.on_inc_ref();
@@ -420,9 +1007,7 @@ pub mod callbacks {
         T::__godot_register_class(&mut class_builder);
     }
 
-    pub fn register_user_binds<T: cap::ImplementsGodotApi + cap::ImplementsGodotExports>(
-        _class_builder: &mut dyn Any,
-    ) {
+    pub fn register_user_binds<T: cap::ImplementsGodotApi>(_class_builder: &mut dyn Any) {
         // let class_builder = class_builder
         //     .downcast_mut::<ClassBuilder<T>>()
         //     .expect("bad type erasure");
@@ -430,6 +1015,9 @@ pub mod callbacks {
         //T::register_methods(class_builder);
         T::__register_methods();
         T::__register_constants();
+    }
+
+    pub fn register_user_properties<T: cap::ImplementsGodotExports>(_class_builder: &mut dyn Any) {
         T::__register_exports();
     }
 }
@@ -441,13 +1029,20 @@ fn default_registration_info(class_name: ClassName) -> ClassRegistrationInfo {
     ClassRegistrationInfo {
         class_name,
         parent_class_name: None,
-        generated_register_fn: None,
+        generated_register_fns: Vec::new(),
+        register_properties_fn: None,
         user_register_fn: None,
+        constant_register_fns: Vec::new(),
         godot_params: default_creation_info(),
+        is_editor_plugin: false,
+        is_abstract: false,
+        docs: ClassDocs::default(),
+        base_callbacks_fn: None,
     }
 }
 
-fn default_creation_info() -> sys::GDExtensionClassCreationInfo {
+#[cfg(before_api = "4.2")]
+fn default_creation_info() -> GodotCreationInfo {
     sys::GDExtensionClassCreationInfo {
         is_abstract: false as u8,
         is_virtual: false as u8,
@@ -468,3 +1063,31 @@ fn default_creation_info() -> sys::GDExtensionClassCreationInfo {
         class_userdata: ptr::null_mut(),
     }
 }
+
+#[cfg(since_api = "4.2")]
+fn default_creation_info() -> GodotCreationInfo {
+    sys::GDExtensionClassCreationInfo2 {
+        is_abstract: false as u8,
+        is_virtual: false as u8,
+        is_exposed: true as u8,
+        set_func: None,
+        get_func: None,
+        get_property_list_func: None,
+        free_property_list_func: None,
+        property_can_revert_func: None,
+        property_get_revert_func: None,
+        validate_property_func: None,
+        notification_func: None,
+        to_string_func: None,
+        reference_func: None,
+        unreference_func: None,
+        create_instance_func: None,
+        free_instance_func: None,
+        recreate_instance_func: None,
+        get_virtual_func: None,
+        get_virtual_call_data_func: None,
+        call_virtual_with_data_func: None,
+        get_rid_func: None,
+        class_userdata: ptr::null_mut(),
+    }
+}
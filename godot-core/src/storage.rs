@@ -8,7 +8,9 @@ use crate::obj::GodotClass;
 use crate::out;
 use godot_ffi as sys;
 
-use std::any::type_name;
+use std::any::{type_name, TypeId};
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
 
 #[derive(Copy, Clone, Debug)]
 pub enum Lifecycle {
@@ -18,6 +20,52 @@ pub enum Lifecycle {
     Dead, // reading this would typically already be too late, only best-effort in case of UB
 }
 
+/// Connects the storages of an object whose class inherits from other Rust classes.
+///
+/// Such an object has one storage per Rust class in its hierarchy, each holding the fields declared by that class. Godot knows
+/// the storage of the most-derived class (as extension instance) and the one of the first Rust class (as instance binding);
+/// all others are reached through these links. Classes inheriting an engine class directly have a single, unlinked storage.
+///
+/// `InstanceStorage<T>` is `#[repr(C)]` and starts with this struct, so the links can be read without knowing `T`.
+pub(crate) struct StorageLinks {
+    type_id: TypeId,
+
+    /// Storage of the Rust base class, or null if the base is an engine class. Owned by this storage.
+    base: AtomicPtr<std::ffi::c_void>,
+
+    /// Storage of the Rust class inheriting from this one, or null.
+    derived: AtomicPtr<std::ffi::c_void>,
+
+    mark_destroyed_fn: unsafe fn(sys::GDExtensionClassInstancePtr),
+    destroy_fn: unsafe fn(sys::GDExtensionClassInstancePtr),
+}
+
+impl StorageLinks {
+    fn new<T: GodotClass>(base_storage: sys::GDExtensionClassInstancePtr) -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            base: AtomicPtr::new(base_storage),
+            derived: AtomicPtr::new(ptr::null_mut()),
+            mark_destroyed_fn: mark_destroyed::<T>,
+            destroy_fn: destroy_storage::<T>,
+        }
+    }
+
+    fn base(&self) -> sys::GDExtensionClassInstancePtr {
+        self.base.load(Ordering::Relaxed)
+    }
+
+    fn derived(&self) -> sys::GDExtensionClassInstancePtr {
+        self.derived.load(Ordering::Relaxed)
+    }
+}
+
+/// # Safety
+/// `instance_ptr` must point to a valid `InstanceStorage<T>` of any `T`.
+unsafe fn links<'u>(instance_ptr: sys::GDExtensionClassInstancePtr) -> &'u StorageLinks {
+    &*(instance_ptr as *const StorageLinks)
+}
+
 #[cfg(not(feature = "threads"))]
 pub(crate) use single_threaded::*;
 
@@ -31,11 +79,16 @@ mod single_threaded {
 
     use crate::obj::GodotClass;
     use crate::out;
+    use godot_ffi as sys;
 
-    use super::Lifecycle;
+    use super::{Lifecycle, StorageLinks};
 
     /// Manages storage and lifecycle of user's extension class instances.
+    #[repr(C)]
     pub struct InstanceStorage<T: GodotClass> {
+        // Must stay the first field, see `StorageLinks`.
+        pub(super) links: StorageLinks,
+
         user_instance: cell::RefCell<T>,
 
         // Declared after `user_instance`, is dropped last
@@ -45,10 +98,11 @@ mod single_threaded {
 
     /// For all Godot extension classes
     impl<T: GodotClass> InstanceStorage<T> {
-        pub fn construct(user_instance: T) -> Self {
+        pub fn construct(user_instance: T, base_storage: sys::GDExtensionClassInstancePtr) -> Self {
             out!("    Storage::construct             <{}>", type_name::<T>());
 
             Self {
+                links: StorageLinks::new::<T>(base_storage),
                 user_instance: cell::RefCell::new(user_instance),
                 lifecycle: cell::Cell::new(Lifecycle::Alive),
                 godot_ref_count: cell::Cell::new(1),
@@ -80,6 +134,7 @@ mod single_threaded {
         }
 
         pub fn get(&self) -> cell::Ref<T> {
+            ensure_main_thread::<T>("bind");
             self.user_instance.try_borrow().unwrap_or_else(|_e| {
                 panic!(
                     "Gd<T>::bind() failed, already bound; T = {}.\n  \
@@ -91,6 +146,7 @@ mod single_threaded {
         }
 
         pub fn get_mut(&self) -> cell::RefMut<T> {
+            ensure_main_thread::<T>("bind_mut");
             self.user_instance.try_borrow_mut().unwrap_or_else(|_e| {
                 panic!(
                     "Gd<T>::bind_mut() failed, already bound; T = {}.\n  \
@@ -105,6 +161,16 @@ mod single_threaded {
             self.godot_ref_count.get()
         }
     }
+
+    /// Without the `threads` feature, user instances are not synchronized and may only be accessed from the main thread.
+    fn ensure_main_thread<T: GodotClass>(method: &str) {
+        assert!(
+            sys::is_main_thread(),
+            "Gd<T>::{method}() failed, called from a thread other than the main thread; T = {}.\n  \
+             Without the `threads` feature, Rust instances can only be accessed on the main thread.",
+            type_name::<T>()
+        );
+    }
 }
 
 #[cfg(feature = "threads")]
@@ -115,8 +181,9 @@ mod multi_threaded {
 
     use crate::obj::GodotClass;
     use crate::out;
+    use godot_ffi as sys;
 
-    use super::Lifecycle;
+    use super::{Lifecycle, StorageLinks};
 
     pub struct AtomicLifecycle {
         atomic: AtomicU32,
@@ -144,7 +211,11 @@ mod multi_threaded {
     }
 
     /// Manages storage and lifecycle of user's extension class instances.
+    #[repr(C)]
     pub struct InstanceStorage<T: GodotClass> {
+        // Must stay the first field, see `StorageLinks`.
+        pub(super) links: StorageLinks,
+
         user_instance: sync::RwLock<T>,
 
         // Declared after `user_instance`, is dropped last
@@ -154,10 +225,11 @@ mod multi_threaded {
 
     /// For all Godot extension classes
     impl<T: GodotClass> InstanceStorage<T> {
-        pub fn construct(user_instance: T) -> Self {
+        pub fn construct(user_instance: T, base_storage: sys::GDExtensionClassInstancePtr) -> Self {
             out!("    Storage::construct             <{}>", type_name::<T>());
 
             Self {
+                links: StorageLinks::new::<T>(base_storage),
                 user_instance: sync::RwLock::new(user_instance),
                 lifecycle: AtomicLifecycle::new(Lifecycle::Alive),
                 godot_ref_count: AtomicU32::new(1),
@@ -209,22 +281,11 @@ mod multi_threaded {
         pub(super) fn godot_ref_count(&self) -> u32 {
             self.godot_ref_count.load(Ordering::Relaxed)
         }
-
-        // fn __static_type_check() {
-        //     enforce_sync::<InstanceStorage<T>>();
-        // }
     }
 
-    // TODO make InstanceStorage<T> Sync
-    // This type can be accessed concurrently from multiple threads, so it should be Sync. That implies however that T must be Sync too
-    // (and possibly Send, because with `&mut` access, a `T` can be extracted as a value using mem::take() etc.).
-    // Which again means that we need to infest half the codebase with T: Sync + Send bounds, *and* make it all conditional on
-    // `#[cfg(feature = "threads")]`. Until the multi-threading design is clarified, we'll thus leave it as is.
-    //
-    // The following code + __static_type_check() above would make sure that InstanceStorage is Sync.
-
-    // Make sure storage is Sync in multi-threaded case, as it can be concurrently accessed through aliased Gd<T> pointers.
-    // fn enforce_sync<T: Sync>() {}
+    // InstanceStorage<T> is Sync if T is Send + Sync, since all its fields are synchronized. Instead of requiring these bounds throughout
+    // the codebase, they are enforced at the boundary: Gd<T> is only Send/Sync if T is, which is the only way to share a storage
+    // across threads from Rust. Accesses from other threads (e.g. GDScript calling a #[func] on a worker thread) are synchronized by the RwLock.
 }
 
 impl<T: GodotClass> InstanceStorage<T> {
@@ -244,6 +305,13 @@ impl<T: GodotClass> InstanceStorage<T> {
             self as *const _,
             self.lifecycle.get()
         );
+
+        // Storages of Rust base classes belong to the same object.
+        let base_ptr = self.links.base();
+        if !base_ptr.is_null() {
+            // SAFETY: base storages live as long as the derived one.
+            unsafe { (links(base_ptr).mark_destroyed_fn)(base_ptr) };
+        }
     }
 
     #[inline(always)]
@@ -279,6 +347,9 @@ impl<T: GodotClass> Drop for InstanceStorage<T> {
 
 /// Interprets the opaque pointer as pointing to `InstanceStorage<T>`.
 ///
+/// If the object's class inherits from other Rust classes, `instance_ptr` may point to the storage of any class in the hierarchy;
+/// the one belonging to `T` is looked up.
+///
 /// Note: returns reference with unbounded lifetime; intended for local usage
 ///
 /// # Safety
@@ -287,13 +358,80 @@ impl<T: GodotClass> Drop for InstanceStorage<T> {
 pub unsafe fn as_storage<'u, T: GodotClass>(
     instance_ptr: sys::GDExtensionClassInstancePtr,
 ) -> &'u InstanceStorage<T> {
-    &*(instance_ptr as *mut InstanceStorage<T>)
+    let storage_ptr = find_storage(instance_ptr, TypeId::of::<T>())
+        .unwrap_or_else(|| panic!("object has no Rust instance of class {}", type_name::<T>()));
+
+    &*(storage_ptr as *mut InstanceStorage<T>)
+}
+
+unsafe fn find_storage(
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+    type_id: TypeId,
+) -> Option<sys::GDExtensionClassInstancePtr> {
+    // Fast path, always taken for classes without Rust base classes.
+    if links(instance_ptr).type_id == type_id {
+        return Some(instance_ptr);
+    }
+
+    let mut current = most_derived_storage(instance_ptr);
+    while !current.is_null() {
+        let current_links = links(current);
+        if current_links.type_id == type_id {
+            return Some(current);
+        }
+        current = current_links.base();
+    }
+
+    None
+}
+
+/// Returns the storage of the most-derived Rust class of the object, starting from any storage of that object.
+///
+/// # Safety
+/// `instance_ptr` is assumed to point to a valid instance.
+pub(crate) unsafe fn most_derived_storage(
+    instance_ptr: sys::GDExtensionClassInstancePtr,
+) -> sys::GDExtensionClassInstancePtr {
+    let mut current = instance_ptr;
+    loop {
+        let derived = links(current).derived();
+        if derived.is_null() {
+            return current;
+        }
+        current = derived;
+    }
+}
+
+/// Registers a newly created storage with the storage of its Rust base class (if any), so that it can be found from there.
+///
+/// # Safety
+/// `instance_ptr` is assumed to point to a valid instance.
+pub(crate) unsafe fn link_to_base(instance_ptr: sys::GDExtensionClassInstancePtr) {
+    let base_ptr = links(instance_ptr).base();
+    if !base_ptr.is_null() {
+        links(base_ptr)
+            .derived
+            .store(instance_ptr, Ordering::Relaxed);
+    }
+}
+
+unsafe fn mark_destroyed<T: GodotClass>(instance_ptr: sys::GDExtensionClassInstancePtr) {
+    let storage = &*(instance_ptr as *mut InstanceStorage<T>);
+    storage.mark_destroyed_by_godot();
 }
 
+/// Destroys the storage, together with the storages of all Rust base classes.
+///
 /// # Safety
-/// `instance_ptr` is assumed to point to a valid instance. This function must only be invoked once for a pointer.
+/// `instance_ptr` is assumed to point to a valid `InstanceStorage<T>`. This function must only be invoked once for a pointer.
 pub unsafe fn destroy_storage<T: GodotClass>(instance_ptr: sys::GDExtensionClassInstancePtr) {
-    let _drop = Box::from_raw(instance_ptr as *mut InstanceStorage<T>);
+    let base_ptr = links(instance_ptr).base();
+    drop(Box::from_raw(instance_ptr as *mut InstanceStorage<T>));
+
+    // Like C++ destructors, destroy the derived part first.
+    if !base_ptr.is_null() {
+        (links(base_ptr).destroy_fn)(base_ptr);
+    }
 }
 
 // ----------------------------------------------------------------------------------------------------------------------------------------------
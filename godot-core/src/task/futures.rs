@@ -0,0 +1,204 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use crate::builtin::{varray, Callable, Signal, Variant};
+use crate::engine::global;
+use crate::engine::object::ConnectFlags;
+use crate::obj::EngineEnum;
+
+use super::runtime::{frame_count, wake_next_frame};
+
+/// Future which resolves the next time a signal is emitted, yielding the emitted arguments.
+///
+/// Usually obtained by awaiting a [`Signal`] directly. Dropping the future before the emission disconnects it from the signal.
+///
+/// # Panics
+/// When polled after the signal's object has been freed without emitting the signal.
+pub struct SignalFuture {
+    state: Arc<Mutex<SignalState>>,
+    signal: Signal,
+    /// Callable connected to the signal, used to disconnect exactly this connection.
+    ///
+    /// It is connected with a [`ConnectionGuard`] bound as extra argument, which is owned by the connection alone: when the object is
+    /// freed, Godot drops the connection together with the guard, which wakes up the future.
+    callable: Callable,
+}
+
+impl SignalFuture {
+    /// Connects to `signal`, resolving on its next emission.
+    ///
+    /// # Panics
+    /// If the signal cannot be connected, e.g. because it doesn't exist.
+    pub fn new(signal: Signal) -> Self {
+        let state = Arc::new(Mutex::new(SignalState::Pending(None)));
+        let resolver = SignalResolver {
+            state: state.clone(),
+        };
+        let guard = ConnectionGuard {
+            resolver: SignalResolver {
+                state: state.clone(),
+            },
+        };
+
+        let callable = Callable::from_fn(
+            format!("SignalFuture({})", signal.name()),
+            move |args: &[Variant]| {
+                // The last argument is the bound guard.
+                let (_guard, args) = args.split_last().expect("guard argument is bound");
+                resolver.resolve(args.to_vec());
+                Variant::nil()
+            },
+        );
+        let guard = Callable::from_fn("SignalFuture guard", move |_args: &[Variant]| {
+            let _ = &guard;
+            Variant::nil()
+        });
+
+        // One-shot: Godot disconnects after the first emission, dropping the bound guard.
+        // Connections are identified by the unbound callable, so `callable` can be used to disconnect.
+        let flags = ConnectFlags::CONNECT_ONE_SHOT.ord() as i64;
+        let error = signal.connect(callable.as_inner().bindv(varray![guard]), flags);
+        assert_eq!(
+            error,
+            global::Error::OK,
+            "failed to connect SignalFuture to signal `{}`",
+            signal.name()
+        );
+
+        Self {
+            state,
+            signal,
+            callable,
+        }
+    }
+}
+
+impl Future for SignalFuture {
+    type Output = Vec<Variant>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = self.state.lock().unwrap();
+
+        match std::mem::replace(&mut *state, SignalState::Consumed) {
+            SignalState::Pending(_) => {
+                *state = SignalState::Pending(Some(cx.waker().clone()));
+                Poll::Pending
+            }
+            SignalState::Ready(args) => Poll::Ready(args),
+            SignalState::Dead => panic!(
+                "object of signal `{}` was freed before the signal was emitted",
+                self.signal.name()
+            ),
+            SignalState::Consumed => panic!("SignalFuture polled after completion"),
+        }
+    }
+}
+
+impl Drop for SignalFuture {
+    fn drop(&mut self) {
+        // From now on, the resolver ignores emissions.
+        let previous_state =
+            std::mem::replace(&mut *self.state.lock().unwrap(), SignalState::Consumed);
+
+        // While pending, the guard is alive, so the connection still exists.
+        if matches!(previous_state, SignalState::Pending(_)) && self.signal.object().is_some() {
+            self.signal.disconnect(self.callable.clone());
+        }
+    }
+}
+
+impl IntoFuture for Signal {
+    type Output = Vec<Variant>;
+    type IntoFuture = SignalFuture;
+
+    fn into_future(self) -> Self::IntoFuture {
+        SignalFuture::new(self)
+    }
+}
+
+/// Returns a future which resolves at the beginning of the next frame.
+///
+/// # Panics
+/// If not called from the main thread, or if the main loop is not a [`SceneTree`][crate::engine::SceneTree].
+pub fn next_frame() -> impl Future<Output = ()> {
+    NextFrame {
+        target_frame: frame_count() + 1,
+    }
+}
+
+/// Future returned by [`next_frame()`].
+struct NextFrame {
+    target_frame: u64,
+}
+
+impl Future for NextFrame {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if frame_count() >= self.target_frame {
+            Poll::Ready(())
+        } else {
+            wake_next_frame(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Shared state
+
+enum SignalState {
+    Pending(Option<Waker>),
+    Ready(Vec<Variant>),
+    Dead,
+    Consumed,
+}
+
+/// Moves the future to its final state and wakes it up.
+struct SignalResolver {
+    state: Arc<Mutex<SignalState>>,
+}
+
+impl SignalResolver {
+    fn resolve(&self, args: Vec<Variant>) {
+        self.transition(SignalState::Ready(args));
+    }
+
+    fn transition(&self, new_state: SignalState) {
+        let waker = {
+            let mut state = self.state.lock().unwrap();
+            let SignalState::Pending(waker) = &mut *state else {
+                return;
+            };
+
+            let waker = waker.take();
+            *state = new_state;
+            waker
+        };
+
+        // Wake outside of lock, as the task may be polled immediately.
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// Bound to the connection; Godot drops it when the connection is removed.
+struct ConnectionGuard {
+    resolver: SignalResolver,
+}
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        // Connection removed without emission, e.g. because the object died.
+        self.resolver.transition(SignalState::Dead);
+    }
+}
@@ -0,0 +1,43 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Integration of Rust `async`/`await` with Godot's main loop.
+//!
+//! Futures are run as tasks with [`spawn()`]. A task is polled on the main thread: first immediately, then after every wake-up, at the
+//! start of the next frame (through the [`SceneTree`][crate::engine::SceneTree]'s `process_frame` signal). Tasks are never resumed in
+//! the middle of a signal emission, so the emitter may still hold binds to objects that the task accesses.
+//!
+//! Signals can be awaited directly, yielding the emitted arguments:
+//! ```no_run
+//! # use godot::prelude::*;
+//! # use godot::engine::Timer;
+//! # use godot::task;
+//! fn start_cutscene(timer: Gd<Timer>) {
+//!     task::spawn(async move {
+//!         godot_print!("Cutscene started");
+//!
+//!         timer.signal("timeout").await;
+//!         task::next_frame().await;
+//!
+//!         godot_print!("Cutscene finished");
+//!     });
+//! }
+//! ```
+//!
+//! Requires Godot 4.2 or later, since the runtime relies on custom callables. With older versions, this module is empty.
+
+#[cfg(since_api = "4.2")]
+mod futures;
+#[cfg(since_api = "4.2")]
+mod runtime;
+
+#[cfg(since_api = "4.2")]
+pub use futures::*;
+#[cfg(since_api = "4.2")]
+pub use runtime::*;
+
+#[cfg(since_api = "4.2")]
+pub(crate) use runtime::cleanup;
@@ -0,0 +1,300 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::marker::PhantomData;
+use std::panic::AssertUnwindSafe;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+
+use godot_ffi as sys;
+
+use crate::builtin::{Callable, Variant};
+use crate::engine::{Engine, SceneTree};
+use crate::obj::Gd;
+
+type BoxedTask = Pin<Box<dyn Future<Output = ()>>>;
+
+/// Spawns `future` as a task, which runs on the main thread and is driven by Godot's main loop.
+///
+/// The future is polled immediately until it first suspends (i.e. until the first `.await` that is not ready). After being woken up,
+/// it is resumed at the start of the next frame. Panics inside the task are caught; they print an error and terminate the task.
+///
+/// Returns a handle, which can be used to query or cancel the task. Dropping the handle does _not_ cancel the task.
+///
+/// # Panics
+/// If not called from the main thread.
+pub fn spawn(future: impl Future<Output = ()> + 'static) -> TaskHandle {
+    assert!(
+        sys::is_main_thread(),
+        "godot::task::spawn() can only be called from the main thread"
+    );
+
+    let id = RUNTIME.with(|rt| {
+        let mut rt = rt.borrow_mut();
+        rt.ensure_frame_hook();
+        rt.add_task(Box::pin(future))
+    });
+
+    // Run synchronously up to the first suspension point, unless we are inside another task; then wait for the current poll to finish.
+    if IS_POLLING.with(Cell::get) {
+        wake_task(id);
+    } else {
+        poll_tasks(vec![id]);
+    }
+
+    TaskHandle {
+        id,
+        _not_send: PhantomData,
+    }
+}
+
+/// Handle to a task spawned with [`spawn()`].
+#[derive(Debug)]
+pub struct TaskHandle {
+    id: u64,
+    _not_send: PhantomData<*const ()>,
+}
+
+impl TaskHandle {
+    /// Returns true if the task has neither completed nor been cancelled.
+    pub fn is_pending(&self) -> bool {
+        RUNTIME.with(|rt| rt.borrow().tasks.contains_key(&self.id))
+    }
+
+    /// Cancels the task, dropping its future. Has no effect if the task is no longer pending.
+    ///
+    /// If the task cancels itself while running, it is dropped as soon as its current poll returns.
+    pub fn cancel(self) {
+        let task = RUNTIME.with(|rt| rt.borrow_mut().tasks.remove(&self.id));
+
+        // Drop outside of borrow, the future's destructor may access the runtime.
+        drop(task);
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Runtime
+
+thread_local! {
+    static RUNTIME: RefCell<AsyncRuntime> = RefCell::new(AsyncRuntime::default());
+    static IS_POLLING: Cell<bool> = Cell::new(false);
+}
+
+/// Tasks woken up, but not yet polled. Accessible from all threads.
+static READY_TASKS: Mutex<Vec<u64>> = Mutex::new(Vec::new());
+
+#[derive(Default)]
+struct AsyncRuntime {
+    /// Spawned tasks. Values are `None` while the task is being polled.
+    tasks: HashMap<u64, Option<BoxedTask>>,
+    next_id: u64,
+
+    /// Scene tree and callable connected to its `process_frame` signal.
+    frame_hook: Option<(Gd<SceneTree>, Callable)>,
+    /// Number of frames processed by the frame hook.
+    frame_count: u64,
+    /// Wakers to be woken at the start of the next frame.
+    frame_waiters: Vec<Waker>,
+}
+
+impl AsyncRuntime {
+    fn add_task(&mut self, task: BoxedTask) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.tasks.insert(id, Some(task));
+        id
+    }
+
+    /// Connects the runtime to the scene tree, so that woken tasks are polled every frame. Returns false if there is no scene tree.
+    fn ensure_frame_hook(&mut self) -> bool {
+        if self.frame_hook.is_some() {
+            return true;
+        }
+
+        // Without a scene tree (e.g. during early init levels, or with a custom main loop), woken tasks are not resumed.
+        let Some(mut tree) = main_scene_tree() else {
+            return false;
+        };
+
+        let callable = Callable::from_fn("godot::task::poll", |_args: &[Variant]| {
+            on_frame();
+            Variant::nil()
+        });
+
+        tree.connect("process_frame".into(), callable.clone());
+        self.frame_hook = Some((tree, callable));
+        true
+    }
+
+    /// Disconnects the runtime from the scene tree, so that Godot no longer calls into this library.
+    fn remove_frame_hook(&mut self) {
+        let Some((mut tree, callable)) = self.frame_hook.take() else {
+            return;
+        };
+
+        // The tree may already be destroyed when the engine shuts down.
+        if tree.is_instance_valid() {
+            tree.disconnect("process_frame".into(), callable);
+        }
+    }
+}
+
+/// Returns the `SceneTree` acting as the main loop, if any.
+fn main_scene_tree() -> Option<Gd<SceneTree>> {
+    Engine::singleton()
+        .get_main_loop()
+        .and_then(|main_loop| main_loop.try_cast::<SceneTree>())
+}
+
+/// Disconnects from the scene tree and drops all remaining tasks. Called when the library is unloaded.
+pub(crate) fn cleanup() {
+    let runtime = RUNTIME.with(|rt| {
+        let mut rt = rt.borrow_mut();
+        rt.remove_frame_hook();
+        std::mem::take(&mut *rt)
+    });
+
+    // Drop outside of borrow, the futures' destructors may access the runtime.
+    drop(runtime);
+
+    READY_TASKS.lock().unwrap().clear();
+}
+
+/// Returns the number of frames processed so far, after connecting the runtime to the scene tree.
+///
+/// # Panics
+/// If not called from the main thread, or if the main loop is not a [`SceneTree`].
+pub(super) fn frame_count() -> u64 {
+    assert!(
+        sys::is_main_thread(),
+        "frames can only be awaited on the main thread"
+    );
+
+    RUNTIME.with(|rt| {
+        let mut rt = rt.borrow_mut();
+        assert!(
+            rt.ensure_frame_hook(),
+            "awaiting frames requires the main loop to be a SceneTree"
+        );
+        rt.frame_count
+    })
+}
+
+/// Wakes `waker` at the start of the next frame.
+pub(super) fn wake_next_frame(waker: Waker) {
+    RUNTIME.with(|rt| rt.borrow_mut().frame_waiters.push(waker));
+}
+
+fn wake_task(id: u64) {
+    READY_TASKS.lock().unwrap().push(id);
+}
+
+/// Called at the start of every frame.
+fn on_frame() {
+    let waiters = RUNTIME.with(|rt| {
+        let mut rt = rt.borrow_mut();
+        rt.frame_count += 1;
+        std::mem::take(&mut rt.frame_waiters)
+    });
+
+    // Wake outside of borrow; wakers of other executors may run arbitrary code.
+    for waker in waiters {
+        waker.wake();
+    }
+
+    poll_ready_tasks();
+}
+
+fn poll_ready_tasks() {
+    if IS_POLLING.with(Cell::get) {
+        return;
+    }
+
+    let ready = std::mem::take(&mut *READY_TASKS.lock().unwrap());
+    poll_tasks(ready);
+}
+
+/// Polls the given tasks, plus all tasks woken up in the meantime.
+///
+/// A task woken up during its own poll is deferred to the next frame, to avoid busy loops within a single frame.
+fn poll_tasks(mut ready: Vec<u64>) {
+    IS_POLLING.with(|polling| polling.set(true));
+
+    let mut polled = HashSet::new();
+    let mut deferred = Vec::new();
+
+    while !ready.is_empty() {
+        for id in ready {
+            if polled.insert(id) {
+                poll_task(id);
+            } else {
+                deferred.push(id);
+            }
+        }
+
+        ready = std::mem::take(&mut *READY_TASKS.lock().unwrap());
+    }
+
+    READY_TASKS.lock().unwrap().extend(deferred);
+    IS_POLLING.with(|polling| polling.set(false));
+}
+
+fn poll_task(id: u64) {
+    // Take the future out of the runtime, so that the task itself can spawn or cancel tasks.
+    let task = RUNTIME.with(|rt| rt.borrow_mut().tasks.get_mut(&id).and_then(Option::take));
+
+    // Completed, cancelled, or stale wake-up.
+    let Some(mut task) = task else {
+        return;
+    };
+
+    let waker = Waker::from(Arc::new(TaskWaker { id }));
+    let mut context = Context::from_waker(&waker);
+
+    let result = crate::private::handle_panic(
+        || format!("async task {id}"),
+        AssertUnwindSafe(|| task.as_mut().poll(&mut context)),
+    );
+
+    let finished_task = RUNTIME.with(|rt| {
+        let mut rt = rt.borrow_mut();
+        match (result, rt.tasks.get_mut(&id)) {
+            // Still pending and not cancelled in the meantime.
+            (Some(Poll::Pending), Some(slot)) => {
+                *slot = Some(task);
+                None
+            }
+            // Completed, panicked or cancelled.
+            _ => {
+                rt.tasks.remove(&id);
+                Some(task)
+            }
+        }
+    });
+
+    // Drop outside of borrow, the future's destructor may access the runtime.
+    drop(finished_task);
+}
+
+struct TaskWaker {
+    id: u64,
+}
+
+impl Wake for TaskWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        // Never poll right away: the wake-up may happen inside a signal emission, whose emitter could still hold a bind_mut() guard on
+        // an object that the task accesses. The task is resumed from the frame hook instead.
+        wake_task(self.id);
+    }
+}
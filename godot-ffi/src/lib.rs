@@ -105,6 +105,11 @@ pub struct GdextConfig {
 // &mut references are handed out (except for registry, see below). Overall, UnsafeCell/RefCell + Sync might be a safer abstraction.
 static mut BINDING: Option<GodotBinding> = None;
 
+thread_local! {
+    /// Set on the thread which initialized the library, i.e. Godot's main thread.
+    static IS_MAIN_THREAD: cell::Cell<bool> = cell::Cell::new(false);
+}
+
 /// # Safety
 ///
 /// - The `interface` pointer must be either:
@@ -165,6 +170,9 @@ pub unsafe fn initialize(
     });
     out!("Assigned binding.");
 
+    // Godot loads GDExtension libraries on the main thread.
+    IS_MAIN_THREAD.with(|is_main| is_main.set(true));
+
     println!(
         "Initialize GDExtension API for Rust: {}",
         CStr::from_ptr(version.string)
@@ -173,6 +181,18 @@ pub unsafe fn initialize(
     );
 }
 
+/// Resets the binding, so that a subsequent [`initialize`] starts from scratch (e.g. after hot-reloading the library).
+///
+/// # Safety
+///
+/// - Must be called from the same thread as `initialize()` previously.
+/// - No Godot APIs may be used after this call, until the library is initialized again.
+pub unsafe fn deinitialize() {
+    BINDING = None;
+    IS_MAIN_THREAD.with(|is_main| is_main.set(false));
+    out!("Cleared binding.");
+}
+
 /// # Safety
 ///
 /// Must be called from the same thread as `initialize()` previously.
@@ -180,6 +200,14 @@ pub unsafe fn is_initialized() -> bool {
     BINDING.is_some()
 }
 
+/// Whether the calling thread is Godot's main thread.
+///
+/// Always false while the library is not initialized. Cheap enough to be called on every instance access.
+#[inline]
+pub fn is_main_thread() -> bool {
+    IS_MAIN_THREAD.with(|is_main| is_main.get())
+}
+
 /// # Safety
 ///
 /// The interface must have been initialised with [`initialize`] before calling this function.
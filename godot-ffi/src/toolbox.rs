@@ -89,6 +89,60 @@ pub fn unbox<T>(value: Box<T>) -> T {
     *value
 }
 
+/// Hands out ownership of a list as a raw pointer to its first element, storing the length in front of it.
+///
+/// Godot passes such lists back without their length (e.g. when freeing property lists); [`unleak_list()`] recovers it.
+/// The pointer is never null, even for empty lists.
+pub fn leak_list<T>(mut list: Vec<T>) -> *const T {
+    let len = list.len();
+    let (layout, offset) = leaked_list_layout::<T>(len);
+
+    // SAFETY: `layout` has non-zero size due to the length header. The elements are moved into the new allocation;
+    // setting the length of `list` to 0 afterwards prevents double drops, while still freeing its buffer.
+    unsafe {
+        let base = std::alloc::alloc(layout);
+        if base.is_null() {
+            std::alloc::handle_alloc_error(layout);
+        }
+
+        base.cast::<usize>().write(len);
+        let elements = base.add(offset).cast::<T>();
+        std::ptr::copy_nonoverlapping(list.as_ptr(), elements, len);
+        list.set_len(0);
+
+        elements
+    }
+}
+
+/// Takes back ownership of a list that was handed out by [`leak_list()`].
+///
+/// # Safety
+/// `ptr` must have been returned by `leak_list::<T>()`, and not been passed to this function before.
+pub unsafe fn unleak_list<T>(ptr: *const T) -> Vec<T> {
+    // The offset of the elements does not depend on the length.
+    let (_, offset) = leaked_list_layout::<T>(0);
+    let base = (ptr as *mut u8).sub(offset);
+    let len = base.cast::<usize>().read();
+
+    let mut list = Vec::with_capacity(len);
+    std::ptr::copy_nonoverlapping(ptr, list.as_mut_ptr(), len);
+    list.set_len(len);
+
+    let (layout, _) = leaked_list_layout::<T>(len);
+    std::alloc::dealloc(base, layout);
+
+    list
+}
+
+/// Layout of a `usize` length header followed by `len` elements of type `T`, and the offset of the first element.
+fn leaked_list_layout<T>(len: usize) -> (std::alloc::Layout, usize) {
+    let elements = std::alloc::Layout::array::<T>(len).expect("list too large");
+
+    std::alloc::Layout::new::<usize>()
+        .extend(elements)
+        .expect("list too large")
+}
+
 /// Explicitly cast away `const` from a pointer, similar to C++ `const_cast`.
 ///
 /// The `as` conversion simultaneously doing 10 other things, potentially causing unintended transmutations.
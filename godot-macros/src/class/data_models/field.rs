@@ -4,7 +4,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
-use crate::class::{FieldExport, FieldVar};
+use crate::class::{extract_docs, FieldExport, FieldGroup, FieldVar};
 use proc_macro2::{Ident, TokenStream};
 
 pub struct Field {
@@ -13,6 +13,12 @@ pub struct Field {
     pub default: Option<TokenStream>,
     pub var: Option<FieldVar>,
     pub export: Option<FieldExport>,
+
+    /// Inspector sections starting at this field, ordered from outermost (category) to innermost (subgroup).
+    pub groups: Vec<FieldGroup>,
+
+    /// Lines of the field's `///` comments, exported to the editor help for properties.
+    pub docs: Vec<String>,
 }
 
 impl Field {
@@ -23,6 +29,8 @@ impl Field {
             default: None,
             var: None,
             export: None,
+            groups: vec![],
+            docs: extract_docs(&field.attributes),
         }
     }
 }
@@ -0,0 +1,120 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use crate::util::KvParser;
+use crate::ParseResult;
+
+/// Kind of inspector section started by a field attribute.
+#[derive(Copy, Clone, Debug)]
+pub enum FieldGroupKind {
+    /// `#[export_category]`, corresponds to `@export_category` in GDScript.
+    Category,
+
+    /// `#[export_group]`, corresponds to `@export_group` in GDScript.
+    Group,
+
+    /// `#[export_subgroup]`, corresponds to `@export_subgroup` in GDScript.
+    Subgroup,
+}
+
+impl FieldGroupKind {
+    /// Name of the attribute, without `#[]`.
+    pub fn attribute_name(self) -> &'static str {
+        match self {
+            Self::Category => "export_category",
+            Self::Group => "export_group",
+            Self::Subgroup => "export_subgroup",
+        }
+    }
+}
+
+/// Store info from `#[export_category]`, `#[export_group]` or `#[export_subgroup]` attributes.
+///
+/// The section starts at the annotated field and contains all following properties, until the next section of the same
+/// or a higher level begins.
+#[derive(Clone, Debug)]
+pub struct FieldGroup {
+    pub kind: FieldGroupKind,
+    pub name: TokenStream,
+    pub prefix: Option<TokenStream>,
+}
+
+impl FieldGroup {
+    /// Parse one of the group attributes to a `FieldGroup` struct.
+    ///
+    /// Possible keys:
+    /// - `name = expr` (required)
+    /// - `prefix = expr` (groups and subgroups only)
+    pub(crate) fn new_from_kv(kind: FieldGroupKind, parser: &mut KvParser) -> ParseResult<Self> {
+        let name = parser.handle_expr_required("name")?;
+
+        let prefix = match kind {
+            FieldGroupKind::Category => None,
+            FieldGroupKind::Group | FieldGroupKind::Subgroup => parser.handle_expr("prefix")?,
+        };
+
+        Ok(Self { kind, name, prefix })
+    }
+
+    /// Tokens registering this section with Godot, to be run right before the first property inside of it.
+    pub fn make_registration(&self, class_name: &Ident) -> TokenStream {
+        let name = &self.name;
+        let prefix = match &self.prefix {
+            Some(prefix) => quote! { ::godot::builtin::GodotString::from(#prefix) },
+            None => quote! { ::godot::builtin::GodotString::new() },
+        };
+
+        let register_fn = match self.kind {
+            FieldGroupKind::Category => {
+                // GDExtension has no dedicated function for categories; like GDScript, register a pseudo-property instead.
+                return quote! {
+                    let property_info = ::godot::builtin::meta::PropertyInfo {
+                        variant_type: ::godot::builtin::VariantType::Nil,
+                        class_name: ::godot::builtin::meta::ClassName::none(),
+                        property_name: ::godot::builtin::StringName::from(#name),
+                        hint: ::godot::engine::global::PropertyHint::PROPERTY_HINT_NONE,
+                        hint_string: ::godot::builtin::GodotString::new(),
+                        usage: ::godot::engine::global::PropertyUsageFlags::PROPERTY_USAGE_CATEGORY,
+                    };
+
+                    let empty_name = ::godot::builtin::StringName::default();
+                    let property_info_sys = property_info.property_sys();
+
+                    unsafe {
+                        ::godot::sys::interface_fn!(classdb_register_extension_class_property)(
+                            ::godot::sys::get_library(),
+                            #class_name::class_name().string_sys(),
+                            std::ptr::addr_of!(property_info_sys),
+                            empty_name.string_sys(),
+                            empty_name.string_sys(),
+                        );
+                    }
+                };
+            }
+            FieldGroupKind::Group => quote! { classdb_register_extension_class_property_group },
+            FieldGroupKind::Subgroup => {
+                quote! { classdb_register_extension_class_property_subgroup }
+            }
+        };
+
+        quote! {
+            let group_name = ::godot::builtin::GodotString::from(#name);
+            let prefix = #prefix;
+
+            unsafe {
+                ::godot::sys::interface_fn!(#register_fn)(
+                    ::godot::sys::get_library(),
+                    #class_name::class_name().string_sys(),
+                    group_name.string_sys(),
+                    prefix.string_sys(),
+                );
+            }
+        }
+    }
+}
@@ -195,6 +195,10 @@ impl GetterSetterImpl {
             FuncDefinition {
                 func: signature,
                 rename: None,
+                default_args: Vec::new(),
+                is_vararg: false,
+                is_virtual: false,
+                docs: Vec::new(),
             },
         );
 
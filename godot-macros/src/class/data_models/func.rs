@@ -14,6 +14,14 @@ pub struct FuncDefinition {
     pub func: venial::Function,
     /// The name the function will be exposed as in Godot. If `None`, the Rust function name is used.
     pub rename: Option<String>,
+    /// Default values of the trailing parameters, from `#[opt(default = ...)]`.
+    pub default_args: Vec<TokenStream>,
+    /// Whether the function is variadic (`#[func(vararg)]`), receiving all arguments as one `&[Variant]` slice.
+    pub is_vararg: bool,
+    /// Whether the function can be overridden in scripts (`#[func(virtual)]`).
+    pub is_virtual: bool,
+    /// Lines of the function's `///` comments, exported to the editor help.
+    pub docs: Vec<String>,
 }
 
 /// Returns a C function which acts as the callback when a virtual method of this instance is invoked.
@@ -27,7 +35,7 @@ pub fn make_virtual_method_callback(
     let signature_info = get_signature_info(method_signature);
     let method_name = &method_signature.name;
 
-    let wrapped_method = make_forwarding_closure(class_name, &signature_info);
+    let wrapped_method = make_forwarding_closure(class_name, &signature_info, false);
     let sig_tuple =
         util::make_signature_tuple_type(&signature_info.ret_type, &signature_info.param_types);
 
@@ -55,18 +63,57 @@ pub fn make_method_registration(
     func_definition: FuncDefinition,
 ) -> TokenStream {
     let signature_info = get_signature_info(&func_definition.func);
-    let sig_tuple =
-        util::make_signature_tuple_type(&signature_info.ret_type, &signature_info.param_types);
+    let is_vararg = func_definition.is_vararg;
 
     let method_name = &signature_info.method_name;
-    let param_idents = &signature_info.param_idents;
+    // Virtual functions have a Rust implementation, which scripts can call with `super()`. Godot's METHOD_FLAG_VIRTUAL would
+    // mark them as unimplemented, so they are registered like regular methods.
+    let method_flags = make_method_flags(signature_info.receiver_type, is_vararg);
+    let forwarding_closure = make_forwarding_closure(class_name, &signature_info, is_vararg);
+
+    // Defaults belong to the trailing parameters; each is evaluated with the parameter type as expected type.
+    let default_param_types = &signature_info.param_types
+        [signature_info.param_idents.len() - func_definition.default_args.len()..];
+    let default_exprs = &func_definition.default_args;
+    let default_count = default_exprs.len();
+    let default_args = quote! {
+        ::std::vec![
+            #(
+                {
+                    let default: #default_param_types = #default_exprs;
+                    ::godot::builtin::ToVariant::to_variant(&default)
+                },
+            )*
+        ]
+    };
 
-    let method_flags = make_method_flags(signature_info.receiver_type);
+    // Variadic functions are registered without parameters and can only be invoked through varcall.
+    let (sig_tuple, param_idents, varcall_invocation, ptrcall_func) = if is_vararg {
+        let sig_tuple = util::make_signature_tuple_type(&signature_info.ret_type, &Vec::new());
+        let invocation = make_vararg_invocation(method_name, &forwarding_closure);
 
-    let forwarding_closure = make_forwarding_closure(class_name, &signature_info);
+        (sig_tuple, &[][..], invocation, quote! { None })
+    } else {
+        let sig_tuple =
+            util::make_signature_tuple_type(&signature_info.ret_type, &signature_info.param_types);
+        let invocation = make_varcall_invocation(
+            method_name,
+            &sig_tuple,
+            &forwarding_closure,
+            default_count,
+            &default_args,
+        );
+        let ptrcall_func = make_ptrcall_func(method_name, &sig_tuple, &forwarding_closure);
+
+        (
+            sig_tuple,
+            &signature_info.param_idents[..],
+            invocation,
+            quote! { Some(#ptrcall_func) },
+        )
+    };
 
-    let varcall_func = make_varcall_func(method_name, &sig_tuple, &forwarding_closure);
-    let ptrcall_func = make_ptrcall_func(method_name, &sig_tuple, &forwarding_closure);
+    let varcall_func = make_varcall_func(method_name, &varcall_invocation);
 
     // String literals
     let class_name_str = class_name.to_string();
@@ -99,12 +146,12 @@ pub fn make_method_registration(
                 #class_name::class_name(),
                 method_name,
                 Some(varcall_func),
-                Some(ptrcall_func),
+                ptrcall_func,
                 #method_flags,
                 &[
                     #( #param_ident_strs ),*
                 ],
-                Vec::new()
+                #default_args
                 )
             };
 
@@ -139,10 +186,26 @@ struct SignatureInfo {
 }
 
 /// Returns a closure expression that forwards the parameters to the Rust instance.
-fn make_forwarding_closure(class_name: &Ident, signature_info: &SignatureInfo) -> TokenStream {
+///
+/// For variadic functions, the closure receives the argument slice instead of a tuple.
+fn make_forwarding_closure(
+    class_name: &Ident,
+    signature_info: &SignatureInfo,
+    is_vararg: bool,
+) -> TokenStream {
     let method_name = &signature_info.method_name;
     let params = &signature_info.param_idents;
 
+    let params_decl = if is_vararg {
+        quote! {
+            let #(#params)* = params;
+        }
+    } else {
+        quote! {
+            let ( #(#params,)* ) = params;
+        }
+    };
+
     let instance_decl = match &signature_info.receiver_type {
         ReceiverType::Ref => quote! {
             let instance = storage.get();
@@ -157,7 +220,7 @@ fn make_forwarding_closure(class_name: &Ident, signature_info: &SignatureInfo) -
         ReceiverType::Ref | ReceiverType::Mut => {
             quote! {
                 |instance_ptr, params| {
-                    let ( #(#params,)* ) = params;
+                    #params_decl
 
                     let storage =
                         unsafe { ::godot::private::as_storage::<#class_name>(instance_ptr) };
@@ -170,7 +233,7 @@ fn make_forwarding_closure(class_name: &Ident, signature_info: &SignatureInfo) -
         ReceiverType::Static => {
             quote! {
                 |_, params| {
-                    let ( #(#params,)* ) = params;
+                    #params_decl
                     <#class_name>::#method_name(#(#params),*)
                 }
             }
@@ -226,24 +289,25 @@ fn get_signature_info(signature: &venial::Function) -> SignatureInfo {
     }
 }
 
-fn make_method_flags(method_type: ReceiverType) -> TokenStream {
-    match method_type {
+fn make_method_flags(method_type: ReceiverType, is_vararg: bool) -> TokenStream {
+    let flags = match method_type {
         ReceiverType::Ref | ReceiverType::Mut => {
             quote! { ::godot::engine::global::MethodFlags::METHOD_FLAGS_DEFAULT }
         }
         ReceiverType::Static => {
             quote! { ::godot::engine::global::MethodFlags::METHOD_FLAG_STATIC }
         }
+    };
+
+    if is_vararg {
+        quote! { #flags | ::godot::engine::global::MethodFlags::METHOD_FLAG_VARARG }
+    } else {
+        flags
     }
 }
 
 /// Generate code for a C FFI function that performs a varcall.
-fn make_varcall_func(
-    method_name: &Ident,
-    sig_tuple: &TokenStream,
-    wrapped_method: &TokenStream,
-) -> TokenStream {
-    let invocation = make_varcall_invocation(method_name, sig_tuple, wrapped_method);
+fn make_varcall_func(method_name: &Ident, invocation: &TokenStream) -> TokenStream {
     let method_name_str = method_name.to_string();
 
     quote! {
@@ -252,7 +316,7 @@ fn make_varcall_func(
                 _method_data: *mut std::ffi::c_void,
                 instance_ptr: sys::GDExtensionClassInstancePtr,
                 args_ptr: *const sys::GDExtensionConstVariantPtr,
-                _arg_count: sys::GDExtensionInt,
+                arg_count: sys::GDExtensionInt,
                 ret: sys::GDExtensionVariantPtr,
                 err: *mut sys::GDExtensionCallError,
             ) {
@@ -334,10 +398,14 @@ fn make_ptrcall_invocation(
 }
 
 /// Generate code for a `varcall()` call expression.
+///
+/// `default_args` evaluates to a `Vec<Variant>`. It is only evaluated when the caller omits arguments.
 fn make_varcall_invocation(
     method_name: &Ident,
     sig_tuple: &TokenStream,
     wrapped_method: &TokenStream,
+    default_count: usize,
+    default_args: &TokenStream,
 ) -> TokenStream {
     let method_name_str = method_name.to_string();
 
@@ -345,6 +413,26 @@ fn make_varcall_invocation(
         <#sig_tuple as ::godot::builtin::meta::VarcallSignatureTuple>::in_varcall(
             instance_ptr,
             args_ptr,
+            arg_count,
+            ret,
+            err,
+            #wrapped_method,
+            #method_name_str,
+            #default_count,
+            || #default_args,
+        )
+    }
+}
+
+/// Generate code for a varcall expression of a variadic function.
+fn make_vararg_invocation(method_name: &Ident, wrapped_method: &TokenStream) -> TokenStream {
+    let method_name_str = method_name.to_string();
+
+    quote! {
+        ::godot::builtin::meta::in_varcall_vararg(
+            instance_ptr,
+            args_ptr,
+            arg_count,
             ret,
             err,
             #wrapped_method,
@@ -6,7 +6,6 @@
 //! Parsing the `var` and `export` attributes on fields.
 
 use crate::class::{Field, FieldVar, Fields, GetSet, GetterSetterImpl, UsageFlags};
-use crate::util;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
@@ -39,8 +38,6 @@ impl FieldHint {
 }
 
 pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
-    let class_name_obj = util::class_name_obj(class_name);
-
     let mut getter_setter_impls = Vec::new();
     let mut export_tokens = Vec::new();
 
@@ -171,6 +168,10 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
             String::new()
         };
 
+        for group in &field.groups {
+            export_tokens.push(group.make_registration(class_name));
+        }
+
         export_tokens.push(quote! {
             use ::godot::builtin::meta::VariantMetadata;
 
@@ -179,7 +180,7 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
 
             let property_info = ::godot::builtin::meta::PropertyInfo {
                 variant_type: <<#field_type as ::godot::bind::property::Property>::Intermediate as ::godot::builtin::meta::VariantMetadata>::variant_type(),
-                class_name: #class_name_obj,
+                class_name: <<#field_type as ::godot::bind::property::Property>::Intermediate as ::godot::builtin::meta::VariantMetadata>::class_name(),
                 property_name: #field_name.into(),
                 hint,
                 hint_string,
@@ -203,18 +204,8 @@ pub fn make_property_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
         });
     }
 
-    let enforce_godot_api_impl = if !export_tokens.is_empty() {
-        quote! {
-            const MUST_HAVE_GODOT_API_IMPL: () = <#class_name as ::godot::private::Cannot_export_without_godot_api_impl>::EXISTS;
-        }
-    } else {
-        TokenStream::new()
-    };
-
     quote! {
         impl #class_name {
-            #enforce_godot_api_impl
-
             #(#getter_setter_impls)*
         }
 
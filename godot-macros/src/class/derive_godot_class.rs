@@ -8,7 +8,10 @@ use proc_macro2::{Ident, Punct, TokenStream};
 use quote::{format_ident, quote};
 use venial::{Declaration, NamedField, Struct, StructFields};
 
-use crate::class::{make_property_impl, Field, FieldExport, FieldVar, Fields};
+use crate::class::{
+    extract_docs, make_property_impl, make_struct_docs, Field, FieldExport, FieldGroup,
+    FieldGroupKind, FieldVar, Fields,
+};
 use crate::util::{bail, ident, KvParser};
 use crate::{util, ParseResult};
 
@@ -25,13 +28,23 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
     let class_name_cstr = util::cstr_u8_slice(&class_name_str);
     let class_name_obj = util::class_name_obj(class_name);
 
-    let base_ty = &struct_cfg.base_ty;
-    let base_class = quote! { ::godot::engine::#base_ty };
+    let prv = quote! { ::godot::private };
+
+    let (base_class, inherits_impl);
+    if let Some(rust_base) = &struct_cfg.rust_base {
+        base_class = quote! { #rust_base };
+        inherits_impl = make_rust_base_inherits_impl(class_name, rust_base);
+    } else {
+        let base_ty = &struct_cfg.base_ty;
+        let inherits_macro = format_ident!("inherits_transitive_{}", base_ty);
+        base_class = quote! { ::godot::engine::#base_ty };
+        inherits_impl = quote! { #prv::class_macros::#inherits_macro!(#class_name); };
+    }
     let base_class_name_obj = util::class_name_obj(&base_class);
-    let inherits_macro = format_ident!("inherits_transitive_{}", base_ty);
 
-    let prv = quote! { ::godot::private };
+    let struct_docs = make_struct_docs(&extract_docs(&class.attributes), &fields.all_fields);
     let godot_exports_impl = make_property_impl(class_name, &fields);
+    let with_base_field_impl = make_with_base_field_impl(class_name, &fields);
 
     let (godot_init_impl, create_fn);
     if struct_cfg.has_generated_init {
@@ -42,6 +55,20 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
         create_fn = quote! { None };
     };
 
+    let recreate_fn = if struct_cfg.is_reloadable {
+        quote! { Some(#prv::callbacks::recreate::<#class_name>) }
+    } else {
+        quote! { None }
+    };
+
+    let is_editor_plugin = struct_cfg.is_editor_plugin;
+    let editor_plugin_check = if is_editor_plugin {
+        make_editor_plugin_check(class_name)
+    } else {
+        TokenStream::new()
+    };
+
+    let is_abstract = struct_cfg.is_abstract;
     let config_impl = make_config_impl(class_name, struct_cfg.is_tool);
 
     Ok(quote! {
@@ -57,7 +84,9 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
 
         #godot_init_impl
         #godot_exports_impl
+        #with_base_field_impl
         #config_impl
+        #editor_plugin_check
 
         ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
             class_name: #class_name_obj,
@@ -65,10 +94,17 @@ pub fn derive_godot_class(decl: Declaration) -> ParseResult<TokenStream> {
                 base_class_name: #base_class_name_obj,
                 generated_create_fn: #create_fn,
                 free_fn: #prv::callbacks::free::<#class_name>,
+                recreate_fn: #recreate_fn,
+                is_editor_plugin: #is_editor_plugin,
+                is_abstract: #is_abstract,
+                docs: #struct_docs,
+                register_properties_fn: #prv::ErasedRegisterFn {
+                    raw: #prv::callbacks::register_user_properties::<#class_name>,
+                },
             },
         });
 
-        #prv::class_macros::#inherits_macro!(#class_name);
+        #inherits_impl
     })
 }
 
@@ -87,15 +123,31 @@ pub fn make_existence_check(ident: &Ident) -> TokenStream {
 /// Returns the name of the base and the default mode
 fn parse_struct_attributes(class: &Struct) -> ParseResult<ClassAttributes> {
     let mut base_ty = ident("RefCounted");
+    let mut rust_base = None;
     let mut has_generated_init = false;
     let mut is_tool = false;
+    let mut is_reloadable = false;
+    let mut is_editor_plugin = false;
+    let mut is_abstract = false;
 
     // #[class] attribute on struct
     if let Some(mut parser) = KvParser::parse(&class.attributes, "class")? {
-        if let Some(base) = parser.handle_ident("base")? {
+        let engine_base = parser.handle_ident("base")?;
+        if let Some(base) = engine_base.clone() {
             base_ty = base;
         }
 
+        // The engine base is inherited from the Rust base, so it cannot be specified as well.
+        if let Some(base) = parser.handle_ident("rust_base")? {
+            if engine_base.is_some() {
+                return bail!(
+                    parser.span(),
+                    "#[class(rust_base)] cannot be combined with #[class(base)]"
+                );
+            }
+            rust_base = Some(base);
+        }
+
         if parser.handle_alone("init")? {
             has_generated_init = true;
         }
@@ -104,13 +156,45 @@ fn parse_struct_attributes(class: &Struct) -> ParseResult<ClassAttributes> {
             is_tool = true;
         }
 
+        // Recreating an instance after hot reload does not recreate the instances of its Rust base classes.
+        if parser.handle_alone("reloadable")? {
+            if rust_base.is_some() {
+                return bail!(
+                    parser.span(),
+                    "#[class(reloadable)] is not supported for classes with #[class(rust_base)]"
+                );
+            }
+            is_reloadable = true;
+        }
+
+        // Editor plugins run in the editor, so they are implicitly tool classes.
+        if parser.handle_alone("editor_plugin")? {
+            is_editor_plugin = true;
+            is_tool = true;
+        }
+
+        // Abstract classes are hidden in the editor, but can still serve as a base for other classes.
+        if parser.handle_alone("abstract")? {
+            if is_editor_plugin {
+                return bail!(
+                    parser.span(),
+                    "#[class(abstract)] cannot be combined with #[class(editor_plugin)]"
+                );
+            }
+            is_abstract = true;
+        }
+
         parser.finish()?;
     }
 
     Ok(ClassAttributes {
         base_ty,
+        rust_base,
         has_generated_init,
         is_tool,
+        is_reloadable,
+        is_editor_plugin,
+        is_abstract,
     })
 }
 
@@ -169,6 +253,29 @@ fn parse_fields(class: &Struct) -> ParseResult<Fields> {
             parser.finish()?;
         }
 
+        // #[export_category], #[export_group], #[export_subgroup]
+        for kind in [
+            FieldGroupKind::Category,
+            FieldGroupKind::Group,
+            FieldGroupKind::Subgroup,
+        ] {
+            if let Some(mut parser) =
+                KvParser::parse(&named_field.attributes, kind.attribute_name())?
+            {
+                if field.var.is_none() && field.export.is_none() {
+                    bail!(
+                        parser.span(),
+                        "#[{}] requires the field to be a property, with #[var] or #[export]",
+                        kind.attribute_name()
+                    )?;
+                }
+
+                let group = FieldGroup::new_from_kv(kind, &mut parser)?;
+                field.groups.push(group);
+                parser.finish()?;
+            }
+        }
+
         // Exported or Rust-only fields
         if is_base {
             base_field = Some(field);
@@ -188,8 +295,12 @@ fn parse_fields(class: &Struct) -> ParseResult<Fields> {
 
 struct ClassAttributes {
     base_ty: Ident,
+    rust_base: Option<Ident>,
     has_generated_init: bool,
     is_tool: bool,
+    is_reloadable: bool,
+    is_editor_plugin: bool,
+    is_abstract: bool,
 }
 
 fn make_godot_init_impl(class_name: &Ident, fields: Fields) -> TokenStream {
@@ -220,6 +331,50 @@ fn make_godot_init_impl(class_name: &Ident, fields: Fields) -> TokenStream {
     }
 }
 
+fn make_with_base_field_impl(class_name: &Ident, fields: &Fields) -> TokenStream {
+    let Some(Field { name, .. }) = &fields.base_field else {
+        return TokenStream::new();
+    };
+
+    quote! {
+        impl ::godot::obj::cap::WithBaseField for #class_name {
+            fn __godot_base(&self) -> &::godot::obj::Gd<Self::Base> {
+                &self.#name
+            }
+
+            fn __godot_base_mut(&mut self) -> &mut ::godot::obj::Gd<Self::Base> {
+                &mut self.#name
+            }
+        }
+    }
+}
+
+/// Checks at compile time that an `#[class(editor_plugin)]` class inherits `EditorPlugin`.
+fn make_editor_plugin_check(class_name: &Ident) -> TokenStream {
+    quote! {
+        const _: () = {
+            fn __check_editor_plugin<T: ::godot::obj::Inherits<::godot::engine::EditorPlugin>>() {}
+
+            #[allow(dead_code)]
+            fn __check() {
+                __check_editor_plugin::<#class_name>();
+            }
+        };
+    }
+}
+
+/// Inherits everything the Rust base class inherits, including the base itself (`Inherits` is reflexive).
+fn make_rust_base_inherits_impl(class_name: &Ident, rust_base: &Ident) -> TokenStream {
+    quote! {
+        impl<T> ::godot::obj::Inherits<T> for #class_name
+        where
+            T: ::godot::obj::GodotClass,
+            #rust_base: ::godot::obj::Inherits<T>,
+        {
+        }
+    }
+}
+
 fn make_config_impl(class_name: &Ident, is_tool: bool) -> TokenStream {
     quote! {
         impl #class_name {
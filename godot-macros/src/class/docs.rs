@@ -0,0 +1,533 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//! Turns `///` comments into fragments of Godot's XML class reference, which is shown in the editor's built-in help.
+//!
+//! Fragments are assembled into the full XML document at runtime, since a class can be spread across several macro invocations.
+
+use proc_macro2::{Literal, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use venial::{Attribute, AttributeValue, Constant, FnParam, Function, TyExpr};
+
+use crate::class::{Field, FuncDefinition};
+
+/// Returns the lines of all `///` comments (`#[doc = "..."]` attributes), in order.
+///
+/// Docs which are not string literals, such as `#[doc = include_str!(...)]`, are skipped.
+pub fn extract_docs(attributes: &[Attribute]) -> Vec<String> {
+    attributes
+        .iter()
+        .filter(|attr| {
+            attr.get_single_path_segment()
+                .map_or(false, |name| name == "doc")
+        })
+        .filter_map(|attr| match &attr.value {
+            AttributeValue::Equals(_, tokens) => match tokens.as_slice() {
+                [TokenTree::Literal(literal)] => parse_string_literal(literal),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect()
+}
+
+/// Returns `StructDocs` for the class itself and its properties.
+///
+/// Without the `docs` feature, the docs are left empty, so they are not embedded into the library.
+pub fn make_struct_docs(class_docs: &[String], fields: &[Field]) -> TokenStream {
+    if !cfg!(feature = "docs") {
+        return quote! {
+            ::godot::private::StructDocs {
+                brief_description: "",
+                description: "",
+                members: "",
+                has_docs: false,
+            }
+        };
+    }
+
+    let (brief_description, description) = split_description(class_docs);
+
+    let properties = fields
+        .iter()
+        .filter(|field| field.var.is_some() || field.export.is_some());
+
+    let members: String = properties.clone().map(make_member_docs).collect();
+
+    let has_docs = !class_docs.is_empty() || properties.any(|field| !field.docs.is_empty());
+
+    quote! {
+        ::godot::private::StructDocs {
+            brief_description: #brief_description,
+            description: #description,
+            members: #members,
+            has_docs: #has_docs,
+        }
+    }
+}
+
+/// Documentation of a `#[signal]`, gathered from the impl block.
+pub struct SignalDocs {
+    pub signature: Function,
+    pub docs: Vec<String>,
+}
+
+/// Returns `InherentImplDocs` for the methods, signals and constants of a `#[godot_api]` impl block.
+///
+/// Without the `docs` feature, the docs are left empty, so they are not embedded into the library.
+pub fn make_inherent_impl_docs(
+    funcs: &[FuncDefinition],
+    signals: &[SignalDocs],
+    constants: &[Constant],
+) -> TokenStream {
+    if !cfg!(feature = "docs") {
+        return quote! {
+            ::godot::private::InherentImplDocs {
+                methods: "",
+                signals: "",
+                constants: "",
+                has_docs: false,
+            }
+        };
+    }
+
+    let has_docs = funcs.iter().any(|func| !func.docs.is_empty())
+        || signals.iter().any(|signal| !signal.docs.is_empty())
+        || constants
+            .iter()
+            .any(|constant| !extract_docs(&constant.attributes).is_empty());
+
+    let methods: String = funcs.iter().map(make_method_docs).collect();
+
+    let signals: String = signals
+        .iter()
+        .map(|signal| {
+            format!(
+                r#"<signal name="{name}">{params}<description>{docs}</description></signal>"#,
+                name = signal.signature.name,
+                params = make_param_docs(&signal.signature, &[]),
+                docs = to_godot_text(&signal.docs),
+            )
+        })
+        .collect();
+
+    let constants: String = constants
+        .iter()
+        .map(|constant| {
+            let value = constant
+                .initializer
+                .as_ref()
+                .map(|value| value.to_token_stream().to_string())
+                .unwrap_or_default();
+
+            format!(
+                r#"<constant name="{name}" value="{value}">{docs}</constant>"#,
+                name = constant.name,
+                value = escape_xml(&value),
+                docs = to_godot_text(&extract_docs(&constant.attributes)),
+            )
+        })
+        .collect();
+
+    quote! {
+        ::godot::private::InherentImplDocs {
+            methods: #methods,
+            signals: #signals,
+            constants: #constants,
+            has_docs: #has_docs,
+        }
+    }
+}
+
+fn make_member_docs(field: &Field) -> String {
+    format!(
+        r#"<member name="{name}" type="{ty}">{docs}</member>"#,
+        name = field.name,
+        ty = escape_xml(&godot_type_name(&field.ty)),
+        docs = to_godot_text(&field.docs),
+    )
+}
+
+fn make_method_docs(func: &FuncDefinition) -> String {
+    let signature = &func.func;
+    let name = match &func.rename {
+        Some(rename) => rename.clone(),
+        None => signature.name.to_string(),
+    };
+
+    let has_receiver = signature
+        .params
+        .inner
+        .iter()
+        .any(|(param, _)| matches!(param, FnParam::Receiver(_)));
+
+    let qualifiers = if func.is_vararg {
+        r#" qualifiers="vararg""#
+    } else if func.is_virtual {
+        r#" qualifiers="virtual""#
+    } else if !has_receiver {
+        r#" qualifiers="static""#
+    } else {
+        ""
+    };
+
+    let return_ty = match &signature.return_ty {
+        Some(ty) => godot_type_name(ty),
+        None => "void".to_string(),
+    };
+
+    // Godot lists no parameters for variadic methods; the Rust `&[Variant]` slice is an implementation detail.
+    let params = if func.is_vararg {
+        String::new()
+    } else {
+        make_param_docs(signature, &func.default_args)
+    };
+
+    format!(
+        r#"<method name="{name}"{qualifiers}><return type="{return_ty}" />{params}<description>{docs}</description></method>"#,
+        return_ty = escape_xml(&return_ty),
+        docs = to_godot_text(&func.docs),
+    )
+}
+
+/// Parameters of a function or signal; `default_args` belong to the trailing parameters.
+fn make_param_docs(signature: &Function, default_args: &[TokenStream]) -> String {
+    let params: Vec<_> = signature
+        .params
+        .inner
+        .iter()
+        .filter_map(|(param, _)| match param {
+            FnParam::Typed(param) => Some(param),
+            FnParam::Receiver(_) => None,
+        })
+        .collect();
+
+    let first_default = params.len().saturating_sub(default_args.len());
+
+    params
+        .iter()
+        .enumerate()
+        .map(|(index, param)| {
+            let default = match index.checked_sub(first_default) {
+                Some(default_index) => format!(
+                    r#" default="{}""#,
+                    escape_xml(&default_args[default_index].to_string())
+                ),
+                None => String::new(),
+            };
+
+            format!(
+                r#"<param index="{index}" name="{name}" type="{ty}"{default} />"#,
+                name = param.name,
+                ty = escape_xml(&godot_type_name(&param.ty)),
+            )
+        })
+        .collect()
+}
+
+/// Splits docs into Godot's brief description (first paragraph) and description (remaining paragraphs).
+fn split_description(docs: &[String]) -> (String, String) {
+    match docs.iter().position(|line| line.trim().is_empty()) {
+        Some(end) => (to_godot_text(&docs[..end]), to_godot_text(&docs[end + 1..])),
+        None => (to_godot_text(docs), String::new()),
+    }
+}
+
+/// Converts Markdown doc lines to the BBCode used by Godot's class reference, escaped for XML.
+///
+/// Lines of a paragraph are joined, paragraphs are separated by line breaks. Code blocks and inline code are converted; other
+/// Markdown is kept as-is.
+fn to_godot_text(docs: &[String]) -> String {
+    let mut paragraphs: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut in_code_block = false;
+
+    for line in docs {
+        // `///` comments start with a space after the slashes.
+        let line = line.strip_prefix(' ').unwrap_or(line);
+
+        if line.trim_start().starts_with("```") {
+            if in_code_block {
+                current.push_str("[/codeblock]");
+                paragraphs.push(std::mem::take(&mut current));
+            } else {
+                if !current.is_empty() {
+                    paragraphs.push(std::mem::take(&mut current));
+                }
+                current.push_str("[codeblock]");
+            }
+            in_code_block = !in_code_block;
+        } else if in_code_block {
+            current.push('\n');
+            current.push_str(line);
+        } else if line.trim().is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(std::mem::take(&mut current));
+            }
+        } else {
+            if !current.is_empty() {
+                current.push(' ');
+            }
+            current.push_str(&convert_inline_code(line.trim()));
+        }
+    }
+
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+
+    escape_xml(&paragraphs.join("\n"))
+}
+
+/// Replaces `` `code` `` spans with `[code]code[/code]`.
+fn convert_inline_code(line: &str) -> String {
+    let mut result = String::new();
+    let mut parts = line.split('`');
+
+    if let Some(first) = parts.next() {
+        result.push_str(first);
+    }
+
+    let mut is_code = true;
+    let rest: Vec<&str> = parts.collect();
+    let span_count = rest.len();
+    for (index, part) in rest.into_iter().enumerate() {
+        // An unmatched backtick at the end is kept.
+        if is_code && index + 1 == span_count {
+            result.push('`');
+            result.push_str(part);
+        } else if is_code {
+            result.push_str("[code]");
+            result.push_str(part);
+            result.push_str("[/code]");
+        } else {
+            result.push_str(part);
+        }
+        is_code = !is_code;
+    }
+
+    result
+}
+
+/// Best-effort mapping of a Rust type to the name of the corresponding Godot type, falling back to the Rust type.
+fn godot_type_name(ty: &TyExpr) -> String {
+    let rust_name: String = ty
+        .to_token_stream()
+        .to_string()
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect();
+
+    map_type_name(&rust_name)
+}
+
+fn map_type_name(rust_name: &str) -> String {
+    if let Some(inner) =
+        strip_generic(rust_name, "Gd").or_else(|| strip_generic(rust_name, "Option"))
+    {
+        return map_type_name(inner);
+    }
+    if strip_generic(rust_name, "Array").is_some() {
+        return "Array".to_string();
+    }
+    if strip_generic(rust_name, "Dictionary").is_some() {
+        return "Dictionary".to_string();
+    }
+
+    let godot_name = match rust_name {
+        "()" => "void",
+        "bool" => "bool",
+        "i8" | "i16" | "i32" | "i64" | "u8" | "u16" | "u32" | "isize" | "usize" => "int",
+        "f32" | "f64" | "real" => "float",
+        "GodotString" | "String" | "&str" => "String",
+        "VariantArray" => "Array",
+        other => other,
+    };
+
+    godot_name.to_string()
+}
+
+/// If `name` is `Outer<Inner>` (possibly with a path prefix), returns `Inner`.
+fn strip_generic<'a>(name: &'a str, outer: &str) -> Option<&'a str> {
+    let start = name.find('<')?;
+    let path = &name[..start];
+    let is_outer = path == outer || path.ends_with(&format!("::{outer}"));
+
+    if is_outer && name.ends_with('>') {
+        Some(&name[start + 1..name.len() - 1])
+    } else {
+        None
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Returns the value of a string literal, like `"text"` or `r"text"`.
+fn parse_string_literal(literal: &Literal) -> Option<String> {
+    let repr = literal.to_string();
+
+    if let Some(raw) = repr.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let content = &raw[hashes..raw.len() - hashes];
+        return Some(content.strip_prefix('"')?.strip_suffix('"')?.to_string());
+    }
+
+    let content = repr.strip_prefix('"')?.strip_suffix('"')?;
+    let mut result = String::with_capacity(content.len());
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next()? {
+            'n' => result.push('\n'),
+            't' => result.push('\t'),
+            'r' => result.push('\r'),
+            '0' => result.push('\0'),
+            '\\' => result.push('\\'),
+            '"' => result.push('"'),
+            '\'' => result.push('\''),
+            'u' => {
+                // \u{XXXX}
+                let code: String = chars.by_ref().skip(1).take_while(|&c| c != '}').collect();
+                result.push(char::from_u32(u32::from_str_radix(&code, 16).ok()?)?);
+            }
+            // Line continuation: skip the line break and leading whitespace of the next line.
+            '\n' => while chars.next_if(|c| c.is_whitespace()).is_some() {},
+            _ => return None,
+        }
+    }
+
+    Some(result)
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_conversion() {
+        let docs = [
+            " Heals the player by `amount`.",
+            " Never exceeds <max>.",
+            "",
+            " ```",
+            " player.heal(5);",
+            " ```",
+        ]
+        .map(String::from);
+
+        assert_eq!(
+            to_godot_text(&docs),
+            "Heals the player by [code]amount[/code]. Never exceeds &lt;max&gt;.\n[codeblock]\nplayer.heal(5);[/codeblock]"
+        );
+    }
+
+    #[test]
+    fn brief_description() {
+        let docs = [" Brief.", "", " Longer", " description."].map(String::from);
+        let (brief, description) = split_description(&docs);
+
+        assert_eq!(brief, "Brief.");
+        assert_eq!(description, "Longer description.");
+    }
+
+    #[test]
+    fn type_names() {
+        assert_eq!(map_type_name("i64"), "int");
+        assert_eq!(map_type_name("Option<Gd<Node2D>>"), "Node2D");
+        assert_eq!(map_type_name("godot::obj::Gd<Resource>"), "Resource");
+        assert_eq!(map_type_name("Array<i64>"), "Array");
+        assert_eq!(map_type_name("Vector2"), "Vector2");
+    }
+
+    #[test]
+    fn member_docs() {
+        let class = venial::parse_declaration(quote! {
+            struct Player {
+                /// Weapon of <this> player & "friends".
+                weapon: Option<Gd<Resource>>,
+            }
+        })
+        .unwrap();
+
+        let venial::StructFields::Named(fields) = &class.as_struct().unwrap().fields else {
+            panic!("named fields");
+        };
+        let field = Field::new(&fields.fields.inner[0].0);
+
+        assert_eq!(
+            make_member_docs(&field),
+            r#"<member name="weapon" type="Resource">Weapon of &lt;this&gt; player &amp; &quot;friends&quot;.</member>"#
+        );
+    }
+
+    #[test]
+    fn method_docs() {
+        let mut func = func_definition(quote! {
+            /// Spawns `count` enemies.
+            fn spawn(count: i64, name: GodotString) -> Gd<Node2D> {}
+        });
+        func.default_args = vec![quote! { "<unnamed>" }];
+
+        assert_eq!(
+            make_method_docs(&func),
+            concat!(
+                r#"<method name="spawn" qualifiers="static"><return type="Node2D" />"#,
+                r#"<param index="0" name="count" type="int" />"#,
+                r#"<param index="1" name="name" type="String" default="&quot;&lt;unnamed&gt;&quot;" />"#,
+                r#"<description>Spawns [code]count[/code] enemies.</description></method>"#,
+            )
+        );
+
+        let mut func = func_definition(quote! {
+            fn log(&self, args: &[Variant]) {}
+        });
+        func.rename = Some("log_all".to_string());
+        func.is_vararg = true;
+
+        assert_eq!(
+            make_method_docs(&func),
+            r#"<method name="log_all" qualifiers="vararg"><return type="void" /><description></description></method>"#
+        );
+    }
+
+    fn func_definition(tokens: TokenStream) -> FuncDefinition {
+        let func = venial::parse_declaration(tokens)
+            .unwrap()
+            .as_function()
+            .unwrap()
+            .clone();
+
+        FuncDefinition {
+            docs: extract_docs(&func.attributes),
+            func,
+            rename: None,
+            default_args: vec![],
+            is_vararg: false,
+            is_virtual: false,
+        }
+    }
+
+    #[test]
+    fn string_literals() {
+        let literal = Literal::string(" says \"hi\"\\");
+        assert_eq!(
+            parse_string_literal(&literal).as_deref(),
+            Some(" says \"hi\"\\")
+        );
+    }
+}
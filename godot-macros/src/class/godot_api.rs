@@ -5,14 +5,17 @@
  */
 
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
 use quote::spanned::Spanned;
+use quote::{format_ident, quote};
 use venial::{
     Attribute, AttributeValue, Constant, Declaration, Error, FnParam, Function, Impl, ImplMember,
     TyExpr,
 };
 
-use crate::class::{make_method_registration, make_virtual_method_callback, FuncDefinition};
+use crate::class::{
+    extract_docs, make_inherent_impl_docs, make_method_registration, make_virtual_method_callback,
+    FuncDefinition, SignalDocs,
+};
 use crate::util;
 use crate::util::{bail, KvParser};
 
@@ -47,11 +50,25 @@ pub fn attribute_godot_api(input_decl: Declaration) -> Result<TokenStream, Error
 
 /// Attribute for user-declared function
 enum BoundAttrType {
-    Func { rename: Option<String> },
+    Func {
+        rename: Option<String>,
+        is_vararg: bool,
+        is_virtual: bool,
+    },
     Signal(AttributeValue),
     Const(AttributeValue),
 }
 
+/// Information about a `#[signal]` declaration, gathered from the impl block.
+struct SignalDefinition {
+    /// Signature without body, attributes or visibility.
+    signature: Function,
+    /// Visibility of the declaration, applied to the generated accessor.
+    vis_marker: Option<venial::VisMarker>,
+    /// Doc attributes of the declaration.
+    attributes: Vec<Attribute>,
+}
+
 struct BoundAttr {
     attr_name: Ident,
     index: usize,
@@ -68,13 +85,27 @@ impl BoundAttr {
 fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
     let class_name = util::validate_impl(&decl, None, "godot_api")?;
     let class_name_obj = util::class_name_obj(&class_name);
-    let (funcs, signals) = process_godot_fns(&mut decl)?;
+    let (funcs, signals, virtual_dispatchers) = process_godot_fns(&mut decl)?;
 
     let mut signal_name_strs: Vec<String> = Vec::new();
     let mut signal_parameters_count: Vec<usize> = Vec::new();
     let mut signal_parameters: Vec<TokenStream> = Vec::new();
+    let mut signal_accessors: Vec<TokenStream> = Vec::new();
 
-    for signature in signals {
+    let signal_docs: Vec<SignalDocs> = signals
+        .iter()
+        .map(|signal| SignalDocs {
+            signature: signal.signature.clone(),
+            docs: extract_docs(&signal.attributes),
+        })
+        .collect();
+
+    for SignalDefinition {
+        signature,
+        vis_marker,
+        attributes,
+    } in signals
+    {
         let mut param_types: Vec<TyExpr> = Vec::new();
         let mut param_names: Vec<String> = Vec::new();
 
@@ -100,6 +131,12 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
             ]
         };
 
+        signal_accessors.push(make_signal_accessor(
+            &signature.name,
+            &param_types,
+            vis_marker,
+            attributes,
+        ));
         signal_name_strs.push(signature.name.to_string());
         signal_parameters_count.push(param_names.len());
         signal_parameters.push(param_array_decl);
@@ -107,11 +144,13 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
 
     let prv = quote! { ::godot::private };
 
+    let consts = process_godot_constants(&mut decl)?;
+    let impl_docs = make_inherent_impl_docs(&funcs, &signal_docs, &consts);
+
     let methods_registration = funcs
         .into_iter()
         .map(|func_def| make_method_registration(&class_name, func_def));
 
-    let consts = process_godot_constants(&mut decl)?;
     let mut integer_constant_names = Vec::new();
     let mut integer_constant_values = Vec::new();
 
@@ -151,47 +190,49 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
     let result = quote! {
         #decl
 
-        impl ::godot::obj::cap::ImplementsGodotApi for #class_name {
-            fn __register_methods() {
-                #(
-                    #methods_registration
-                )*
-
-                unsafe {
-                    use ::godot::sys;
-
-                    #(
-                        let parameters_info: [::godot::builtin::meta::PropertyInfo; #signal_parameters_count] = #signal_parameters;
-
-                        let mut parameters_info_sys: [::godot::sys::GDExtensionPropertyInfo; #signal_parameters_count] =
-                            std::array::from_fn(|i| parameters_info[i].property_sys());
-
-                        let signal_name = ::godot::builtin::StringName::from(#signal_name_strs);
-
-                        sys::interface_fn!(classdb_register_extension_class_signal)(
-                            sys::get_library(),
-                            #class_name_obj.string_sys(),
-                            signal_name.string_sys(),
-                            parameters_info_sys.as_ptr(),
-                            sys::GDExtensionInt::from(#signal_parameters_count as i64),
-                        );
-                    )*
-                }
-            }
-
-            fn __register_constants() {
-                #register_constants
-            }
+        impl #class_name {
+            #( #signal_accessors )*
+            #( #virtual_dispatchers )*
         }
 
-        impl ::godot::private::Cannot_export_without_godot_api_impl for #class_name {}
-
         ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
             class_name: #class_name_obj,
             component: #prv::PluginComponent::UserMethodBinds {
                 generated_register_fn: #prv::ErasedRegisterFn {
-                    raw: #prv::callbacks::register_user_binds::<#class_name>,
+                    raw: {
+                        // Free function instead of a trait impl, so that a class can have several `#[godot_api]` blocks.
+                        fn __register_binds(_class_builder: &mut dyn ::std::any::Any) {
+                            #(
+                                #methods_registration
+                            )*
+
+                            unsafe {
+                                use ::godot::sys;
+
+                                #(
+                                    let parameters_info: [::godot::builtin::meta::PropertyInfo; #signal_parameters_count] = #signal_parameters;
+
+                                    let mut parameters_info_sys: [::godot::sys::GDExtensionPropertyInfo; #signal_parameters_count] =
+                                        std::array::from_fn(|i| parameters_info[i].property_sys());
+
+                                    let signal_name = ::godot::builtin::StringName::from(#signal_name_strs);
+
+                                    sys::interface_fn!(classdb_register_extension_class_signal)(
+                                        sys::get_library(),
+                                        #class_name_obj.string_sys(),
+                                        signal_name.string_sys(),
+                                        parameters_info_sys.as_ptr(),
+                                        sys::GDExtensionInt::from(#signal_parameters_count as i64),
+                                    );
+                                )*
+                            }
+
+                            #register_constants
+                        }
+                        __register_binds
+                    },
                 },
+                docs: #impl_docs,
             },
         });
     };
@@ -199,9 +240,38 @@ fn transform_inherent_impl(mut decl: Impl) -> Result<TokenStream, Error> {
     Ok(result)
 }
 
-fn process_godot_fns(decl: &mut Impl) -> Result<(Vec<FuncDefinition>, Vec<Function>), Error> {
+/// Returns an associated function `signal_<name>(&Gd<O>)`, which hands out a typed handle to the signal.
+///
+/// `O` is bounded by the declaring class, so handles can only be obtained for objects that actually have the signal.
+fn make_signal_accessor(
+    signal_name: &Ident,
+    param_types: &[TyExpr],
+    vis_marker: Option<venial::VisMarker>,
+    attributes: Vec<Attribute>,
+) -> TokenStream {
+    let accessor_name = format_ident!("signal_{}", signal_name);
+    let signal_name_str = signal_name.to_string();
+
+    quote! {
+        #( #attributes )*
+        #[allow(dead_code)]
+        #vis_marker fn #accessor_name<O>(
+            object: &::godot::obj::Gd<O>
+        ) -> ::godot::obj::TypedSignal<( #( #param_types, )* )>
+        where
+            O: ::godot::obj::Inherits<Self>,
+        {
+            ::godot::obj::TypedSignal::__new(&object.clone().upcast::<Self>(), #signal_name_str)
+        }
+    }
+}
+
+fn process_godot_fns(
+    decl: &mut Impl,
+) -> Result<(Vec<FuncDefinition>, Vec<SignalDefinition>, Vec<TokenStream>), Error> {
     let mut func_definitions = vec![];
     let mut signal_signatures = vec![];
+    let mut virtual_dispatchers = vec![];
 
     let mut removed_indexes = vec![];
     for (index, item) in decl.body_items.iter_mut().enumerate() {
@@ -230,10 +300,54 @@ fn process_godot_fns(decl: &mut Impl) -> Result<(Vec<FuncDefinition>, Vec<Functi
             }
 
             match attr.ty {
-                BoundAttrType::Func { rename } => {
+                BoundAttrType::Func {
+                    mut rename,
+                    is_vararg,
+                    is_virtual,
+                } => {
+                    // Must happen before reducing to signature, as #[opt] attributes are removed from the parameters.
+                    let default_args = extract_default_args(method)?;
+
+                    if is_vararg {
+                        let param_count = method
+                            .params
+                            .inner
+                            .iter()
+                            .filter(|(param, _)| matches!(param, FnParam::Typed(_)))
+                            .count();
+
+                        if param_count != 1 || !default_args.is_empty() {
+                            return bail!(
+                                &method.name,
+                                "#[func(vararg)]: function must have exactly one parameter of type `&[Variant]`, without default"
+                            );
+                        }
+                    }
+
+                    if is_virtual {
+                        if is_vararg {
+                            return bail!(
+                                &method.name,
+                                "#[func]: `virtual` and `vararg` cannot be combined"
+                            );
+                        }
+
+                        // The Rust implementation is registered under the Godot name, and serves as default for scripts.
+                        let godot_name = rename.unwrap_or_else(|| method.name.to_string());
+                        virtual_dispatchers.push(make_virtual_dispatcher(method, &godot_name)?);
+                        rename = Some(godot_name);
+                    }
+
                     // Signatures are the same thing without body
                     let sig = util::reduce_to_signature(method);
-                    func_definitions.push(FuncDefinition { func: sig, rename });
+                    func_definitions.push(FuncDefinition {
+                        func: sig,
+                        rename,
+                        default_args,
+                        is_vararg,
+                        is_virtual,
+                        docs: extract_docs(&method.attributes),
+                    });
                 }
                 BoundAttrType::Signal(ref _attr_val) => {
                     if method.return_ty.is_some() {
@@ -241,7 +355,22 @@ fn process_godot_fns(decl: &mut Impl) -> Result<(Vec<FuncDefinition>, Vec<Functi
                     }
                     let sig = util::reduce_to_signature(method);
 
-                    signal_signatures.push(sig.clone());
+                    // Doc comments are carried over to the generated accessor.
+                    let attributes = method
+                        .attributes
+                        .iter()
+                        .filter(|attr| {
+                            attr.get_single_path_segment()
+                                .map_or(false, |name| name == "doc")
+                        })
+                        .cloned()
+                        .collect();
+
+                    signal_signatures.push(SignalDefinition {
+                        signature: sig,
+                        vis_marker: method.vis_marker.clone(),
+                        attributes,
+                    });
                     removed_indexes.push(index);
                 }
                 BoundAttrType::Const(_) => {
@@ -260,7 +389,135 @@ fn process_godot_fns(decl: &mut Impl) -> Result<(Vec<FuncDefinition>, Vec<Functi
         decl.body_items.remove(index);
     }
 
-    Ok((func_definitions, signal_signatures))
+    Ok((func_definitions, signal_signatures, virtual_dispatchers))
+}
+
+/// Turns the `#[func(virtual)]` method into the default implementation, and returns a dispatcher with the original name.
+///
+/// The dispatcher calls the override in the object's script if there is one, otherwise the default implementation. A `&self`
+/// dispatcher keeps the receiver; scripts can still bind the object, as shared binds nest. A `&mut self` dispatcher takes
+/// `this: &mut Gd<Self>` instead and binds the object only for the default implementation, so it is not bound while the
+/// script runs.
+fn make_virtual_dispatcher(method: &mut Function, godot_name: &str) -> Result<TokenStream, Error> {
+    let receiver = method
+        .params
+        .inner
+        .iter()
+        .find_map(|(param, _)| match param {
+            FnParam::Receiver(receiver) if receiver.tk_ref.is_some() => Some(receiver),
+            _ => None,
+        });
+
+    let Some(receiver) = receiver else {
+        return bail!(
+            &method.name,
+            "#[func(virtual)]: function must have a `&self` or `&mut self` receiver"
+        );
+    };
+    let is_mut = receiver.tk_mut.is_some();
+
+    // Parameters are forwarded, so they need a name.
+    let mut params = vec![];
+    let mut param_idents = vec![];
+    for (index, (param, _)) in method.params.inner.iter().enumerate() {
+        if let FnParam::Typed(param) = param {
+            if is_mut && param.name == "this" {
+                return bail!(
+                    &param.name,
+                    "#[func(virtual)]: parameter name `this` is reserved for the object in `&mut self` functions"
+                );
+            }
+
+            let name = if param.name == "_" {
+                format_ident!("__unnamed_{index}")
+            } else {
+                param.name.clone()
+            };
+            let ty = &param.ty;
+
+            params.push(quote! { #name: #ty });
+            param_idents.push(name);
+        }
+    }
+
+    let dispatcher_name = method.name.clone();
+    let default_name = format_ident!("__godot_default_{}", method.name);
+    method.name = default_name.clone();
+
+    let (receiver, object_id, call_default) = if is_mut {
+        (
+            quote! { this: &mut ::godot::obj::Gd<Self> },
+            quote! { this.instance_id() },
+            quote! { this.bind_mut().#default_name( #( #param_idents ),* ) },
+        )
+    } else {
+        (
+            quote! { &self },
+            quote! { <Self as ::godot::obj::cap::WithBaseField>::__godot_base(self).instance_id() },
+            quote! { self.#default_name( #( #param_idents ),* ) },
+        )
+    };
+
+    let return_override = if method.return_ty.is_some() {
+        quote! { return ::godot::builtin::FromVariant::from_variant(&__godot_result); }
+    } else {
+        quote! { return; }
+    };
+
+    let attributes = &method.attributes;
+    let vis_marker = &method.vis_marker;
+    let return_ty = method.return_ty.as_ref().map(|ty| quote! { -> #ty });
+
+    Ok(quote! {
+        #( #attributes )*
+        #[allow(dead_code)]
+        #vis_marker fn #dispatcher_name(#receiver, #( #params ),*) #return_ty {
+            let __godot_args: &[::godot::builtin::Variant] = &[
+                #( ::godot::builtin::ToVariant::to_variant(&#param_idents), )*
+            ];
+
+            if let Some(__godot_result) =
+                ::godot::private::call_script_override(#object_id, #godot_name, __godot_args)
+            {
+                #return_override
+            }
+
+            #call_default
+        }
+    })
+}
+
+/// Removes `#[opt(default = ...)]` attributes from the parameters of `method`, returning the default expressions.
+///
+/// Only trailing parameters can have defaults, as Godot fills in omitted arguments from the end.
+fn extract_default_args(method: &mut Function) -> Result<Vec<TokenStream>, Error> {
+    let mut default_args = vec![];
+
+    for (param, _) in method.params.inner.iter_mut() {
+        let FnParam::Typed(param) = param else {
+            continue;
+        };
+
+        let Some(mut parser) = KvParser::parse(&param.attributes, "opt")? else {
+            if !default_args.is_empty() {
+                return bail!(
+                    &param.name,
+                    "parameters following one with #[opt(default = ...)] must have a default value as well"
+                );
+            }
+            continue;
+        };
+
+        default_args.push(parser.handle_expr_required("default")?);
+        parser.finish()?;
+
+        param.attributes.retain(|attr| {
+            attr.get_single_path_segment()
+                .map_or(true, |name| name != "opt")
+        });
+    }
+
+    Ok(default_args)
 }
 
 fn process_godot_constants(decl: &mut Impl) -> Result<Vec<Constant>, Error> {
@@ -310,23 +567,26 @@ where
 
         let new_found = match attr_name {
             name if name == "func" => {
-                // TODO you-win (August 8, 2023): handle default values here as well?
-
                 // Safe unwrap since #[func] must be present if we got to this point
                 let mut parser = KvParser::parse(attributes, "func")?.unwrap();
 
                 let rename = parser.handle_expr("rename")?.map(|ts| ts.to_string());
+                let is_vararg = parser.handle_alone("vararg")?;
+                let is_virtual = parser.handle_alone("virtual")?;
+                parser.finish()?;
 
                 Some(BoundAttr {
                     attr_name: attr_name.clone(),
                     index,
-                    ty: BoundAttrType::Func { rename },
+                    ty: BoundAttrType::Func {
+                        rename,
+                        is_vararg,
+                        is_virtual,
+                    },
                 })
             }
             name if name == "signal" => {
-                // TODO once parameters are supported, this should probably be moved to the struct definition
-                // E.g. a zero-sized type Signal<(i32, String)> with a provided emit(i32, String) method
-                // This could even be made public (callable on the struct obj itself)
+                // Parameters are available through the typed TypedSignal<(P0, P1, ...)> handle; see make_signal_accessor().
                 Some(BoundAttr {
                     attr_name: attr_name.clone(),
                     index,
@@ -366,11 +626,21 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
     let mut to_string_impl = TokenStream::new();
     let mut register_class_impl = TokenStream::new();
     let mut on_notification_impl = TokenStream::new();
+    let mut get_property_impl = TokenStream::new();
+    let mut set_property_impl = TokenStream::new();
+    let mut get_property_list_impl = TokenStream::new();
+    let mut property_can_revert_impl = TokenStream::new();
+    let mut property_get_revert_impl = TokenStream::new();
 
     let mut register_fn = quote! { None };
     let mut create_fn = quote! { None };
     let mut to_string_fn = quote! { None };
     let mut on_notification_fn = quote! { None };
+    let mut get_property_fn = quote! { None };
+    let mut set_property_fn = quote! { None };
+    let mut get_property_list_fn = quote! { None };
+    let mut property_can_revert_fn = quote! { None };
+    let mut property_get_revert_fn = quote! { None };
 
     let mut virtual_methods = vec![];
     let mut virtual_method_names = vec![];
@@ -443,6 +713,92 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
                 };
             }
 
+            "get_property" => {
+                get_property_impl = quote! {
+                    impl ::godot::obj::cap::GodotGet for #class_name {
+                        fn __godot_get_property(&self, property: ::godot::builtin::StringName) -> Option<::godot::builtin::Variant> {
+                            if ::godot::private::is_class_inactive(Self::__config().is_tool) {
+                                return None;
+                            }
+
+                            <Self as #trait_name>::get_property(self, property)
+                        }
+                    }
+                };
+
+                get_property_fn = quote! { Some(#prv::callbacks::get_property::<#class_name>) };
+            }
+
+            "set_property" => {
+                set_property_impl = quote! {
+                    impl ::godot::obj::cap::GodotSet for #class_name {
+                        fn __godot_set_property(&mut self, property: ::godot::builtin::StringName, value: ::godot::builtin::Variant) -> bool {
+                            if ::godot::private::is_class_inactive(Self::__config().is_tool) {
+                                return false;
+                            }
+
+                            <Self as #trait_name>::set_property(self, property, value)
+                        }
+                    }
+                };
+
+                set_property_fn = quote! { Some(#prv::callbacks::set_property::<#class_name>) };
+            }
+
+            "get_property_list" => {
+                get_property_list_impl = quote! {
+                    impl ::godot::obj::cap::GodotGetPropertyList for #class_name {
+                        fn __godot_get_property_list(&self) -> Vec<::godot::builtin::meta::PropertyInfo> {
+                            if ::godot::private::is_class_inactive(Self::__config().is_tool) {
+                                return Vec::new();
+                            }
+
+                            <Self as #trait_name>::get_property_list(self)
+                        }
+                    }
+                };
+
+                get_property_list_fn = quote! {
+                    Some(#prv::callbacks::get_property_list::<#class_name>)
+                };
+            }
+
+            "property_can_revert" => {
+                property_can_revert_impl = quote! {
+                    impl ::godot::obj::cap::GodotPropertyCanRevert for #class_name {
+                        fn __godot_property_can_revert(&self, property: ::godot::builtin::StringName) -> bool {
+                            if ::godot::private::is_class_inactive(Self::__config().is_tool) {
+                                return false;
+                            }
+
+                            <Self as #trait_name>::property_can_revert(self, property)
+                        }
+                    }
+                };
+
+                property_can_revert_fn = quote! {
+                    Some(#prv::callbacks::property_can_revert::<#class_name>)
+                };
+            }
+
+            "property_get_revert" => {
+                property_get_revert_impl = quote! {
+                    impl ::godot::obj::cap::GodotPropertyGetRevert for #class_name {
+                        fn __godot_property_get_revert(&self, property: ::godot::builtin::StringName) -> Option<::godot::builtin::Variant> {
+                            if ::godot::private::is_class_inactive(Self::__config().is_tool) {
+                                return None;
+                            }
+
+                            <Self as #trait_name>::property_get_revert(self, property)
+                        }
+                    }
+                };
+
+                property_get_revert_fn = quote! {
+                    Some(#prv::callbacks::property_get_revert::<#class_name>)
+                };
+            }
+
             // Other virtual methods, like ready, process etc.
             _ => {
                 let method = util::reduce_to_signature(method);
@@ -475,6 +831,11 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
         #godot_init_impl
         #to_string_impl
         #on_notification_impl
+        #get_property_impl
+        #set_property_impl
+        #get_property_list_impl
+        #property_can_revert_impl
+        #property_get_revert_impl
         #register_class_impl
 
         impl ::godot::private::You_forgot_the_attribute__godot_api for #class_name {}
@@ -494,6 +855,11 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
                     _ => None,
                 }
             }
+
+            fn __base_callbacks() -> &'static ::std::sync::OnceLock<#prv::BaseCallbacks> {
+                static BASE_CALLBACKS: ::std::sync::OnceLock<#prv::BaseCallbacks> = ::std::sync::OnceLock::new();
+                &BASE_CALLBACKS
+            }
         }
 
         ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
@@ -503,7 +869,13 @@ fn transform_trait_impl(original_impl: Impl) -> Result<TokenStream, Error> {
                 user_create_fn: #create_fn,
                 user_to_string_fn: #to_string_fn,
                 user_on_notification_fn: #on_notification_fn,
+                user_set_fn: #set_property_fn,
+                user_get_fn: #get_property_fn,
+                user_get_property_list_fn: #get_property_list_fn,
+                user_property_can_revert_fn: #property_can_revert_fn,
+                user_property_get_revert_fn: #property_get_revert_fn,
                 get_virtual_fn: #prv::callbacks::get_virtual::<#class_name>,
+                base_callbacks_fn: <#class_name as ::godot::obj::cap::ImplementsGodotVirtual>::__base_callbacks,
             },
         });
     };
@@ -5,10 +5,12 @@
  */
 
 mod derive_godot_class;
+mod docs;
 mod godot_api;
 mod data_models {
     pub mod field;
     pub mod field_export;
+    pub mod field_group;
     pub mod field_var;
     pub mod func;
     pub mod property;
@@ -16,8 +18,10 @@ mod data_models {
 
 pub(crate) use data_models::field::*;
 pub(crate) use data_models::field_export::*;
+pub(crate) use data_models::field_group::*;
 pub(crate) use data_models::field_var::*;
 pub(crate) use data_models::func::*;
 pub(crate) use data_models::property::*;
 pub(crate) use derive_godot_class::*;
+pub(crate) use docs::*;
 pub(crate) use godot_api::*;
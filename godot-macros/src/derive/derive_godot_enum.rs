@@ -0,0 +1,259 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+use venial::{Declaration, StructFields};
+
+use crate::util::{bail, class_name_obj, KvParser};
+use crate::ParseResult;
+
+pub fn derive_godot_enum(decl: Declaration) -> ParseResult<TokenStream> {
+    let enum_ = match decl {
+        Declaration::Enum(e) => e,
+        Declaration::Struct(s) => {
+            return bail!(s.tk_struct, "GodotEnum can only be derived on enums")
+        }
+        Declaration::Union(u) => {
+            return bail!(u.tk_union, "GodotEnum can only be derived on enums")
+        }
+        _ => unreachable!(),
+    };
+
+    if enum_.generic_params.is_some() {
+        return bail!(&enum_.name, "GodotEnum cannot be derived on generic enums");
+    }
+
+    if enum_.variants.is_empty() {
+        return bail!(
+            enum_.name,
+            "In order to derive GodotEnum, enums must have at least one variant"
+        );
+    }
+
+    let mut variants = Vec::new();
+    for (enum_v, _) in enum_.variants.inner.iter() {
+        match &enum_v.contents {
+            StructFields::Unit => variants.push(enum_v.name.clone()),
+            _ => {
+                return bail!(
+                    enum_v.name,
+                    "GodotEnum can only be derived on enums with only unit variants"
+                )
+            }
+        }
+    }
+
+    let attr = parse_enum_attributes(&enum_.attributes)?;
+    let name = &enum_.name;
+
+    let engine_enum_impl = make_engine_enum_impl(name, &variants);
+    let property_impl = make_property_impl(name, &variants, attr.is_bitfield);
+    let constants_registration = match &attr.owner_class {
+        Some(owner) => {
+            let owner_check = make_owner_class_check(owner);
+            let registration =
+                make_constants_registration(name, owner, &variants, attr.is_bitfield);
+
+            quote! {
+                #owner_check
+                #registration
+            }
+        }
+        None => TokenStream::new(),
+    };
+
+    Ok(quote! {
+        #engine_enum_impl
+        #property_impl
+        #constants_registration
+    })
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+// Implementation
+
+struct EnumAttributes {
+    owner_class: Option<Ident>,
+    is_bitfield: bool,
+}
+
+fn parse_enum_attributes(attributes: &[venial::Attribute]) -> ParseResult<EnumAttributes> {
+    let mut owner_class = None;
+    let mut is_bitfield = false;
+
+    if let Some(mut parser) = KvParser::parse(attributes, "godot")? {
+        owner_class = parser.handle_ident("class")?;
+
+        if parser.handle_alone("bitfield")? {
+            is_bitfield = true;
+        }
+
+        // Bitfields only exist as class-scoped constants in Godot.
+        if is_bitfield && owner_class.is_none() {
+            return bail!(
+                parser.span(),
+                "#[godot(bitfield)] requires an owner class, e.g. #[godot(bitfield, class = MyClass)]"
+            );
+        }
+
+        parser.finish()?;
+    }
+
+    Ok(EnumAttributes {
+        owner_class,
+        is_bitfield,
+    })
+}
+
+/// `EngineEnum` provides `ToVariant`, `FromVariant` and `VariantMetadata` through blanket impls.
+fn make_engine_enum_impl(name: &Ident, variants: &[Ident]) -> TokenStream {
+    quote! {
+        impl ::godot::obj::EngineEnum for #name {
+            fn try_from_ord(ord: i32) -> Option<Self> {
+                #(
+                    if ord == Self::#variants as i32 {
+                        return Some(Self::#variants);
+                    }
+                )*
+                None
+            }
+
+            fn ord(self) -> i32 {
+                self as i32
+            }
+        }
+
+        impl ::godot::sys::GodotFuncMarshal for #name {
+            type Via = i64;
+            type FromViaError = ::godot::sys::PrimitiveConversionError<i64, i32>;
+            type IntoViaError = ::std::convert::Infallible;
+
+            fn try_from_via(via: Self::Via) -> ::std::result::Result<Self, Self::FromViaError> {
+                let err = ::godot::sys::PrimitiveConversionError::new(via);
+                let ord = i32::try_from(via).map_err(|_| err)?;
+                <Self as ::godot::obj::EngineEnum>::try_from_ord(ord).ok_or(err)
+            }
+
+            fn try_into_via(self) -> ::std::result::Result<Self::Via, Self::IntoViaError> {
+                Ok(<Self as ::godot::obj::EngineEnum>::ord(self).into())
+            }
+        }
+    }
+}
+
+fn make_property_impl(name: &Ident, variants: &[Ident], is_bitfield: bool) -> TokenStream {
+    let variant_strs = variants.iter().map(|v| v.to_string());
+    let hint = if is_bitfield {
+        quote! { PROPERTY_HINT_FLAGS }
+    } else {
+        quote! { PROPERTY_HINT_ENUM }
+    };
+
+    quote! {
+        impl ::godot::bind::property::Property for #name {
+            type Intermediate = Self;
+
+            fn get_property(&self) -> Self {
+                *self
+            }
+
+            fn set_property(&mut self, value: Self) {
+                *self = value;
+            }
+        }
+
+        impl ::godot::bind::property::Export for #name {
+            fn default_export_info() -> ::godot::bind::property::ExportInfo {
+                let enumerators: &[(&str, i64)] = &[
+                    #( (#variant_strs, Self::#variants as i64), )*
+                ];
+
+                let hint_string = enumerators
+                    .iter()
+                    .map(|(name, value)| format!("{name}:{value}"))
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                ::godot::bind::property::ExportInfo {
+                    hint: ::godot::engine::global::PropertyHint::#hint,
+                    hint_string: hint_string.into(),
+                }
+            }
+        }
+    }
+}
+
+/// Checks at compile time that the owner named in `class = ...` is a Rust class, which registers constants through plugins.
+fn make_owner_class_check(owner: &Ident) -> TokenStream {
+    quote! {
+        const _: () = {
+            fn __check_owner_class<T: ::godot::obj::GodotClass<Declarer = ::godot::obj::dom::UserDomain>>() {}
+
+            #[allow(dead_code)]
+            fn __check() {
+                __check_owner_class::<#owner>();
+            }
+        };
+    }
+}
+
+fn make_constants_registration(
+    name: &Ident,
+    owner: &Ident,
+    variants: &[Ident],
+    is_bitfield: bool,
+) -> TokenStream {
+    let prv = quote! { ::godot::private };
+    let owner_class_name_obj = class_name_obj(owner);
+    let enum_name_str = name.to_string();
+    let constant_names = variants.iter().map(|v| to_constant_case(&v.to_string()));
+
+    let kind = if is_bitfield {
+        quote! { Bitfield { name: #enum_name_str.into(), flags: enumerators } }
+    } else {
+        quote! { Enum { name: #enum_name_str.into(), enumerators } }
+    };
+
+    quote! {
+        ::godot::sys::plugin_add!(__GODOT_PLUGIN_REGISTRY in #prv; #prv::ClassPlugin {
+            class_name: #owner_class_name_obj,
+            component: #prv::PluginComponent::UserConstants {
+                register_fn: #prv::ErasedRegisterFn {
+                    raw: {
+                        fn __register_enum(_class_builder: &mut dyn ::std::any::Any) {
+                            use ::godot::builtin::meta::registration::constant::*;
+
+                            let enumerators = vec![
+                                #( IntegerConstant::new(#constant_names.into(), #name::#variants as i64), )*
+                            ];
+
+                            ExportConstant::new(#owner_class_name_obj, ConstantKind::#kind).register();
+                        }
+                        __register_enum
+                    },
+                },
+            },
+        });
+    }
+}
+
+/// Converts a Rust variant name like `RunFast` to the Godot constant style `RUN_FAST`.
+fn to_constant_case(name: &str) -> String {
+    let mut result = String::with_capacity(name.len() + 4);
+    let mut prev_is_lower_or_digit = false;
+
+    for c in name.chars() {
+        if c.is_uppercase() && prev_is_lower_or_digit {
+            result.push('_');
+        }
+
+        prev_is_lower_or_digit = c.is_lowercase() || c.is_ascii_digit();
+        result.extend(c.to_uppercase());
+    }
+
+    result
+}
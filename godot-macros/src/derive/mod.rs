@@ -8,10 +8,12 @@
 
 mod derive_export;
 mod derive_from_variant;
+mod derive_godot_enum;
 mod derive_property;
 mod derive_to_variant;
 
 pub(crate) use derive_export::*;
 pub(crate) use derive_from_variant::*;
+pub(crate) use derive_godot_enum::*;
 pub(crate) use derive_property::*;
 pub(crate) use derive_to_variant::*;
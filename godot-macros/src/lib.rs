@@ -104,6 +104,48 @@ use crate::util::ident;
 /// }
 /// ```
 ///
+/// ## Rust base classes
+///
+/// A class can also inherit from another `#[derive(GodotClass)]` struct, with `#[class(rust_base = Base)]` instead of `base`.
+/// Its engine base is then the one of the Rust base. Methods, properties and signals of the base are available on the derived
+/// class, both from Rust (via [`Inherits`](../obj/trait.Inherits.html) and `upcast()`) and from Godot.
+///
+/// The base class must be registered with a constructor, which is run before the derived one. Its fields can be accessed through
+/// the `#[base]` field:
+///
+/// ```
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base = Node2D)]
+/// struct Enemy {
+///     #[var]
+///     health: i64,
+///
+///     #[base]
+///     base: Base<Node2D>,
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init, rust_base = Enemy)]
+/// struct Boss {
+///     #[base]
+///     base: Base<Enemy>,
+/// }
+///
+/// impl Boss {
+///     fn health(&self) -> i64 {
+///         self.base.bind().health
+///     }
+/// }
+/// ```
+///
+/// Notifications and virtual methods implemented by the base class keep working: if the derived class doesn't override one,
+/// the base class' implementation is used. Notifications handled by both are first delivered to the base class. Other
+/// callbacks like `to_string()` or `get_property()` are replaced if the derived class implements them.
+///
+/// `#[class(reloadable)]` is not supported on classes with a Rust base.
+///
 ///
 /// # Properties and exports
 ///
@@ -133,9 +175,6 @@ use crate::util::ident;
 /// generates a trivial getter and setter named `get_my_field` and `set_my_field`, respectively.
 /// These are `pub` in Rust, since they're exposed from GDScript anyway.
 ///
-/// For technical reasons, an impl-block with the `#[godot_api]` attribute is required for properties to
-/// work. Failing to include one will cause a compile error if you try to create any properties.
-///
 /// If you want to implement your own getter and/or setter, write those as a function on your Rust
 /// type, expose it using `#[func]`, and annotate the field with
 /// `#[export(get = ..., set = ...)]`:
@@ -302,11 +341,77 @@ use crate::util::ident;
 /// impl MyStruct {}
 /// ```
 ///
+/// Properties can be laid out in the inspector with `#[export_category]`, `#[export_group]` and `#[export_subgroup]`,
+/// which behave like their GDScript counterparts. Each attribute starts a new section at the annotated field, and all
+/// following properties belong to it, until another section of the same or a higher level begins. Groups and subgroups
+/// accept an optional `prefix`, which is stripped from the property names displayed in the inspector:
+///
+/// ```
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// struct MyStruct {
+///     // @export_category("Player")
+///     // @export_group("Movement", "move_")
+///     #[export_category(name = "Player")]
+///     #[export_group(name = "Movement", prefix = "move_")]
+///     #[export]
+///     move_speed: f64,
+///
+///     #[export]
+///     move_acceleration: f64,
+///
+///     // @export_subgroup("Jumping")
+///     #[export_subgroup(name = "Jumping")]
+///     #[export]
+///     jump_height: f64,
+///
+///     // @export_group("Combat")
+///     #[export_group(name = "Combat")]
+///     #[export]
+///     health: i64,
+/// }
+///
+/// #[godot_api]
+/// impl MyStruct {}
+/// ```
+///
 ///
 /// # Signals
 ///
-/// The `#[signal]` attribute is accepted, but not yet implemented. See [issue
-/// #8](https://github.com/godot-rust/gdext/issues/8).
+/// Signals are declared as bodiless functions inside a `#[godot_api]` impl block and annotated with `#[signal]`.
+/// Parameters are registered with Godot, so they show up in the editor and can be used from GDScript.
+///
+/// For every signal, an associated function `signal_<name>` is generated, which returns a
+/// [`TypedSignal`](../obj/struct.TypedSignal.html) handle for a given object. Its `emit()` method accepts exactly the declared
+/// parameters, so emitting with wrong arguments is a compile error rather than a runtime failure.
+///
+/// ```no_run
+/// use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Player {
+///     #[base]
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[signal]
+///     fn hit(damage: i64, source: Gd<Node>);
+///
+///     #[func]
+///     fn on_collision(&mut self, source: Gd<Node>) {
+///         let this = self.base.clone().cast::<Self>();
+///         Self::signal_hit(&this).emit(10, source);
+///     }
+/// }
+///
+/// fn connect_hud(player: &Gd<Player>, hud: &Gd<Node>) {
+///     Player::signal_hit(player).connect_obj(hud, "on_player_hit");
+/// }
+/// ```
 ///
 ///
 /// # Running code in the editor
@@ -318,7 +423,119 @@ use crate::util::ident;
 /// for more information and further customization.
 ///
 /// This is very similar to [GDScript's `@tool` feature](https://docs.godotengine.org/en/stable/tutorials/plugins/running_code_in_the_editor.html).
-#[proc_macro_derive(GodotClass, attributes(class, base, var, export, init, signal))]
+///
+///
+/// # Editor plugins
+///
+/// Classes inheriting `EditorPlugin` can be added to the editor with `#[class(editor_plugin)]`. They are registered when
+/// the `Editor` init level is loaded and removed again when it is unloaded, so no GDScript plugin is needed to bootstrap them.
+/// This requires Godot 4.1 or later.
+///
+/// Editor plugins are implicitly [tool classes](#running-code-in-the-editor).
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # use godot::engine::EditorPlugin;
+/// #[derive(GodotClass)]
+/// #[class(init, editor_plugin, base=EditorPlugin)]
+/// struct LevelEditor {
+///     #[base]
+///     base: Base<EditorPlugin>,
+/// }
+/// ```
+///
+///
+/// # Abstract classes
+///
+/// Base classes which are only meant to be extended can be declared with `#[class(abstract)]`. Such classes are not offered in
+/// the editor's "Create New Node" dialog, but GDScript classes can still `extends` them.
+///
+/// If the class has a constructor (`#[class(init)]` or an `init()` method), subclasses construct it as their base, so it must be
+/// provided. Without a constructor, Godot registers the class as fully abstract and refuses to instantiate it.
+///
+/// ```
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, abstract, base=Node2D)]
+/// struct Enemy {
+///     #[var]
+///     health: i64,
+///
+///     #[base]
+///     base: Base<Node2D>,
+/// }
+/// ```
+///
+///
+/// # Documentation
+///
+/// With the Cargo feature `docs` and since Godot 4.3, `///` comments are shown in the editor's built-in help. This covers the class itself, its `#[var]` and
+/// `#[export]` fields, as well as `#[func]`, `#[signal]` and `#[constant]` items in `#[godot_api]` blocks. The first paragraph of
+/// the class docs becomes its brief description. Code blocks and inline code are converted to Godot's BBCode markup.
+///
+/// Classes without any doc comments keep the help page that Godot generates on its own. On earlier versions, docs are ignored.
+///
+/// ```
+/// # use godot::prelude::*;
+/// /// A coin the player can pick up.
+/// ///
+/// /// Emits `collected` when touched by the player.
+/// #[derive(GodotClass)]
+/// #[class(init, base=Area2D)]
+/// struct Coin {
+///     /// Score added to the player's total.
+///     #[export]
+///     value: i64,
+///
+///     #[base]
+///     base: Base<Area2D>,
+/// }
+/// ```
+///
+///
+/// # Hot reloading
+///
+/// Since Godot 4.2, a library with `reloadable = true` in its `.gdextension` file is reloaded whenever it is recompiled
+/// while the editor is running. Objects of your classes survive this, but their Rust instances have to be rebuilt.
+///
+/// With `#[class(reloadable)]`, this is done by [`Reloadable::recreate()`](../obj/trait.Reloadable.html), which you implement yourself.
+/// Godot then restores all registered properties and sends `NOTIFICATION_EXTENSION_RELOADED`, which you can handle in
+/// `on_notification()` to migrate remaining state. Objects of classes without the attribute are not recreated.
+///
+/// ```
+/// # use godot::prelude::*;
+/// # use godot::obj::Reloadable;
+/// #[derive(GodotClass)]
+/// #[class(init, reloadable, base=Node)]
+/// struct Counter {
+///     #[var]
+///     count: i64,
+///
+///     #[base]
+///     base: Base<Node>,
+/// }
+///
+/// impl Reloadable for Counter {
+///     fn recreate(base: Base<Node>) -> Self {
+///         // `count` is restored by Godot afterwards.
+///         Self { count: 0, base }
+///     }
+/// }
+/// ```
+#[proc_macro_derive(
+    GodotClass,
+    attributes(
+        class,
+        base,
+        var,
+        export,
+        export_category,
+        export_group,
+        export_subgroup,
+        init,
+        signal
+    )
+)]
 pub fn derive_godot_class(input: TokenStream) -> TokenStream {
     translate(input, class::derive_godot_class)
 }
@@ -351,6 +568,112 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 /// Neither `#[godot_api]` attribute is required. For small data bundles inheriting `RefCounted`, you may be fine with
 /// accessing properties directly from GDScript.
 ///
+/// # Static functions
+///
+/// A `#[func]` without `self` receiver is registered as a static method. From Godot 4.1 on, it can be called on the class
+/// itself, e.g. `Inventory.from_json(text)` in GDScript. Godot 4.0 does not support static extension methods; there, they
+/// are registered as regular methods and must be called on an instance.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # struct Inventory { items: VariantArray }
+/// #[godot_api]
+/// impl Inventory {
+///     #[func]
+///     fn from_json(text: GodotString) -> Gd<Inventory> {
+///         let items = VariantArray::new(); // parse text...
+///         Gd::new(Inventory { items })
+///     }
+/// }
+/// ```
+///
+/// # Virtual functions
+///
+/// A method declared with `#[func(virtual)]` can be overridden by scripts extending the Rust class, e.g. in GDScript. The
+/// Rust body acts as the default implementation, used if the script does not override the method (or calls `super`).
+///
+/// Calling the method from Rust dispatches to the script override if there is one:
+/// * For `&self` methods, the dispatcher is a method with the same signature, which requires a `#[base]` field. The object stays
+///   bound while the override runs, so the script can call back into `&self` methods, but not into `&mut self` ones.
+/// * For `&mut self` methods, the dispatcher is an associated function taking `this: &mut Gd<Self>` in place of `&mut self`.
+///   It binds the object only to run the Rust implementation, so the script can call back into any method. The object must
+///   not be bound when the dispatcher is called.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotClass)]
+/// #[class(init, base=Node)]
+/// struct Enemy {
+///     health: i64,
+///     #[base]
+///     base: Base<Node>,
+/// }
+///
+/// #[godot_api]
+/// impl Enemy {
+///     // GDScript: `extends Enemy` and `func is_fatal(dmg): ...`
+///     #[func(virtual)]
+///     fn is_fatal(&self, dmg: i64) -> bool {
+///         dmg >= self.health
+///     }
+///
+///     #[func(virtual)]
+///     fn on_hit(&mut self, dmg: i64) {
+///         self.health -= dmg;
+///     }
+///
+///     #[func]
+///     fn take_damage(&mut self, dmg: i64) {
+///         if self.is_fatal(dmg) {
+///             self.base.queue_free();
+///         }
+///     }
+/// }
+///
+/// fn hit(enemy: &mut Gd<Enemy>, dmg: i64) {
+///     Enemy::on_hit(enemy, dmg);
+/// }
+/// ```
+///
+/// # Default parameters
+///
+/// Trailing parameters of a `#[func]` can be given default values with `#[opt(default = ...)]`. Callers from GDScript may
+/// then omit these arguments, and the editor documentation shows the defaults. The expression must have the type of the
+/// parameter, and is evaluated whenever it is needed.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # struct Spawner;
+/// #[godot_api]
+/// impl Spawner {
+///     #[func]
+///     fn spawn(&mut self, count: i64, #[opt(default = 1.0)] speed: f64) {
+///         // GDScript: spawn(3) or spawn(3, 2.5)
+///     }
+/// }
+/// ```
+///
+/// # Variadic functions
+///
+/// With `#[func(vararg)]`, a function accepts any number of arguments, like GDScript's `print()`. It must have exactly one
+/// parameter of type `&[Variant]`, which receives all arguments. Such functions can only be invoked through varcall.
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// # #[derive(GodotClass)]
+/// # struct Console;
+/// #[godot_api]
+/// impl Console {
+///     #[func(vararg)]
+///     fn log_all(&self, args: &[Variant]) -> i64 {
+///         // GDScript: log_all("a", 1, Vector2.ZERO)
+///         args.len() as i64
+///     }
+/// }
+/// ```
+///
 /// # Examples
 ///
 /// ## `RefCounted` as a base, overridden `init`
@@ -399,6 +722,37 @@ pub fn derive_godot_class(input: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
+///
+/// ## Multiple `impl` blocks
+///
+/// A class can have any number of inherent `#[godot_api]` blocks, which may also live in different modules (the class must
+/// be imported there by name). All of them are registered together with the class. There is at most one block per virtual
+/// trait, however.
+///
+/// ```no_run
+///# use godot::prelude::*;
+///
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// pub struct Player;
+///
+/// #[godot_api]
+/// impl Player {
+///     #[func]
+///     fn jump(&mut self) {}
+/// }
+///
+/// mod combat {
+///     use super::Player;
+///     use godot::prelude::*;
+///
+///     #[godot_api]
+///     impl Player {
+///         #[func]
+///         fn attack(&mut self) {}
+///     }
+/// }
+/// ```
 #[proc_macro_attribute]
 pub fn godot_api(_meta: TokenStream, input: TokenStream) -> TokenStream {
     translate(input, class::attribute_godot_api)
@@ -498,10 +852,6 @@ pub fn derive_from_variant(input: TokenStream) -> TokenStream {
 ///     foo: TestEnum
 /// }
 ///
-/// # //TODO: remove this when https://github.com/godot-rust/gdext/issues/187 is truly addressed
-/// # #[godot_api]
-/// # impl TestClass {}
-///
 /// # fn main() {
 /// let mut class = TestClass {foo: TestEnum::B};
 /// assert_eq!(class.get_foo(), TestEnum::B as i32);
@@ -522,6 +872,57 @@ pub fn derive_export(input: TokenStream) -> TokenStream {
     translate(input, derive::derive_export)
 }
 
+/// Derive macro for exporting C-like Rust enums to Godot as integer enums.
+///
+/// Implements `ToVariant`, `FromVariant`, [`Property`](../bind/property/trait.Property.html) and
+/// [`Export`](../bind/property/trait.Export.html), so the enum can be used in `#[func]` signatures as well as `#[var]` and
+/// `#[export]` fields. The editor shows exported fields as a drop-down list of all variants.
+///
+/// The enum must only contain unit variants and implement `Copy`. Discriminants may be implicit or explicit.
+///
+/// # Class-scoped registration
+///
+/// With `#[godot(class = MyClass)]`, the enumerators are additionally registered as constants of `MyClass`, so that GDScript
+/// can refer to them as `MyClass.State.IDLE`. Variant names are converted to Godot's constant style, e.g. `RunFast` becomes
+/// `RUN_FAST`. `MyClass` must be a class declared with `#[derive(GodotClass)]`.
+///
+/// Adding `bitfield` (i.e. `#[godot(class = MyClass, bitfield)]`) registers the constants as a bitfield instead, which lets
+/// GDScript combine them into flag sets. Exported fields are then shown as flag check boxes. A value of the Rust enum itself always
+/// holds exactly one flag; combined flags are passed as plain integers. `bitfield` cannot be used without `class`.
+///
+/// # Example
+///
+/// ```no_run
+/// # use godot::prelude::*;
+/// #[derive(GodotEnum, Clone, Copy, Default, PartialEq, Debug)]
+/// #[godot(class = Player)]
+/// enum State {
+///     #[default]
+///     Idle,
+///     Walk,
+///     RunFast = 4,
+/// }
+///
+/// #[derive(GodotClass)]
+/// #[class(init)]
+/// struct Player {
+///     #[export]
+///     state: State,
+/// }
+///
+/// #[godot_api]
+/// impl Player {
+///     #[func]
+///     fn is_idle(&self) -> bool {
+///         self.state == State::Idle
+///     }
+/// }
+/// ```
+#[proc_macro_derive(GodotEnum, attributes(godot))]
+pub fn derive_godot_enum(input: TokenStream) -> TokenStream {
+    translate(input, derive::derive_godot_enum)
+}
+
 /// Similar to `#[test]`, but runs an integration test with Godot.
 ///
 /// Transforms the `fn` into one returning `bool` (success of the test), which must be called explicitly.
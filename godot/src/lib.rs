@@ -99,8 +99,10 @@
 //! As a rule of thumb, if you must use threading, prefer to use [Rust threads](https://doc.rust-lang.org/std/thread)
 //! over Godot threads.
 //!
-//! The Cargo feature `threads` provides experimental support for multithreading. The underlying safety
-//! rules are still being worked out, as such you may encounter unsoundness and an unstable API.
+//! Without the Cargo feature `threads`, Rust instances of user classes may only be accessed on the main thread; `Gd::bind()`
+//! and `Gd::bind_mut()` panic otherwise. The feature provides experimental support for multithreading: user instances are
+//! guarded by a lock, and `Gd<T>` becomes `Send + Sync` whenever `T` is. The underlying safety rules are still being worked out,
+//! as such you may encounter unsoundness and an unstable API.
 //!
 //! # Cargo features
 //!
@@ -129,16 +131,23 @@
 //!   ```
 //!   <br>
 //!
+//! * **`docs`**
+//!
+//!   Generate documentation for the editor's built-in help from `///` comments on user classes (Godot 4.3+). Without this feature,
+//!   doc comments are not embedded into the library at all.<br><br>
+//!
 //! * **`serde`**
 //!
-//!   Implement the [serde](https://docs.rs/serde) traits `Serialize` and `Deserialize` traits for certain built-in types.
+//!   Implement the [serde](https://docs.rs/serde) traits `Serialize` and `Deserialize` traits for built-in types, including `Variant`,
+//!   `Array`, `Dictionary`, the string types and packed arrays. Objects, `Callable` and `Signal` are not serializable.
+//!   Types backed by the engine (e.g. `GodotString` or `Variant`) need a running Godot instance to be (de)serialized.
 //!   The serialized representation underlies **no stability guarantees** and may change at any time, even without a SemVer-breaking change.
 //!   <br><br>
 //!
 //! * **`threads`**
 //!
-//!   Experimental threading support. This enables `Send`/`Sync` traits for `Gd<T>` and makes the guard types `Gd`/`GdMut` aware of
-//!   multi-threaded references. The safety aspects of this are not ironed out yet; use at your own risk. The API may also change
+//!   Experimental threading support. This enables `Send`/`Sync` traits for `Gd<T>` (if `T: Send + Sync`) and makes the guard types
+//!   `GdRef`/`GdMut` aware of multi-threaded references. The safety aspects of this are not ironed out yet; use at your own risk. The API may also change
 //!   at any time.
 //!
 //! # Public API
@@ -158,7 +167,7 @@
 //! This allows us to decide whether it fits the scope of the library and to design proper APIs for it.
 
 #[doc(inline)]
-pub use godot_core::{builtin, engine, log, obj};
+pub use godot_core::{builtin, engine, log, obj, task};
 
 #[doc(hidden)]
 pub use godot_core::sys;
@@ -173,7 +182,9 @@ pub mod init {
 /// Export user-defined classes and methods to be called by the engine.
 pub mod bind {
     pub use godot_core::property;
-    pub use godot_macros::{godot_api, Export, FromVariant, GodotClass, Property, ToVariant};
+    pub use godot_macros::{
+        godot_api, Export, FromVariant, GodotClass, GodotEnum, Property, ToVariant,
+    };
 }
 
 /// Testing facilities (unstable).
@@ -188,7 +199,9 @@ pub use godot_core::private;
 /// Often-imported symbols.
 pub mod prelude {
     pub use super::bind::property::{Export, Property, TypeStringHint};
-    pub use super::bind::{godot_api, Export, FromVariant, GodotClass, Property, ToVariant};
+    pub use super::bind::{
+        godot_api, Export, FromVariant, GodotClass, GodotEnum, Property, ToVariant,
+    };
 
     pub use super::builtin::math::FloatExt as _;
     pub use super::builtin::*;
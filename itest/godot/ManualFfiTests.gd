@@ -304,3 +304,24 @@ func test_func_rename():
 	assert_eq(func_rename.has_method("renamed_static"), false)
 	assert_eq(func_rename.has_method("spell_static"), true)
 	assert_eq(func_rename.spell_static(), "static")
+
+func test_func_static():
+	# Static extension methods can only be called on the class since Godot 4.1.
+	if Engine.get_version_info().hex < 0x040100:
+		return
+
+	# Untyped, so that the call is resolved at runtime and the script also parses on 4.0.
+	var func_static = FuncStatic
+	var obj = func_static.create(7)
+	assert_eq(obj.get_value(), 7)
+
+class EnemyGd extends AbstractEnemy:
+	func _init():
+		health = 10
+
+func test_extend_abstract_class():
+	var enemy := EnemyGd.new()
+	assert_eq(enemy.health, 10)
+	assert_eq(enemy.is_alive(), true)
+	assert_that(enemy is AbstractEnemy)
+	enemy.free()
@@ -0,0 +1,13 @@
+# This Source Code Form is subject to the terms of the Mozilla Public
+# License, v. 2.0. If a copy of the MPL was not distributed with this
+# file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+extends FuncVirtual
+
+# Overrides the Rust default implementation.
+func on_hit(dmg: int) -> int:
+	return dmg * 10
+
+# Calls back into the Rust default implementation.
+func on_heal(amount: int) -> int:
+	return super(amount * 2)
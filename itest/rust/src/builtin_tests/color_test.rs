@@ -5,7 +5,9 @@
  */
 
 use crate::framework::itest;
-use godot::builtin::{Color, ColorChannelOrder};
+use godot::builtin::inner::InnerColor;
+use godot::builtin::math::{assert_eq_approx, ApproxEq};
+use godot::builtin::{Color, ColorChannelOrder, GodotString};
 
 #[itest]
 fn color_from_rgba8() {
@@ -61,7 +63,6 @@ fn color_from_html() {
 
 #[itest]
 fn color_from_string() {
-    // We don't test all possibilities because internally the string is just passed to the engine.
     assert_eq!(
         Color::from_string("white"),
         Some(Color::from_rgba(1.0, 1.0, 1.0, 1.0))
@@ -110,3 +111,129 @@ fn color_to_u64() {
     assert_eq!(c.to_u64(ColorChannelOrder::Abgr), 0x0404_0303_0202_0101);
     assert_eq!(c.to_u64(ColorChannelOrder::Argb), 0x0404_0101_0202_0303);
 }
+
+#[itest]
+fn color_html_matches_engine() {
+    for html in [
+        "#abc", "abcd", "#A1B2C3", "a1b2c3d4", "", "#", "#abcde", "#abcg", "##abc", "0x123456",
+    ] {
+        let godot_html = GodotString::from(html);
+        let engine =
+            InnerColor::html_is_valid(godot_html.clone()).then(|| InnerColor::html(godot_html));
+
+        assert_eq!(Color::from_html(html), engine, "from_html({html:?})");
+    }
+}
+
+#[itest]
+fn color_from_string_matches_engine() {
+    // Sentinel that no successful lookup can produce.
+    let fallback = Color::from_rgba(-1.0, -1.0, -1.0, -1.0);
+
+    for string in [
+        "white",
+        "BLACK",
+        "lawn-green",
+        "Light Goldenrod",
+        "web_purple",
+        "gray",
+        "transparent",
+        "Rebecca.Purple",
+        "#abcd",
+        "octarine",
+        "",
+    ] {
+        let engine = InnerColor::from_string(GodotString::from(string), fallback);
+        let engine = (engine != fallback).then_some(engine);
+
+        assert_eq!(
+            Color::from_string(string),
+            engine,
+            "from_string({string:?})"
+        );
+    }
+}
+
+#[itest]
+fn color_methods_match_engine() {
+    let colors = [
+        Color::from_rgba(0.2, 0.4, 0.8, 0.5),
+        Color::from_rgba(1.0, 0.0, 0.5, 1.0),
+        Color::from_rgba(0.001, 0.04, 0.9, 0.0),
+        Color::from_rgba(1.5, -0.25, 0.3, 0.75),
+    ];
+
+    for c in colors {
+        let inner = InnerColor::from_outer(&c);
+        let other = Color::from_rgba(0.7, 0.1, 0.3, 0.6);
+
+        assert_eq_approx!(c.luminance(), inner.get_luminance(), "luminance: {c}");
+        assert_eq_approx!(c.blend(other), inner.blend(other), "blend: {c}");
+        assert_eq_approx!(c.lerp(other, 0.3), inner.lerp(other, 0.3), "lerp: {c}");
+        assert_eq_approx!(
+            c.clamp(Color::from_rgba(0.1, 0.1, 0.1, 0.1), other),
+            inner.clamp(Color::from_rgba(0.1, 0.1, 0.1, 0.1), other),
+            "clamp: {c}"
+        );
+        assert_eq_approx!(c.darkened(0.3), inner.darkened(0.3), "darkened: {c}");
+        assert_eq_approx!(c.lightened(0.3), inner.lightened(0.3), "lightened: {c}");
+        assert_eq_approx!(c.inverted(), inner.inverted(), "inverted: {c}");
+        assert_eq_approx!(
+            c.linear_to_srgb(),
+            inner.linear_to_srgb(),
+            "linear_to_srgb: {c}"
+        );
+        assert_eq_approx!(
+            c.srgb_to_linear(),
+            inner.srgb_to_linear(),
+            "srgb_to_linear: {c}"
+        );
+        assert_eq!(c.to_html(), inner.to_html(true).to_string(), "to_html: {c}");
+        assert_eq!(
+            c.to_html_without_alpha(),
+            inner.to_html(false).to_string(),
+            "to_html_without_alpha: {c}"
+        );
+        assert_eq!(
+            c.approx_eq(&other),
+            inner.is_equal_approx(other),
+            "approx_eq: {c}"
+        );
+    }
+
+    for (h, s, v) in [
+        (0.0, 1.0, 1.0),
+        (0.3, 0.5, 0.8),
+        (0.95, 0.2, 0.4),
+        (1.4, 1.0, 1.0),
+    ] {
+        assert_eq_approx!(
+            Color::from_hsv(h, s, v),
+            InnerColor::from_hsv(h, s, v, 1.0),
+            "from_hsv({h}, {s}, {v})"
+        );
+    }
+
+    for (h, s, l) in [
+        (0.0, 1.0, 0.5),
+        (0.3, 0.5, 0.8),
+        (0.58, 0.5, 0.79),
+        (0.95, 0.9, 0.2),
+        (0.6, 0.0, 0.4),
+        (1.4, 1.0, 1.0),
+    ] {
+        assert_eq_approx!(
+            Color::from_ok_hsl(h, s, l),
+            InnerColor::from_ok_hsl(h, s, l, 1.0),
+            "from_ok_hsl({h}, {s}, {l})"
+        );
+    }
+
+    for rgbe in [0, 0x7800_0100, 0x8123_4567, u32::MAX] {
+        assert_eq_approx!(
+            Color::from_rgbe9995(rgbe),
+            InnerColor::from_rgbe9995(rgbe as i64),
+            "from_rgbe9995({rgbe:#x})"
+        );
+    }
+}
@@ -6,13 +6,14 @@
 
 use std::collections::{HashMap, HashSet};
 
-use godot::builtin::{dict, varray, Dictionary, FromVariant, ToVariant, Variant};
+use godot::bind::property::{Export, ExportInfo};
+use godot::builtin::{dict, varray, Dictionary, FromVariant, GodotString, ToVariant, Variant};
 
 use crate::framework::{expect_panic, itest};
 
 #[itest]
 fn dictionary_default() {
-    assert_eq!(Dictionary::default().len(), 0);
+    assert_eq!(<Dictionary>::default().len(), 0);
 }
 
 #[itest]
@@ -22,13 +23,13 @@ fn dictionary_new() {
 
 #[itest]
 fn dictionary_from_iterator() {
-    let dictionary = Dictionary::from_iter([("foo", 1), ("bar", 2)]);
+    let dictionary = <Dictionary>::from_iter([("foo", 1), ("bar", 2)]);
 
     assert_eq!(dictionary.len(), 2);
     assert_eq!(dictionary.get("foo"), Some(1.to_variant()), "key = \"foo\"");
     assert_eq!(dictionary.get("bar"), Some(2.to_variant()), "key = \"bar\"");
 
-    let dictionary = Dictionary::from_iter([(1, "foo"), (2, "bar")]);
+    let dictionary = <Dictionary>::from_iter([(1, "foo"), (2, "bar")]);
 
     assert_eq!(dictionary.len(), 2);
     assert_eq!(dictionary.get(1), Some("foo".to_variant()), "key = 1");
@@ -97,7 +98,7 @@ fn dictionary_clone() {
     };
     #[allow(clippy::redundant_clone)]
     let clone = dictionary.clone();
-    Dictionary::from_variant(&clone.get("bar").unwrap()).insert("final", 4);
+    <Dictionary>::from_variant(&clone.get("bar").unwrap()).insert("final", 4);
     assert_eq!(subdictionary.get("final"), Some(4.to_variant()));
 }
 
@@ -122,7 +123,7 @@ fn dictionary_duplicate_deep() {
         "bar": subdictionary.clone()
     };
     let clone = dictionary.duplicate_deep();
-    Dictionary::from_variant(&clone.get("bar").unwrap()).insert("baz", 4);
+    <Dictionary>::from_variant(&clone.get("bar").unwrap()).insert("baz", 4);
     assert_eq!(
         subdictionary.get("baz"),
         Some(true.to_variant()),
@@ -141,7 +142,7 @@ fn dictionary_duplicate_shallow() {
         "bar": subdictionary.clone()
     };
     let mut clone = dictionary.duplicate_shallow();
-    Dictionary::from_variant(&clone.get("bar").unwrap()).insert("baz", 4);
+    <Dictionary>::from_variant(&clone.get("bar").unwrap()).insert("baz", 4);
     assert_eq!(
         subdictionary.get("baz"),
         Some(4.to_variant()),
@@ -584,3 +585,108 @@ fn dictionary_iter_erase() {
     ```
      */
 }
+
+#[itest]
+fn typed_dictionary_insert_get() {
+    let mut dictionary = Dictionary::<GodotString, i64>::default();
+    assert_eq!(dictionary.insert("one", 1), None);
+    dictionary.set(GodotString::from("two"), 2);
+
+    assert_eq!(dictionary.len(), 2);
+    assert_eq!(dictionary.get("one"), Some(1));
+    assert_eq!(dictionary.get("missing"), None);
+    assert!(dictionary.contains_key("two"));
+    assert_eq!(dictionary.insert("one", 10), Some(1));
+    assert_eq!(
+        dictionary.find_key_by_value(10),
+        Some(GodotString::from("one"))
+    );
+
+    assert_eq!(dictionary.remove("two"), Some(2));
+    assert_eq!(dictionary.remove("two"), None);
+    assert_eq!(dictionary.len(), 1);
+}
+
+#[itest]
+fn typed_dictionary_iter() {
+    let dictionary: Dictionary<i64, GodotString> = (0..3)
+        .map(|i| (i, GodotString::from(format!("v{i}"))))
+        .collect();
+
+    let map: HashMap<i64, String> = dictionary
+        .iter_shared()
+        .map(|(key, value)| (key, value.to_string()))
+        .collect();
+    let expected = HashMap::from([(0, "v0".into()), (1, "v1".into()), (2, "v2".into())]);
+    assert_eq!(map, expected);
+
+    let keys: HashSet<i64> = dictionary.keys_shared().collect();
+    assert_eq!(keys, HashSet::from([0, 1, 2]));
+}
+
+#[itest]
+fn typed_dictionary_variant_roundtrip() {
+    let mut dictionary = Dictionary::<GodotString, i64>::default();
+    dictionary.set("answer", 42);
+
+    let variant = dictionary.to_variant();
+    let back = variant.to::<Dictionary<GodotString, i64>>();
+    assert_eq!(back.get("answer"), Some(42));
+    assert_eq!(back, dictionary);
+
+    // A typed dictionary can always be viewed as an untyped one.
+    let untyped = variant.to::<Dictionary>();
+    assert_eq!(untyped.get("answer"), Some(42.to_variant()));
+}
+
+#[itest]
+fn typed_dictionary_wrong_entry() {
+    let untyped = dict! { "key": "not an int" };
+
+    // Before Godot 4.4, dictionaries are untyped on the engine side: conversion succeeds, but reading fails.
+    #[cfg(before_api = "4.4")]
+    {
+        let typed = untyped.to_variant().to::<Dictionary<GodotString, i64>>();
+        expect_panic("value of wrong type is not convertible", || {
+            typed.get("key");
+        });
+    }
+
+    // Since Godot 4.4, the engine knows the types, so the conversion itself is checked.
+    #[cfg(since_api = "4.4")]
+    assert_eq!(
+        untyped
+            .to_variant()
+            .try_to::<Dictionary<GodotString, i64>>(),
+        Err(godot::builtin::VariantConversionError::BadType)
+    );
+}
+
+#[itest]
+#[cfg(since_api = "4.4")]
+fn typed_dictionary_conversion_checked() {
+    let typed = Dictionary::<GodotString, i64>::default().to_variant();
+
+    assert_eq!(
+        typed.try_to::<Dictionary<i64, i64>>(),
+        Err(godot::builtin::VariantConversionError::BadType)
+    );
+    assert!(typed.try_to::<Dictionary<GodotString, i64>>().is_ok());
+}
+
+#[itest]
+fn typed_dictionary_export_info() {
+    let info = <Dictionary<GodotString, i64> as Export>::default_export_info();
+
+    #[cfg(since_api = "4.4")]
+    assert_eq!(
+        info,
+        ExportInfo {
+            hint: godot::engine::global::PropertyHint::PROPERTY_HINT_DICTIONARY_TYPE,
+            hint_string: "4:;2:".into(),
+        }
+    );
+
+    #[cfg(before_api = "4.4")]
+    assert_eq!(info, ExportInfo::with_hint_none());
+}
@@ -7,9 +7,11 @@
 use std::cell::Cell;
 
 use godot::bind::{godot_api, GodotClass};
-use godot::builtin::{GodotString, Variant};
+use godot::builtin::{
+    FromVariant, GodotString, Signal, StringName, ToVariant, Variant, VariantType,
+};
 
-use godot::engine::Object;
+use godot::engine::{global, Object};
 use godot::obj::{Base, Gd};
 use godot::sys;
 
@@ -87,3 +89,93 @@ fn signals() {
     receiver.free();
     emitter.free();
 }
+
+#[itest]
+fn signals_typed() {
+    let mut emitter = Gd::<Emitter>::new_default();
+    let receiver = Gd::<Receiver>::new_default();
+
+    let mut signal_0 = Emitter::signal_signal_0_arg(&emitter);
+    let mut signal_1 = Emitter::signal_signal_1_arg(&emitter);
+    let mut signal_2 = Emitter::signal_signal_2_arg(&emitter);
+    assert_eq!(signal_1.name(), "signal_1_arg".into());
+    assert_eq!(signal_1.object(), emitter.clone().upcast::<Object>());
+
+    signal_0.connect_obj(&receiver, "receive_0_arg");
+    signal_1.connect(receiver.callable("receive_1_arg"));
+    signal_2.connect(receiver.callable("receive_2_arg"));
+    assert!(signal_1.is_connected(receiver.callable("receive_1_arg")));
+
+    signal_0.emit();
+    signal_1.emit(987);
+    signal_2.emit(
+        receiver.clone().upcast(),
+        GodotString::from(SIGNAL_ARG_STRING),
+    );
+
+    for used in receiver.bind().used.iter() {
+        assert!(used.get());
+    }
+
+    signal_1.disconnect(receiver.callable("receive_1_arg"));
+    assert!(!signal_1.is_connected(receiver.callable("receive_1_arg")));
+
+    receiver.free();
+    emitter.free();
+}
+
+#[itest]
+fn signal_builtin_basic() {
+    let emitter = Gd::<Emitter>::new_default();
+    let signal = Signal::from_object_signal(&emitter, "signal_1_arg");
+
+    assert!(!signal.is_null());
+    assert_eq!(signal.name(), StringName::from("signal_1_arg"));
+    assert_eq!(signal.object(), Some(emitter.clone().upcast::<Object>()));
+    assert_eq!(signal.object_id(), Some(emitter.instance_id()));
+    assert_eq!(signal, signal.clone());
+    assert_ne!(signal, Signal::from_object_signal(&emitter, "signal_0_arg"));
+
+    let invalid = Signal::invalid();
+    assert!(invalid.is_null());
+    assert_eq!(invalid.object(), None);
+    assert_eq!(invalid.object_id(), None);
+
+    emitter.free();
+}
+
+#[itest]
+fn signal_builtin_connect_emit() {
+    let emitter = Gd::<Emitter>::new_default();
+    let receiver = Gd::<Receiver>::new_default();
+    let signal = Signal::from_object_signal(&emitter, "signal_1_arg");
+    let callable = receiver.callable("receive_1_arg");
+
+    assert!(!signal.is_connected(callable.clone()));
+    assert_eq!(signal.connect(callable.clone(), 0), global::Error::OK);
+    assert!(signal.is_connected(callable.clone()));
+    assert_eq!(signal.connections().len(), 1);
+
+    signal.emit(&[987.to_variant()]);
+    assert!(receiver.bind().used[1].get());
+
+    signal.disconnect(callable.clone());
+    assert!(!signal.is_connected(callable));
+    assert!(signal.connections().is_empty());
+
+    receiver.free();
+    emitter.free();
+}
+
+#[itest]
+fn signal_builtin_variant() {
+    let emitter = Gd::<Emitter>::new_default();
+    let signal = Signal::from_object_signal(&emitter, "signal_0_arg");
+
+    let variant = signal.to_variant();
+    assert_eq!(variant.get_type(), VariantType::Signal);
+    assert_eq!(Signal::from_variant(&variant), signal);
+    assert!(format!("{signal:?}").contains("signal_0_arg"));
+
+    emitter.free();
+}
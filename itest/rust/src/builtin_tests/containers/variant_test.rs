@@ -345,7 +345,7 @@ fn variant_type_correct() {
         VariantType::Array
     );
     assert_eq!(
-        Dictionary::default().to_variant().get_type(),
+        <Dictionary>::default().to_variant().get_type(),
         VariantType::Dictionary
     );
 }
@@ -162,3 +162,30 @@ fn deg_to_rad(rotation: Vector3) -> Vector3 {
         rotation.z.to_radians(),
     )
 }
+
+#[itest]
+fn basis_looking_at_same() {
+    let up = Vector3::new(0.1, 1.0, -0.2);
+
+    for target in [
+        Vector3::new(0.0, 0.0, -1.0),
+        Vector3::new(3.0, 0.5, 2.0),
+        Vector3::new(-1.0, -4.0, 0.25),
+    ] {
+        #[cfg(before_api = "4.1")]
+        assert_eq_approx!(
+            Basis::new_looking_at(target, up),
+            InnerBasis::looking_at(target, up),
+            "looking_at({target}, {up})"
+        );
+
+        #[cfg(since_api = "4.1")]
+        for use_model_front in [false, true] {
+            assert_eq_approx!(
+                Basis::new_looking_at(target, up, use_model_front),
+                InnerBasis::looking_at(target, up, use_model_front),
+                "looking_at({target}, {up}, {use_model_front})"
+            );
+        }
+    }
+}
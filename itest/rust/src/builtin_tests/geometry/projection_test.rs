@@ -10,7 +10,7 @@ use crate::framework::itest;
 
 use godot::builtin::inner::InnerProjection;
 use godot::builtin::math::assert_eq_approx;
-use godot::builtin::{real, Projection, RealConv, Vector2};
+use godot::builtin::{real, Projection, ProjectionPlane, RealConv, Vector2};
 
 #[itest]
 fn test_create_orthogonal() {
@@ -344,3 +344,88 @@ fn test_projection_combined() {
         }
     }
 }
+
+#[itest]
+fn test_projection_getters_match_engine() {
+    let projections = [
+        Projection::create_perspective(90.0, 1.0, 1.0, 2.0, false),
+        Projection::create_perspective(45.0, 16.0 / 9.0, 0.05, 100.0, true),
+        Projection::create_frustum(-0.1, 0.3, -0.025, 0.05, 0.05, 100.0),
+        Projection::create_orthogonal(-2.0, 2.0, -1.0, 1.0, 0.5, 10.0),
+        Projection::create_orthogonal_aspect(10.0, 4.0 / 3.0, 0.0, 50.0, false),
+    ];
+
+    let planes = [
+        ProjectionPlane::Near,
+        ProjectionPlane::Far,
+        ProjectionPlane::Left,
+        ProjectionPlane::Top,
+        ProjectionPlane::Right,
+        ProjectionPlane::Bottom,
+    ];
+
+    for proj in projections {
+        let inner = InnerProjection::from_outer(&proj);
+
+        assert_eq_approx!(
+            proj.z_near(),
+            real::from_f64(inner.get_z_near()),
+            "z_near: {proj}"
+        );
+        assert_eq_approx!(
+            proj.z_far(),
+            real::from_f64(inner.get_z_far()),
+            "z_far: {proj}"
+        );
+        assert_eq_approx!(
+            proj.aspect(),
+            real::from_f64(inner.get_aspect()),
+            "aspect: {proj}"
+        );
+        assert_eq_approx!(
+            proj.lod_multiplier(),
+            real::from_f64(inner.get_lod_multiplier()),
+            "lod_multiplier: {proj}"
+        );
+        assert_eq_approx!(
+            proj.viewport_half_extents(),
+            inner.get_viewport_half_extents(),
+            "viewport_half_extents: {proj}"
+        );
+        assert_eq_approx!(
+            proj.far_plane_half_extents(),
+            inner.get_far_plane_half_extents(),
+            "far_plane_half_extents: {proj}"
+        );
+        assert_eq!(
+            proj.pixels_per_meter(1024),
+            inner.get_pixels_per_meter(1024),
+            "pixels_per_meter: {proj}"
+        );
+
+        for plane in planes {
+            assert_eq_approx!(
+                proj.projection_plane(plane),
+                inner.get_projection_plane(plane as i64),
+                "projection_plane {plane:?}: {proj}"
+            );
+        }
+
+        if !proj.is_orthogonal() {
+            assert_eq_approx!(proj.fov(), real::from_f64(inner.get_fov()), "fov: {proj}");
+            assert_eq_approx!(
+                proj.perspective_znear_adjusted(0.5),
+                inner.perspective_znear_adjusted(0.5),
+                "perspective_znear_adjusted: {proj}"
+            );
+        }
+    }
+
+    for (fov_x, aspect) in [(90.0, 1.0), (60.0, 16.0 / 9.0), (110.0, 0.5)] {
+        assert_eq_approx!(
+            Projection::fovy_of(fov_x, aspect),
+            real::from_f64(InnerProjection::get_fovy(fov_x.as_f64(), aspect.as_f64())),
+            "fovy_of: fov_x={fov_x} aspect={aspect}"
+        );
+    }
+}
@@ -31,3 +31,7 @@ mod string {
 }
 
 mod color_test;
+
+godot::private::cfg_serde! {
+    mod serde_test;
+}
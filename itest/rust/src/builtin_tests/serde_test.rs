@@ -0,0 +1,149 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::fmt::Debug;
+
+use godot::builtin::{
+    array, dict, varray, Array, Color, Dictionary, GodotString, NodePath, PackedByteArray,
+    PackedColorArray, PackedInt32Array, PackedStringArray, PackedVector2Array, Rid, StringName,
+    ToVariant, Variant, Vector2,
+};
+use godot::engine::Node;
+use serde::{Deserialize, Serialize};
+
+use crate::framework::itest;
+
+fn roundtrip<T>(value: &T, expected_json: &str)
+where
+    T: for<'a> Deserialize<'a> + Serialize + PartialEq + Debug,
+{
+    let json = serde_json::to_string(value).unwrap();
+    let back: T = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(back, *value, "serde round-trip changes value");
+    assert_eq!(
+        json, expected_json,
+        "value does not conform to expected JSON"
+    );
+}
+
+#[itest]
+fn serde_strings() {
+    roundtrip(&GodotString::from("hello ünïcödé"), "\"hello ünïcödé\"");
+    roundtrip(&StringName::from("some_name"), "\"some_name\"");
+    roundtrip(
+        &NodePath::from("../Parent/Child:position"),
+        "\"../Parent/Child:position\"",
+    );
+}
+
+#[itest]
+fn serde_rid() {
+    roundtrip(&Rid::new(17), "17");
+    roundtrip(&Rid::Invalid, "0");
+}
+
+#[itest]
+fn serde_typed_array() {
+    roundtrip(&array![1, 2, 3], "[1,2,3]");
+    roundtrip(&Array::<GodotString>::new(), "[]");
+    roundtrip(
+        &array![GodotString::from("a"), GodotString::from("b")],
+        "[\"a\",\"b\"]",
+    );
+}
+
+#[itest]
+fn serde_packed_arrays() {
+    roundtrip(&PackedByteArray::from(&[0, 1, 255]), "[0,1,255]");
+    roundtrip(&PackedInt32Array::from(&[-7, 42]), "[-7,42]");
+    roundtrip(
+        &PackedStringArray::from(&[GodotString::from("x"), GodotString::from("y")]),
+        "[\"x\",\"y\"]",
+    );
+    roundtrip(
+        &PackedVector2Array::from(&[Vector2::new(1.0, 2.0)]),
+        "[{\"x\":1.0,\"y\":2.0}]",
+    );
+    roundtrip(
+        &PackedColorArray::from(&[Color::from_rgba(1.0, 0.5, 0.0, 1.0)]),
+        "[{\"r\":1.0,\"g\":0.5,\"b\":0.0,\"a\":1.0}]",
+    );
+}
+
+#[itest]
+fn serde_variant() {
+    roundtrip(&Variant::nil(), "\"Nil\"");
+    roundtrip(&true.to_variant(), "{\"Bool\":true}");
+    roundtrip(&7.to_variant(), "{\"Int\":7}");
+    roundtrip(&1.5.to_variant(), "{\"Float\":1.5}");
+    roundtrip(&"text".to_variant(), "{\"String\":\"text\"}");
+    roundtrip(
+        &StringName::from("name").to_variant(),
+        "{\"StringName\":\"name\"}",
+    );
+    roundtrip(
+        &Vector2::new(1.0, -2.0).to_variant(),
+        "{\"Vector2\":{\"x\":1.0,\"y\":-2.0}}",
+    );
+    roundtrip(
+        &varray![1, "two", Variant::nil()].to_variant(),
+        "{\"Array\":[{\"Int\":1},{\"String\":\"two\"},\"Nil\"]}",
+    );
+    roundtrip(
+        &PackedInt32Array::from(&[3, 4]).to_variant(),
+        "{\"PackedInt32Array\":[3,4]}",
+    );
+}
+
+#[itest]
+fn serde_dictionary() {
+    roundtrip(&Dictionary::new(), "[]");
+    roundtrip(
+        &dict! { "key": 1, 2: varray![true] },
+        "[[{\"String\":\"key\"},{\"Int\":1}],[{\"Int\":2},{\"Array\":[{\"Bool\":true}]}]]",
+    );
+}
+
+#[itest]
+fn serde_variant_non_self_describing() {
+    // bincode identifies enum variants by index and needs sequence lengths upfront.
+    let values = [
+        Variant::nil(),
+        true.to_variant(),
+        "text".to_variant(),
+        Vector2::new(1.0, -2.0).to_variant(),
+        varray![1, "two", Variant::nil()].to_variant(),
+        dict! { "key": 1, 2: varray![true] }.to_variant(),
+        PackedColorArray::from(&[Color::from_rgba(1.0, 0.5, 0.0, 1.0)]).to_variant(),
+    ];
+
+    for value in values {
+        let bytes = bincode::serialize(&value).unwrap();
+        let back: Variant = bincode::deserialize(&bytes).unwrap();
+
+        assert_eq!(back, value, "bincode round-trip changes value");
+    }
+}
+
+#[itest]
+fn serde_variant_object_fails() {
+    let node = Node::new_alloc();
+    let result = serde_json::to_string(&node.to_variant());
+    node.free();
+
+    let err = result.expect_err("objects must not be serializable");
+    assert!(err.to_string().contains("Object"), "error message: {err}");
+}
+
+#[itest]
+fn serde_variant_unknown_tag_fails() {
+    let result = serde_json::from_str::<Variant>("{\"Octarine\":1}");
+    assert!(result.is_err());
+
+    let result = serde_json::from_str::<Variant>("{\"Int\":\"not an int\"}");
+    assert!(result.is_err());
+}
@@ -0,0 +1,179 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use godot::engine::{Engine, SceneTree};
+use godot::prelude::*;
+use godot::task;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=Object)]
+struct AsyncEmitter {
+    finish_count: i64,
+    #[base]
+    base: Base<Object>,
+}
+
+#[godot_api]
+impl AsyncEmitter {
+    #[signal]
+    fn finished(value: i64);
+
+    fn finish(&mut self, value: i64) {
+        self.finish_count += 1;
+        self.base
+            .emit_signal("finished".into(), &[value.to_variant()]);
+    }
+}
+
+/// Emits the scene tree's `process_frame` signal, at which the runtime resumes woken tasks.
+fn process_frame() {
+    let mut tree = Engine::singleton()
+        .get_main_loop()
+        .expect("main loop")
+        .cast::<SceneTree>();
+
+    tree.emit_signal("process_frame".into(), &[]);
+}
+
+#[itest]
+fn task_await_signal() {
+    let mut emitter = Gd::<AsyncEmitter>::new_default();
+    let result = Rc::new(Cell::new(None));
+
+    let signal = emitter.signal("finished");
+    let result_in_task = result.clone();
+    let handle = task::spawn(async move {
+        let args = signal.await;
+        result_in_task.set(Some(args[0].to::<i64>()));
+    });
+
+    assert!(handle.is_pending());
+    assert_eq!(result.get(), None);
+
+    // Woken by the emission, but only resumed in the next frame.
+    emitter.emit_signal("finished".into(), &[42.to_variant()]);
+    assert_eq!(result.get(), None);
+    assert!(handle.is_pending());
+
+    process_frame();
+    assert_eq!(result.get(), Some(42));
+    assert!(!handle.is_pending());
+
+    emitter.free();
+}
+
+#[itest]
+fn task_runs_until_first_await() {
+    let emitter = Gd::<AsyncEmitter>::new_default();
+    let steps = Rc::new(Cell::new(0));
+
+    let signal = emitter.signal("finished");
+    let steps_in_task = steps.clone();
+    let handle = task::spawn(async move {
+        steps_in_task.set(1);
+        signal.await;
+        steps_in_task.set(2);
+    });
+
+    assert_eq!(steps.get(), 1);
+
+    handle.cancel();
+    assert_eq!(steps.get(), 1);
+
+    emitter.free();
+}
+
+#[itest]
+fn task_cancel_disconnects() {
+    let emitter = Gd::<AsyncEmitter>::new_default();
+    let signal = emitter.signal("finished");
+
+    let awaited = signal.clone();
+    let handle = task::spawn(async move {
+        awaited.await;
+        panic!("cancelled task must not resume");
+    });
+
+    assert_eq!(signal.connections().len(), 1);
+    handle.cancel();
+    assert_eq!(signal.connections().len(), 0);
+
+    emitter.free();
+}
+
+#[itest]
+fn task_await_signal_of_freed_object() {
+    let emitter = Gd::<AsyncEmitter>::new_default();
+    let resumed = Rc::new(Cell::new(false));
+
+    let signal = emitter.signal("finished");
+    let resumed_in_task = resumed.clone();
+    let handle = task::spawn(async move {
+        signal.await;
+        resumed_in_task.set(true);
+    });
+
+    assert!(handle.is_pending());
+
+    // Freeing the object drops its connections, which wakes up the future. It panics, terminating the task instead of hanging.
+    emitter.free();
+    process_frame();
+    assert!(!handle.is_pending());
+    assert!(!resumed.get());
+}
+
+#[itest]
+fn task_resumes_outside_of_emission() {
+    let mut emitter = Gd::<AsyncEmitter>::new_default();
+    let resumed = Rc::new(Cell::new(false));
+
+    let signal = emitter.signal("finished");
+    let emitter_in_task = emitter.clone();
+    let resumed_in_task = resumed.clone();
+    let handle = task::spawn(async move {
+        signal.await;
+
+        // Would panic if the task was resumed inside finish(), which holds a bind_mut() guard while emitting.
+        assert_eq!(emitter_in_task.bind().finish_count, 1);
+        resumed_in_task.set(true);
+    });
+
+    emitter.bind_mut().finish(7);
+    assert!(handle.is_pending());
+
+    process_frame();
+    assert!(resumed.get());
+    assert!(!handle.is_pending());
+
+    emitter.free();
+}
+
+#[itest]
+fn task_await_next_frame() {
+    let frames = Rc::new(Cell::new(0));
+
+    let frames_in_task = frames.clone();
+    let handle = task::spawn(async move {
+        task::next_frame().await;
+        frames_in_task.set(1);
+        task::next_frame().await;
+        frames_in_task.set(2);
+    });
+
+    assert_eq!(frames.get(), 0);
+
+    process_frame();
+    assert_eq!(frames.get(), 1);
+
+    process_frame();
+    assert_eq!(frames.get(), 2);
+    assert!(!handle.is_pending());
+}
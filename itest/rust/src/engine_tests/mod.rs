@@ -4,6 +4,9 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+#[cfg(since_api = "4.2")]
+mod async_test;
 mod native_structures_test;
 mod node_test;
+mod script_instance_test;
 mod utilities_test;
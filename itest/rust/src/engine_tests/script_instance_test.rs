@@ -0,0 +1,201 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use std::ffi::c_void;
+
+use godot::bind::{godot_api, GodotClass};
+use godot::builtin::meta::{ClassName, PropertyInfo};
+use godot::builtin::{GodotString, StringName, ToVariant, Variant, VariantType};
+use godot::engine::global::{MethodFlags, PropertyHint, PropertyUsageFlags};
+use godot::engine::notify::ObjectNotification;
+use godot::engine::{
+    create_script_instance, Engine, Object, Script, ScriptExtension, ScriptExtensionVirtual,
+    ScriptInstance, ScriptLanguage, ScriptMethodInfo,
+};
+use godot::obj::{Base, Gd, Share};
+use godot::sys;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, base=ScriptExtension)]
+struct TestScript {
+    #[base]
+    base: Base<ScriptExtension>,
+}
+
+#[godot_api]
+impl ScriptExtensionVirtual for TestScript {
+    fn can_instantiate(&self) -> bool {
+        true
+    }
+
+    fn instance_create(&self, _for_object: Gd<Object>) -> *mut c_void {
+        let script = self.base.share().upcast::<Script>();
+        create_script_instance(TestScriptInstance::new(script))
+    }
+}
+
+struct TestScriptInstance {
+    script: Gd<Script>,
+    script_property_b: bool,
+    notifications: Vec<i32>,
+    properties: Vec<PropertyInfo>,
+    methods: Vec<ScriptMethodInfo>,
+}
+
+impl TestScriptInstance {
+    fn new(script: Gd<Script>) -> Self {
+        Self {
+            script,
+            script_property_b: true,
+            notifications: Vec::new(),
+            properties: vec![property_info("script_property_b", VariantType::Bool)],
+            methods: vec![ScriptMethodInfo {
+                id: 1,
+                method_name: StringName::from("script_method_a"),
+                class_name: ClassName::none(),
+                return_type: property_info("", VariantType::String),
+                arguments: vec![
+                    property_info("arg_a", VariantType::String),
+                    property_info("arg_b", VariantType::Int),
+                ],
+                default_arguments: vec![],
+                flags: MethodFlags::METHOD_FLAGS_DEFAULT,
+            }],
+        }
+    }
+}
+
+fn property_info(name: &str, variant_type: VariantType) -> PropertyInfo {
+    PropertyInfo {
+        variant_type,
+        class_name: ClassName::none(),
+        property_name: StringName::from(name),
+        hint: PropertyHint::PROPERTY_HINT_NONE,
+        hint_string: GodotString::new(),
+        usage: PropertyUsageFlags::PROPERTY_USAGE_DEFAULT,
+    }
+}
+
+impl ScriptInstance for TestScriptInstance {
+    fn class_name(&self) -> GodotString {
+        GodotString::from("TestScript")
+    }
+
+    fn set(&mut self, name: StringName, value: &Variant) -> bool {
+        if name.to_string() != "script_property_b" {
+            return false;
+        }
+
+        self.script_property_b = value.to();
+        true
+    }
+
+    fn get(&self, name: StringName) -> Option<Variant> {
+        match name.to_string().as_str() {
+            "script_property_b" => Some(self.script_property_b.to_variant()),
+            "notification_count" => Some((self.notifications.len() as i64).to_variant()),
+            _ => None,
+        }
+    }
+
+    fn get_property_list(&self) -> &[PropertyInfo] {
+        &self.properties
+    }
+
+    fn get_method_list(&self) -> &[ScriptMethodInfo] {
+        &self.methods
+    }
+
+    fn call(
+        &mut self,
+        method: StringName,
+        args: &[&Variant],
+    ) -> Result<Variant, sys::GDExtensionCallErrorType> {
+        match method.to_string().as_str() {
+            "script_method_a" => {
+                let arg_a = args[0].to::<GodotString>();
+                let arg_b = args[1].to::<i64>();
+
+                Ok(format!("{arg_a}{arg_b}").to_variant())
+            }
+            _ => Err(sys::GDEXTENSION_CALL_ERROR_INVALID_METHOD),
+        }
+    }
+
+    fn has_method(&self, method: StringName) -> bool {
+        method.to_string() == "script_method_a"
+    }
+
+    fn get_script(&self) -> &Gd<Script> {
+        &self.script
+    }
+
+    fn get_language(&self) -> Gd<ScriptLanguage> {
+        Engine::singleton()
+            .get_script_language(0)
+            .expect("at least one script language registered")
+    }
+
+    fn get_property_type(&self, name: StringName) -> Option<VariantType> {
+        (name.to_string() == "script_property_b").then_some(VariantType::Bool)
+    }
+
+    fn notification(&mut self, what: i32) {
+        self.notifications.push(what);
+    }
+}
+
+#[itest]
+fn script_instance_properties() {
+    let script = Gd::<TestScript>::new_default();
+    let mut object = Object::new_alloc();
+    object.set_script(script.to_variant());
+
+    let name = StringName::from("script_property_b");
+    assert_eq!(object.get(name.clone()), true.to_variant());
+
+    object.set(name.clone(), false.to_variant());
+    assert_eq!(object.get(name.clone()), false.to_variant());
+
+    let has_property = object
+        .get_property_list()
+        .iter_shared()
+        .any(|dict| dict.get("name") == Some(name.to_variant()));
+    assert!(has_property, "script property is listed");
+
+    object.free();
+}
+
+#[itest]
+fn script_instance_methods() {
+    let script = Gd::<TestScript>::new_default();
+    let mut object = Object::new_alloc();
+    object.set_script(script.to_variant());
+
+    let method = StringName::from("script_method_a");
+    assert!(object.has_method(method.clone()));
+
+    let result = object.call(method, &["test".to_variant(), 3.to_variant()]);
+    assert_eq!(result, "test3".to_variant());
+
+    object.free();
+}
+
+#[itest]
+fn script_instance_notification() {
+    let script = Gd::<TestScript>::new_default();
+    let mut object = Object::new_alloc();
+    object.set_script(script.to_variant());
+
+    let count_before = object.get("notification_count".into()).to::<i64>();
+    object.notify(ObjectNotification::Unknown(12345));
+    let count_after = object.get("notification_count".into()).to::<i64>();
+    assert_eq!(count_after, count_before + 1);
+
+    object.free();
+}
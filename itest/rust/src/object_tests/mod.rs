@@ -7,5 +7,8 @@
 mod base_test;
 mod object_test;
 mod property_test;
+mod reload_test;
+mod rust_base_test;
 mod singleton_test;
+mod thread_test;
 mod virtual_methods_test;
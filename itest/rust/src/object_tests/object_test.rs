@@ -8,8 +8,10 @@ use std::cell::{Cell, RefCell};
 use std::rc::Rc;
 
 use godot::bind::{godot_api, GodotClass};
+use godot::builtin::meta::CallError;
 use godot::builtin::{
-    FromVariant, GodotString, StringName, ToVariant, Variant, VariantConversionError, Vector3,
+    FromVariant, GodotString, StringName, ToVariant, Variant, VariantConversionError, VariantType,
+    Vector3,
 };
 use godot::engine::{
     file_access, Area2D, Camera3D, FileAccess, Node, Node3D, Object, RefCounted, RefCountedVirtual,
@@ -696,6 +698,130 @@ fn object_call_with_args() {
     node.free();
 }
 
+#[itest]
+fn object_try_call() {
+    let mut node = Node3D::new_alloc();
+
+    let expected_pos = Vector3::new(2.5, 6.42, -1.11);
+    let result = node.try_call(
+        StringName::from("set_position"),
+        &[expected_pos.to_variant()],
+    );
+
+    assert_eq!(result, Ok(Variant::nil()));
+    assert_eq!(node.get_position(), expected_pos);
+    node.free();
+}
+
+#[itest]
+fn object_try_call_errors() {
+    let mut node = Node3D::new_alloc();
+
+    let result = node.try_call(StringName::from("no_such_method"), &[]);
+    assert_eq!(result, Err(CallError::InvalidMethod));
+
+    let result = node.try_call(StringName::from("set_position"), &["text".to_variant()]);
+    assert_eq!(
+        result,
+        Err(CallError::InvalidArgument {
+            index: 0,
+            expected: VariantType::Vector3,
+        })
+    );
+
+    let result = node.try_call(StringName::from("set_position"), &[]);
+    assert_eq!(result, Err(CallError::TooFewArguments { expected: 1 }));
+
+    node.free();
+}
+
+#[itest]
+fn object_call_typed() {
+    let mut node = Node3D::new_alloc();
+
+    let expected_pos = Vector3::new(2.5, 6.42, -1.11);
+    let result = node.call_typed::<Variant, _, _>("set_position", (expected_pos,));
+    assert_eq!(result, Ok(Variant::nil()));
+
+    let pos: Vector3 = node.call_typed("get_position", ()).unwrap();
+    assert_eq!(pos, expected_pos);
+
+    let args = [expected_pos.to_variant()];
+    let result = node.call_typed::<Variant, _, _>("set_position", &args[..]);
+    assert_eq!(result, Ok(Variant::nil()));
+
+    node.free();
+}
+
+#[itest]
+fn object_call_typed_errors() {
+    let mut node = Node3D::new_alloc();
+
+    let result = node.call_typed::<Variant, _, _>("no_such_method", ());
+    assert_eq!(result, Err(CallError::InvalidMethod));
+
+    let result = node.call_typed::<Variant, _, _>("set_position", ("text",));
+    assert_eq!(
+        result,
+        Err(CallError::InvalidArgument {
+            index: 0,
+            expected: VariantType::Vector3,
+        })
+    );
+
+    let result = node.call_typed::<i64, _, _>("get_position", ());
+    assert_eq!(
+        result,
+        Err(CallError::InvalidReturnValue {
+            actual: VariantType::Vector3
+        })
+    );
+
+    node.free();
+}
+
+#[itest]
+fn object_get_as_set_as() {
+    let mut node = Node3D::new_alloc();
+
+    let expected_pos = Vector3::new(-1.0, 2.0, 0.5);
+    node.set_as("position", expected_pos);
+    assert_eq!(node.get_position(), expected_pos);
+    assert_eq!(node.get_as::<Vector3, _>("position"), Ok(expected_pos));
+
+    node.set_as("name", "Renamed");
+    assert_eq!(
+        node.get_as::<GodotString, _>("name"),
+        Ok(GodotString::from("Renamed"))
+    );
+
+    assert_eq!(
+        node.get_as::<i64, _>("position"),
+        Err(VariantConversionError::BadType)
+    );
+    assert_eq!(
+        node.get_as::<Variant, _>("no_such_property"),
+        Ok(Variant::nil())
+    );
+
+    node.free();
+}
+
+#[itest]
+fn object_connect_obj() {
+    let double_use: Gd<DoubleUse> = Gd::new_default();
+    let mut emitter: Gd<SignalEmitter> = Gd::new_default();
+
+    let result = emitter.connect_obj("do_use", &double_use, "use_1");
+    assert_eq!(result, Ok(()));
+
+    emitter.emit_signal("do_use".into(), &[]);
+    assert!(double_use.bind().used.get(), "use_1 was not called");
+
+    double_use.free();
+    emitter.free();
+}
+
 #[itest]
 fn object_get_scene_tree(ctx: &TestContext) {
     let node = Node3D::new_alloc();
@@ -6,7 +6,10 @@
 
 use godot::{
     bind::property::ExportInfo,
-    engine::{global::PropertyHint, Texture},
+    engine::{
+        global::{PropertyHint, PropertyUsageFlags},
+        Node3D, Resource, Texture,
+    },
     prelude::*,
     test::itest,
 };
@@ -351,10 +354,7 @@ fn derive_export() {
         .iter_shared()
         .find(|c| c.get_or_nil("name") == "foo".to_variant())
         .unwrap();
-    assert_eq!(
-        property.get_or_nil("class_name"),
-        "DeriveExport".to_variant()
-    );
+    assert_eq!(property.get_or_nil("class_name"), "".to_variant());
     assert_eq!(
         property.get_or_nil("type"),
         (VariantType::Int as i32).to_variant()
@@ -368,3 +368,194 @@ fn derive_export() {
         "A:0,B:1,C:2".to_variant()
     );
 }
+
+#[derive(GodotClass)]
+#[class(init, base=Resource)]
+pub struct ExportedResource {
+    #[export]
+    pub value: i32,
+}
+
+#[godot_api]
+impl ExportedResource {}
+
+#[derive(GodotClass)]
+#[class(init)]
+pub struct ExportObjects {
+    #[export]
+    engine_resource: Option<Gd<Resource>>,
+
+    #[export]
+    user_resource: Option<Gd<ExportedResource>>,
+
+    #[export]
+    node: Option<Gd<Node3D>>,
+
+    #[export]
+    user_resources: Array<Gd<ExportedResource>>,
+
+    #[export]
+    nodes: Array<Gd<Node3D>>,
+}
+
+#[godot_api]
+impl ExportObjects {}
+
+fn find_property(object: &Gd<Object>, name: &str) -> Dictionary {
+    object
+        .get_property_list()
+        .iter_shared()
+        .find(|p| p.get_or_nil("name") == name.to_variant())
+        .unwrap_or_else(|| panic!("property `{name}` not found"))
+}
+
+fn check_object_property(
+    object: &Gd<Object>,
+    name: &str,
+    variant_type: VariantType,
+    class_name: &str,
+    hint: PropertyHint,
+    hint_string: &str,
+) {
+    let property = find_property(object, name);
+
+    assert_eq!(
+        property.get_or_nil("type"),
+        (variant_type as i32).to_variant(),
+        "type of `{name}`"
+    );
+    assert_eq!(
+        property.get_or_nil("class_name"),
+        class_name.to_variant(),
+        "class_name of `{name}`"
+    );
+    assert_eq!(
+        property.get_or_nil("hint"),
+        hint.ord().to_variant(),
+        "hint of `{name}`"
+    );
+    assert_eq!(
+        property.get_or_nil("hint_string"),
+        hint_string.to_variant(),
+        "hint_string of `{name}`"
+    );
+}
+
+#[itest]
+fn export_object_hints() {
+    let object = Gd::<ExportObjects>::new_default().upcast::<Object>();
+
+    check_object_property(
+        &object,
+        "engine_resource",
+        VariantType::Object,
+        "Resource",
+        PropertyHint::PROPERTY_HINT_RESOURCE_TYPE,
+        "Resource",
+    );
+    check_object_property(
+        &object,
+        "user_resource",
+        VariantType::Object,
+        "ExportedResource",
+        PropertyHint::PROPERTY_HINT_RESOURCE_TYPE,
+        "ExportedResource",
+    );
+    check_object_property(
+        &object,
+        "node",
+        VariantType::Object,
+        "Node3D",
+        PropertyHint::PROPERTY_HINT_NODE_TYPE,
+        "Node3D",
+    );
+}
+
+#[itest]
+fn export_object_array_hints() {
+    let object = Gd::<ExportObjects>::new_default().upcast::<Object>();
+
+    let resource_hint = PropertyHint::PROPERTY_HINT_RESOURCE_TYPE.ord();
+    let node_hint = PropertyHint::PROPERTY_HINT_NODE_TYPE.ord();
+    let object_type = VariantType::Object as i32;
+
+    check_object_property(
+        &object,
+        "user_resources",
+        VariantType::Array,
+        "",
+        PropertyHint::PROPERTY_HINT_TYPE_STRING,
+        &format!("{object_type}/{resource_hint}:ExportedResource"),
+    );
+    check_object_property(
+        &object,
+        "nodes",
+        VariantType::Array,
+        "",
+        PropertyHint::PROPERTY_HINT_TYPE_STRING,
+        &format!("{object_type}/{node_hint}:Node3D"),
+    );
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+pub struct ExportGroups {
+    #[export_category(name = "Player")]
+    #[export_group(name = "Movement", prefix = "move_")]
+    #[export]
+    move_speed: f64,
+
+    #[export]
+    move_acceleration: f64,
+
+    #[export_subgroup(name = "Jumping", prefix = "jump_")]
+    #[export]
+    jump_height: f64,
+
+    #[export_group(name = "Combat")]
+    #[var]
+    health: i64,
+}
+
+#[godot_api]
+impl ExportGroups {}
+
+#[itest]
+fn export_groups() {
+    let object = Gd::<ExportGroups>::new_default();
+
+    let section_flags = PropertyUsageFlags::PROPERTY_USAGE_CATEGORY.ord()
+        | PropertyUsageFlags::PROPERTY_USAGE_GROUP.ord()
+        | PropertyUsageFlags::PROPERTY_USAGE_SUBGROUP.ord();
+
+    let properties: Vec<(GodotString, i32, GodotString)> = object
+        .get_property_list()
+        .iter_shared()
+        .map(|p| {
+            (
+                p.get_or_nil("name").to(),
+                p.get_or_nil("usage").to::<i32>() & section_flags,
+                p.get_or_nil("hint_string").to(),
+            )
+        })
+        .skip_while(|(name, _, _)| name != &GodotString::from("Player"))
+        .take(7)
+        .collect();
+
+    let category = PropertyUsageFlags::PROPERTY_USAGE_CATEGORY.ord();
+    let group = PropertyUsageFlags::PROPERTY_USAGE_GROUP.ord();
+    let subgroup = PropertyUsageFlags::PROPERTY_USAGE_SUBGROUP.ord();
+
+    let expected = [
+        ("Player", category, ""),
+        ("Movement", group, "move_"),
+        ("move_speed", 0, ""),
+        ("move_acceleration", 0, ""),
+        ("Jumping", subgroup, "jump_"),
+        ("jump_height", 0, ""),
+        ("Combat", group, ""),
+    ]
+    .map(|(name, usage, prefix)| (GodotString::from(name), usage, GodotString::from(prefix)));
+
+    assert_eq!(properties, expected);
+}
@@ -0,0 +1,79 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::engine::ClassDb;
+use godot::obj::Reloadable;
+use godot::prelude::*;
+
+#[derive(GodotClass)]
+#[class(init, reloadable, base=Node)]
+struct ReloadableNode {
+    #[var]
+    counter: i64,
+    recreated: bool,
+
+    #[base]
+    base: Base<Node>,
+}
+
+impl Reloadable for ReloadableNode {
+    fn recreate(base: Base<Node>) -> Self {
+        Self {
+            counter: 0,
+            recreated: true,
+            base,
+        }
+    }
+}
+
+#[itest]
+fn reload_fresh_instance_uses_init() {
+    let obj = Gd::<ReloadableNode>::new_alloc();
+    assert!(!obj.bind().recreated);
+
+    obj.free();
+}
+
+#[itest]
+fn reload_unregister_and_register_class() {
+    let class_name = ReloadableNode::class_name();
+    let class_db = ClassDb::singleton();
+
+    // Same steps as unloading and loading the library, restricted to one class.
+    godot::private::unregister_class(class_name);
+    assert!(!class_db.class_exists(class_name.to_string_name()));
+
+    godot::private::register_class_from_plugins(class_name);
+    assert!(class_db.class_exists(class_name.to_string_name()));
+
+    // Methods and properties are registered again, too.
+    let mut obj = Gd::<ReloadableNode>::new_alloc();
+    obj.set("counter".into(), 3.to_variant());
+    assert_eq!(obj.bind().counter, 3);
+    assert!(!obj.bind().recreated);
+
+    obj.free();
+}
+
+#[itest]
+fn reload_recreate_callback_attaches_instance() {
+    // Simulate what Godot does after a hot reload: attach a new Rust instance to an already existing object.
+    let node = Node::new_alloc();
+    let id = node.instance_id();
+
+    unsafe {
+        godot::private::callbacks::recreate::<ReloadableNode>(std::ptr::null_mut(), node.obj_sys());
+    }
+
+    let mut obj = Gd::<ReloadableNode>::from_instance_id(id);
+    assert!(obj.bind().recreated);
+
+    obj.bind_mut().counter = 7;
+    assert_eq!(obj.get("counter".into()), 7.to_variant());
+
+    obj.free();
+}
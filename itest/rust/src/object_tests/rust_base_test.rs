@@ -0,0 +1,147 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::notify::NodeNotification;
+use godot::engine::{ClassDb, Node2DVirtual};
+use godot::prelude::*;
+
+use crate::framework::{itest, TestContext};
+
+#[derive(GodotClass)]
+#[class(base=Node2D)]
+struct RustBaseEnemy {
+    #[var]
+    health: i64,
+    ready_calls: i64,
+
+    #[base]
+    base: Base<Node2D>,
+}
+
+#[godot_api]
+impl RustBaseEnemy {
+    #[func]
+    fn take_damage(&mut self, amount: i64) {
+        self.health -= amount;
+    }
+}
+
+#[godot_api]
+impl Node2DVirtual for RustBaseEnemy {
+    fn init(base: Base<Node2D>) -> Self {
+        Self {
+            health: 100,
+            ready_calls: 0,
+            base,
+        }
+    }
+
+    fn to_string(&self) -> GodotString {
+        format!("RustBaseEnemy(health={})", self.health).into()
+    }
+
+    fn ready(&mut self) {
+        self.ready_calls += 1;
+    }
+}
+
+#[derive(GodotClass)]
+#[class(init, rust_base=RustBaseEnemy)]
+struct RustBaseBoss {
+    #[var]
+    armor: i64,
+    #[var]
+    processed: bool,
+
+    #[base]
+    base: Base<RustBaseEnemy>,
+}
+
+#[godot_api]
+impl RustBaseBoss {
+    #[func]
+    fn base_health(&self) -> i64 {
+        self.base.bind().health
+    }
+}
+
+#[godot_api]
+impl Node2DVirtual for RustBaseBoss {
+    fn process(&mut self, _delta: f64) {
+        self.processed = true;
+    }
+}
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+#[itest]
+fn rust_base_class_hierarchy() {
+    let class_db = ClassDb::singleton();
+    let boss_name = RustBaseBoss::class_name().to_string_name();
+
+    assert_eq!(
+        class_db.get_parent_class(boss_name.clone()),
+        RustBaseEnemy::class_name().to_string_name()
+    );
+    assert!(class_db.is_parent_class(boss_name, Node2D::class_name().to_string_name()));
+}
+
+#[itest]
+fn rust_base_upcast_downcast() {
+    let boss = Gd::<RustBaseBoss>::new_default();
+    let id = boss.instance_id();
+
+    let enemy = boss.upcast::<RustBaseEnemy>();
+    assert_eq!(enemy.bind().health, 100);
+
+    let node = enemy.upcast::<Node>();
+    let boss = node.cast::<RustBaseBoss>();
+    assert_eq!(boss.instance_id(), id);
+
+    boss.free();
+}
+
+#[itest]
+fn rust_base_state() {
+    let mut boss = Gd::<RustBaseBoss>::new_default();
+    assert_eq!(boss.bind().base_health(), 100);
+
+    // Methods and properties of the base class operate on the base's fields.
+    boss.call("take_damage".into(), &[30.to_variant()]);
+    assert_eq!(boss.get("health".into()), 70.to_variant());
+    assert_eq!(boss.call("base_health".into(), &[]), 70.to_variant());
+
+    boss.set("armor".into(), 5.to_variant());
+    assert_eq!(boss.bind().armor, 5);
+
+    boss.bind().base.clone().bind_mut().health = 10;
+    assert_eq!(boss.bind().base_health(), 10);
+
+    boss.free();
+}
+
+#[itest]
+fn rust_base_inherited_callbacks(test_context: &TestContext) {
+    let boss = Gd::<RustBaseBoss>::new_default();
+
+    // Not overridden by the derived class.
+    assert_eq!(boss.to_string(), "RustBaseEnemy(health=100)");
+
+    // Overridden virtual methods of the derived class don't hide the ones of the base class.
+    let mut scene_tree = test_context.scene_tree.clone();
+    scene_tree.add_child(boss.clone().upcast());
+    assert_eq!(boss.clone().upcast::<RustBaseEnemy>().bind().ready_calls, 1);
+
+    // A process notification (as sent each frame) reaches the `process` override of the derived class.
+    assert!(!boss.bind().processed);
+    boss.clone()
+        .upcast::<Node>()
+        .notify(NodeNotification::Process);
+    assert!(boss.bind().processed);
+
+    scene_tree.remove_child(boss.clone().upcast());
+    boss.free();
+}
@@ -0,0 +1,82 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use crate::framework::itest;
+use godot::prelude::*;
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct ThreadedObj {
+    value: i64,
+}
+
+#[godot_api]
+impl ThreadedObj {
+    #[func]
+    fn increment(&mut self) {
+        self.value += 1;
+    }
+}
+
+godot::private::cfg_threads! {
+    !
+    #[itest]
+    fn thread_bind_outside_main_thread_panics() {
+        let obj = Gd::<ThreadedObj>::new_default();
+        let id = obj.instance_id();
+
+        // Gd<T> is not Send without the `threads` feature, so only the ID can cross threads.
+        let result = std::thread::spawn(move || {
+            let obj = Gd::<ThreadedObj>::from_instance_id(id);
+            let value = obj.bind().value;
+            value
+        })
+        .join();
+
+        assert!(result.is_err(), "bind() on non-main thread must panic");
+
+        // Instance is still usable on the main thread.
+        assert_eq!(obj.bind().value, 0);
+    }
+}
+
+godot::private::cfg_threads! {
+    #[itest]
+    fn thread_gd_send_to_rust_thread() {
+        let obj = Gd::<ThreadedObj>::new_default();
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let mut obj = obj.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..100 {
+                        obj.bind_mut().increment();
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(obj.bind().value, 400);
+    }
+
+    #[itest]
+    fn thread_worker_thread_pool() {
+        use godot::engine::WorkerThreadPool;
+
+        let obj = Gd::<ThreadedObj>::new_default();
+        let mut pool = WorkerThreadPool::singleton();
+
+        // GDScript-style invocation: Godot calls the #[func] on one of its worker threads.
+        let task_id = pool.add_task(obj.callable("increment"));
+        pool.wait_for_task_completion(task_id);
+
+        assert_eq!(obj.bind().value, 1);
+    }
+}
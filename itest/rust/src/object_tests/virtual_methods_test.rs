@@ -9,11 +9,13 @@
 use crate::framework::{itest, TestContext};
 
 use godot::bind::{godot_api, GodotClass};
+use godot::builtin::meta::{ClassName, PropertyInfo};
 use godot::builtin::{
     real, varray, Color, GodotString, PackedByteArray, PackedColorArray, PackedFloat32Array,
     PackedInt32Array, PackedStringArray, PackedVector2Array, PackedVector3Array, RealConv,
-    StringName, ToVariant, Variant, VariantArray, Vector2, Vector3,
+    StringName, ToVariant, Variant, VariantArray, VariantType, Vector2, Vector3,
 };
+use godot::engine::global::{PropertyHint, PropertyUsageFlags};
 use godot::engine::notify::NodeNotification;
 use godot::engine::resource_loader::CacheMode;
 use godot::engine::{
@@ -488,3 +490,102 @@ impl CollisionObject2DTest {
             .unwrap_or(Variant::nil())
     }
 }
+
+// ----------------------------------------------------------------------------------------------------------------------------------------------
+
+/// Exposes one integer property per entry in `values`, with `0` as the revert value.
+#[derive(GodotClass)]
+#[class(init, base = RefCounted)]
+struct DynamicPropertiesTest {
+    values: Vec<(String, i64)>,
+}
+
+impl DynamicPropertiesTest {
+    fn index_of(&self, property: &StringName) -> Option<usize> {
+        let property = property.to_string();
+        self.values.iter().position(|(name, _)| *name == property)
+    }
+}
+
+#[godot_api]
+impl RefCountedVirtual for DynamicPropertiesTest {
+    fn get_property(&self, property: StringName) -> Option<Variant> {
+        let index = self.index_of(&property)?;
+        Some(self.values[index].1.to_variant())
+    }
+
+    fn set_property(&mut self, property: StringName, value: Variant) -> bool {
+        let Some(index) = self.index_of(&property) else {
+            return false;
+        };
+
+        self.values[index].1 = value.to();
+        true
+    }
+
+    fn get_property_list(&self) -> Vec<PropertyInfo> {
+        self.values
+            .iter()
+            .map(|(name, _)| PropertyInfo {
+                variant_type: VariantType::Int,
+                class_name: ClassName::none(),
+                property_name: name.as_str().into(),
+                hint: PropertyHint::PROPERTY_HINT_NONE,
+                hint_string: GodotString::new(),
+                usage: PropertyUsageFlags::PROPERTY_USAGE_DEFAULT,
+            })
+            .collect()
+    }
+
+    fn property_can_revert(&self, property: StringName) -> bool {
+        self.index_of(&property).is_some()
+    }
+
+    fn property_get_revert(&self, property: StringName) -> Option<Variant> {
+        self.index_of(&property).map(|_| 0.to_variant())
+    }
+}
+
+fn make_dynamic_properties() -> Gd<DynamicPropertiesTest> {
+    let mut obj = Gd::<DynamicPropertiesTest>::new_default();
+    obj.bind_mut().values = vec![("speed".to_string(), 5), ("armor".to_string(), 2)];
+    obj
+}
+
+#[itest]
+fn test_dynamic_property_get_set() {
+    let mut obj = make_dynamic_properties();
+
+    assert_eq!(obj.get_as::<i64, _>("speed"), Ok(5));
+    assert_eq!(obj.get_as::<i64, _>("armor"), Ok(2));
+
+    obj.set_as("speed", 12);
+    assert_eq!(obj.bind().values[0].1, 12);
+    assert_eq!(obj.get_as::<i64, _>("speed"), Ok(12));
+
+    // Unknown properties are handled normally by Godot.
+    assert_eq!(obj.get_as::<Variant, _>("unknown"), Ok(Variant::nil()));
+}
+
+#[itest]
+fn test_dynamic_property_list() {
+    let obj = make_dynamic_properties();
+
+    let names: Vec<GodotString> = obj
+        .get_property_list()
+        .iter_shared()
+        .map(|property| property.get_or_nil("name").to())
+        .collect();
+
+    assert!(names.contains(&"speed".into()), "names: {names:?}");
+    assert!(names.contains(&"armor".into()), "names: {names:?}");
+}
+
+#[itest]
+fn test_dynamic_property_revert() {
+    let obj = make_dynamic_properties();
+
+    assert!(obj.property_can_revert("armor".into()));
+    assert!(!obj.property_can_revert("unknown".into()));
+    assert_eq!(obj.property_get_revert("armor".into()), 0.to_variant());
+}
@@ -0,0 +1,56 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::ClassDb;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, abstract, base=Node)]
+struct AbstractEnemy {
+    #[var]
+    health: i64,
+
+    #[base]
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl AbstractEnemy {
+    #[func]
+    fn is_alive(&self) -> bool {
+        self.health > 0
+    }
+}
+
+// No constructor: cannot be instantiated at all.
+#[derive(GodotClass)]
+#[class(abstract, base=RefCounted)]
+struct AbstractWithoutInit {}
+
+#[itest]
+fn abstract_class_registered() {
+    let class_db = ClassDb::singleton();
+    let class_name = AbstractEnemy::class_name().to_string_name();
+
+    assert!(class_db.class_exists(class_name.clone()));
+    assert!(class_db.is_parent_class(class_name, Node::class_name().to_string_name()));
+
+    let class_name = AbstractWithoutInit::class_name().to_string_name();
+    assert!(class_db.class_exists(class_name.clone()));
+    assert!(class_db.is_parent_class(class_name, RefCounted::class_name().to_string_name()));
+}
+
+// Editor visibility (the `is_virtual` flag) is not exposed through the ClassDB API, so only instantiability is checked.
+#[itest]
+fn abstract_class_instantiable_only_with_init() {
+    let class_db = ClassDb::singleton();
+
+    // Subclasses still need to construct the base, so classes with a constructor remain instantiable.
+    assert!(class_db.can_instantiate(AbstractEnemy::class_name().to_string_name()));
+    assert!(!class_db.can_instantiate(AbstractWithoutInit::class_name().to_string_name()));
+}
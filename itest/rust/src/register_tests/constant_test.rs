@@ -111,6 +111,7 @@ impl ::godot::obj::cap::ImplementsGodotApi for HasOtherConstants {
             generated_register_fn: ::godot::private::ErasedRegisterFn {
                 raw: ::godot::private::callbacks::register_user_binds::<HasOtherConstants>,
             },
+            docs: ::godot::private::InherentImplDocs::default(),
         },
     }
 );
@@ -0,0 +1,73 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::ClassDb;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+/// A class with documentation on all registered items.
+///
+/// Contains `inline code` and a code block:
+/// ```gdscript
+/// var docs = DocumentedClass.new()
+/// ```
+#[derive(GodotClass)]
+#[class(init, base=Node)]
+struct DocumentedClass {
+    /// Property exposed via `#[var]`, with <special> & "escaped" characters.
+    #[var]
+    item: i64,
+
+    /// Property exposed via `#[export]`.
+    #[export]
+    label: GodotString,
+
+    /// Not registered with Godot, so not documented either.
+    _private: bool,
+
+    #[base]
+    base: Base<Node>,
+}
+
+#[godot_api]
+impl DocumentedClass {
+    /// Emitted when `item` changes.
+    #[signal]
+    fn item_changed(previous: i64);
+
+    /// Largest value that `item` can take.
+    #[constant]
+    const MAX_ITEM: i64 = 64;
+
+    /// Returns the sum of `item` and `offset`.
+    #[func]
+    fn offset_item(&self, offset: i64) -> i64 {
+        self.item + offset
+    }
+
+    #[func]
+    fn undocumented(&self) {}
+}
+
+#[itest]
+fn documented_class_registered() {
+    let mut obj = Gd::<DocumentedClass>::new_default();
+    assert!(obj.has_method("offset_item".into()));
+    assert!(obj.has_signal("item_changed".into()));
+
+    obj.set("item".into(), 5.to_variant());
+    let result = obj.call("offset_item".into(), &[3.to_variant()]);
+    assert_eq!(result, 8.to_variant());
+
+    let class_name = DocumentedClass::class_name().to_string_name();
+    assert_eq!(
+        ClassDb::singleton().class_get_integer_constant(class_name, "MAX_ITEM".into()),
+        DocumentedClass::MAX_ITEM
+    );
+
+    obj.free();
+}
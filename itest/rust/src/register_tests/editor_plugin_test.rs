@@ -0,0 +1,49 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::{ClassDb, EditorPlugin, Engine};
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init, editor_plugin, base=EditorPlugin)]
+struct TestEditorPlugin {
+    #[base]
+    base: Base<EditorPlugin>,
+}
+
+#[itest]
+fn editor_plugin_registered_only_in_editor() {
+    let in_editor = Engine::singleton().is_editor_hint();
+    let class_name = TestEditorPlugin::class_name();
+
+    // EditorPlugin classes are registered at the Editor init level, and skipped when running a game.
+    let class_db = ClassDb::singleton();
+    assert_eq!(
+        class_db.class_exists(class_name.to_string_name()),
+        in_editor
+    );
+    assert_eq!(
+        godot::private::editor_plugins().contains(&class_name),
+        in_editor
+    );
+
+    if in_editor {
+        assert!(class_db.is_parent_class(
+            class_name.to_string_name(),
+            EditorPlugin::class_name().to_string_name(),
+        ));
+    }
+
+    // Regular classes are not added to the editor.
+    assert!(!godot::private::editor_plugins().contains(&Node::class_name()));
+}
+
+#[itest]
+fn editor_plugin_is_tool() {
+    assert!(TestEditorPlugin::__config().is_tool);
+}
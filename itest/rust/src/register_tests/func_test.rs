@@ -4,8 +4,12 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+use godot::builtin::meta::CallError;
+use godot::engine::Script;
 use godot::prelude::*;
 
+use crate::framework::itest;
+
 #[derive(GodotClass)]
 #[class(base=RefCounted)]
 struct FuncRename;
@@ -46,3 +50,216 @@ impl RefCountedVirtual for FuncRename {
         Self
     }
 }
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncDefaults;
+
+#[godot_api]
+impl FuncDefaults {
+    #[func]
+    fn sum_with_defaults(
+        &self,
+        a: i64,
+        #[opt(default = 10)] b: i64,
+        #[opt(default = GodotString::from("!"))] suffix: GodotString,
+    ) -> GodotString {
+        format!("{}{}", a + b, suffix).into()
+    }
+}
+
+#[itest]
+fn func_default_params() {
+    let obj = Gd::<FuncDefaults>::new_default().to_variant();
+
+    let result = obj.call("sum_with_defaults", &[1.to_variant()]);
+    assert_eq!(result, "11!".to_variant());
+
+    let result = obj.call("sum_with_defaults", &[1.to_variant(), 2.to_variant()]);
+    assert_eq!(result, "3!".to_variant());
+
+    let result = obj.call(
+        "sum_with_defaults",
+        &[1.to_variant(), 2.to_variant(), "?".to_variant()],
+    );
+    assert_eq!(result, "3?".to_variant());
+}
+
+#[itest]
+fn func_default_params_arg_count() {
+    let mut obj = Gd::<FuncDefaults>::new_default();
+    let method = StringName::from("sum_with_defaults");
+
+    let result = obj.try_call(method.clone(), &[]);
+    assert_eq!(result, Err(CallError::TooFewArguments { expected: 1 }));
+
+    let args = [1, 2, 3, 4].map(|i: i64| i.to_variant());
+    let result = obj.try_call(method, &args);
+    assert_eq!(result, Err(CallError::TooManyArguments { expected: 3 }));
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncVararg;
+
+#[godot_api]
+impl FuncVararg {
+    #[func(vararg)]
+    fn join_all(&self, args: &[Variant]) -> GodotString {
+        let parts: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        parts.join(",").into()
+    }
+
+    #[func(vararg)]
+    fn count_static(args: &[Variant]) -> i64 {
+        args.len() as i64
+    }
+}
+
+#[itest]
+fn func_vararg() {
+    let obj = Gd::<FuncVararg>::new_default().to_variant();
+
+    let result = obj.call("join_all", &[]);
+    assert_eq!(result, "".to_variant());
+
+    let result = obj.call(
+        "join_all",
+        &[1.to_variant(), "two".to_variant(), true.to_variant()],
+    );
+    assert_eq!(result, "1,two,true".to_variant());
+
+    let result = obj.call("count_static", &[1.to_variant(), 2.to_variant()]);
+    assert_eq!(result, 2.to_variant());
+}
+
+#[derive(GodotClass)]
+#[class(base=RefCounted)]
+struct FuncStatic {
+    value: i64,
+}
+
+#[godot_api]
+impl FuncStatic {
+    #[func]
+    fn create(value: i64) -> Gd<Self> {
+        Gd::new(Self { value })
+    }
+
+    #[func]
+    fn get_value(&self) -> i64 {
+        self.value
+    }
+}
+
+#[itest]
+fn func_static_factory() {
+    // Static functions can always be called on an instance, independent of the Godot version.
+    let obj = Gd::new(FuncStatic { value: 0 }).to_variant();
+
+    let created = obj.call("create", &[42.to_variant()]);
+    let created = created.to::<Gd<FuncStatic>>();
+    assert_eq!(created.bind().get_value(), 42);
+}
+
+#[cfg(since_api = "4.1")]
+#[itest]
+fn func_static_method_flags() {
+    use godot::engine::global::MethodFlags;
+    use godot::engine::ClassDb;
+    use godot::obj::EngineEnum;
+
+    let methods = ClassDb::singleton()
+        .class_get_method_list_ex(FuncStatic::class_name().to_string_name())
+        .no_inheritance(true)
+        .done();
+
+    let flags_of = |name: &str| -> i32 {
+        let method = methods
+            .iter_shared()
+            .find(|method| method.get_or_nil("name") == name.to_variant())
+            .unwrap_or_else(|| panic!("method {name} not registered"));
+
+        method.get_or_nil("flags").to::<i32>()
+    };
+
+    let static_flag = MethodFlags::METHOD_FLAG_STATIC.ord();
+    assert_eq!(flags_of("create") & static_flag, static_flag);
+    assert_eq!(flags_of("get_value") & static_flag, 0);
+}
+
+#[derive(GodotClass)]
+#[class(init, base=RefCounted)]
+struct FuncVirtual {
+    health: i64,
+    #[base]
+    base: Base<RefCounted>,
+}
+
+#[godot_api]
+impl FuncVirtual {
+    #[func(virtual)]
+    fn on_hit(&self, dmg: i64) -> i64 {
+        dmg
+    }
+
+    #[func]
+    fn hit(&self, dmg: i64) -> i64 {
+        self.on_hit(dmg)
+    }
+
+    #[func(virtual)]
+    fn on_heal(&mut self, amount: i64) -> i64 {
+        self.health += amount;
+        self.health
+    }
+}
+
+#[itest]
+fn func_virtual_default() {
+    let obj = Gd::<FuncVirtual>::new_default();
+
+    assert_eq!(obj.bind().on_hit(5), 5);
+    assert_eq!(
+        obj.to_variant().call("on_hit", &[5.to_variant()]),
+        5.to_variant()
+    );
+}
+
+#[itest]
+fn func_virtual_script_override() {
+    let mut obj = Gd::<FuncVirtual>::new_default();
+    let script = load::<Script>("res://VirtualOverride.gd");
+    obj.set_script(script.to_variant());
+
+    // Dispatched from Godot.
+    let result = obj.to_variant().call("on_hit", &[5.to_variant()]);
+    assert_eq!(result, 50.to_variant());
+
+    // Dispatched from Rust.
+    let result = obj.to_variant().call("hit", &[5.to_variant()]);
+    assert_eq!(result, 50.to_variant());
+}
+
+#[itest]
+fn func_virtual_script_override_calls_back() {
+    let mut obj = Gd::<FuncVirtual>::new_default();
+    let script = load::<Script>("res://VirtualOverride.gd");
+    obj.set_script(script.to_variant());
+
+    // The override calls the Rust default implementation with `super()`. The dispatcher does not bind the object meanwhile.
+    let result = FuncVirtual::on_heal(&mut obj, 5);
+    assert_eq!(result, 10);
+
+    let result = FuncVirtual::on_heal(&mut obj, 1);
+    assert_eq!(result, 12);
+    assert_eq!(obj.bind().health, 12);
+}
+
+#[itest]
+fn func_virtual_default_mut() {
+    let mut obj = Gd::<FuncVirtual>::new_default();
+
+    assert_eq!(FuncVirtual::on_heal(&mut obj, 3), 3);
+    assert_eq!(obj.bind().health, 3);
+}
@@ -0,0 +1,150 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::global::PropertyHint;
+use godot::engine::ClassDb;
+use godot::obj::EngineEnum;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[derive(GodotEnum, Clone, Copy, Default, PartialEq, Debug)]
+#[godot(class = HasGodotEnum)]
+enum State {
+    #[default]
+    Idle,
+    Walk,
+    RunFast = 4,
+}
+
+#[derive(GodotEnum, Clone, Copy, Default, PartialEq, Debug)]
+#[godot(class = HasGodotEnum, bitfield)]
+enum Abilities {
+    #[default]
+    Jump = 1,
+    Swim = 2,
+    Fly = 4,
+}
+
+#[derive(GodotClass)]
+#[class(init)]
+struct HasGodotEnum {
+    #[export]
+    state: State,
+
+    #[export]
+    ability: Abilities,
+}
+
+#[godot_api]
+impl HasGodotEnum {
+    #[func]
+    fn next_state(&self, state: State) -> State {
+        match state {
+            State::Idle => State::Walk,
+            State::Walk => State::RunFast,
+            State::RunFast => State::Idle,
+        }
+    }
+}
+
+#[itest]
+fn godot_enum_variant_conversion() {
+    assert_eq!(State::Walk.to_variant(), 1.to_variant());
+    assert_eq!(State::RunFast.to_variant(), 4.to_variant());
+
+    assert_eq!(State::from_variant(&0.to_variant()), State::Idle);
+    assert_eq!(State::try_from_variant(&2.to_variant()).ok(), None);
+    assert_eq!(State::try_from_ord(4), Some(State::RunFast));
+}
+
+#[itest]
+fn godot_enum_func_param_and_return() {
+    let obj = Gd::<HasGodotEnum>::new_default();
+
+    let next = obj
+        .to_variant()
+        .call("next_state", &[State::Walk.to_variant()]);
+    assert_eq!(State::from_variant(&next), State::RunFast);
+}
+
+#[itest]
+fn godot_enum_export_hint() {
+    let obj = Gd::<HasGodotEnum>::new_default();
+
+    let property = obj
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "state".to_variant())
+        .unwrap();
+
+    assert_eq!(
+        property.get_or_nil("hint"),
+        PropertyHint::PROPERTY_HINT_ENUM.ord().to_variant()
+    );
+    assert_eq!(
+        property.get_or_nil("hint_string"),
+        "Idle:0,Walk:1,RunFast:4".to_variant()
+    );
+}
+
+#[itest]
+fn godot_enum_bitfield_export_hint() {
+    let obj = Gd::<HasGodotEnum>::new_default();
+
+    let property = obj
+        .get_property_list()
+        .iter_shared()
+        .find(|c| c.get_or_nil("name") == "ability".to_variant())
+        .unwrap();
+
+    assert_eq!(
+        property.get_or_nil("hint"),
+        PropertyHint::PROPERTY_HINT_FLAGS.ord().to_variant()
+    );
+    assert_eq!(
+        property.get_or_nil("hint_string"),
+        "Jump:1,Swim:2,Fly:4".to_variant()
+    );
+}
+
+#[itest]
+fn godot_enum_class_constants() {
+    let class_name = HasGodotEnum::class_name().to_string_name();
+    let class_db = ClassDb::singleton();
+
+    assert!(class_db
+        .class_has_enum_ex(class_name.clone(), "State".into())
+        .no_inheritance(true)
+        .done());
+
+    let expected = [("IDLE", 0), ("WALK", 1), ("RUN_FAST", 4)];
+    for (name, value) in expected {
+        assert_eq!(
+            class_db.class_get_integer_constant(class_name.clone(), name.into()),
+            value
+        );
+    }
+}
+
+#[itest]
+fn godot_enum_class_bitfield() {
+    let class_name = HasGodotEnum::class_name().to_string_name();
+    let class_db = ClassDb::singleton();
+
+    let flags = class_db
+        .class_get_enum_constants_ex(class_name.clone(), "Abilities".into())
+        .no_inheritance(true)
+        .done();
+
+    for name in ["JUMP", "SWIM", "FLY"] {
+        assert!(flags.contains(name.into()));
+    }
+    assert_eq!(
+        class_db.class_get_integer_constant(class_name, "FLY".into()),
+        Abilities::Fly as i64
+    );
+}
@@ -4,9 +4,14 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/.
  */
 
+mod abstract_test;
 mod constant_test;
 mod derive_variant_test;
+mod docs_test;
+mod editor_plugin_test;
 mod func_test;
 mod gdscript_ffi_test;
+mod godot_enum_test;
+mod multiple_api_test;
 mod option_ffi_test;
 mod var_test;
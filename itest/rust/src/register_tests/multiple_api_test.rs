@@ -0,0 +1,85 @@
+/*
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+use godot::engine::ClassDb;
+use godot::prelude::*;
+
+use crate::framework::itest;
+
+#[derive(GodotClass)]
+#[class(init)]
+struct SplitApi {
+    // Getter is declared in the second `#[godot_api]` block.
+    #[var(get = get_health, set)]
+    health: i64,
+}
+
+#[godot_api]
+impl SplitApi {
+    #[constant]
+    const MAX_HEALTH: i64 = 100;
+
+    #[func]
+    fn heal(&mut self, amount: i64) {
+        self.health = (self.health + amount).min(Self::MAX_HEALTH);
+    }
+}
+
+mod other_module {
+    use super::SplitApi;
+    use godot::prelude::*;
+
+    #[godot_api]
+    impl SplitApi {
+        #[constant]
+        const MIN_HEALTH: i64 = 0;
+
+        #[signal]
+        fn damaged(amount: i64);
+
+        #[func]
+        fn damage(&mut self, amount: i64) {
+            self.health = (self.health - amount).max(Self::MIN_HEALTH);
+        }
+
+        #[func]
+        fn get_health(&self) -> i64 {
+            self.health
+        }
+    }
+}
+
+#[itest]
+fn multiple_api_blocks_methods() {
+    let mut obj = Gd::<SplitApi>::new_default();
+
+    assert!(obj.has_method("heal".into()));
+    assert!(obj.has_method("damage".into()));
+
+    obj.call("heal".into(), &[150.to_variant()]);
+    assert_eq!(obj.get("health".into()), 100.to_variant());
+
+    obj.call("damage".into(), &[30.to_variant()]);
+    assert_eq!(obj.get("health".into()), 70.to_variant());
+}
+
+#[itest]
+fn multiple_api_blocks_signals_and_constants() {
+    let obj = Gd::<SplitApi>::new_default();
+    assert!(obj.has_signal("damaged".into()));
+
+    let class_name = SplitApi::class_name().to_string_name();
+    let class_db = ClassDb::singleton();
+
+    assert_eq!(
+        class_db.class_get_integer_constant(class_name.clone(), "MAX_HEALTH".into()),
+        100
+    );
+    assert_eq!(
+        class_db.class_get_integer_constant(class_name, "MIN_HEALTH".into()),
+        0
+    );
+}